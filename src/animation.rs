@@ -0,0 +1,108 @@
+use crate::buffer::Buffer;
+use crate::Vec2;
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, Frames, ImageFormat};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::time::Duration;
+
+/// Browsers show frames without a delay, or with a very short one, for this long instead.
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// Every frame of an animated GIF, PNG or WebP and how long to show each of them.
+pub struct Animation {
+    frames: Vec<(Buffer, Duration)>,
+    current: usize,
+    elapsed: Duration,
+}
+
+fn open(path: &Path) -> Option<(ImageFormat, BufReader<File>)> {
+    let format = image::ImageReader::open(path).ok()?.with_guessed_format().ok()?.format()?;
+    Some((format, BufReader::new(File::open(path).ok()?)))
+}
+
+fn frames<'a>(format: ImageFormat, reader: BufReader<File>) -> Option<Frames<'a>> {
+    match format {
+        ImageFormat::Gif => Some(GifDecoder::new(reader).ok()?.into_frames()),
+        ImageFormat::Png => Some(PngDecoder::new(reader).ok()?.apng().ok()?.into_frames()),
+        ImageFormat::WebP => Some(WebPDecoder::new(reader).ok()?.into_frames()),
+        _ => None,
+    }
+}
+
+/// Whether the file at `path` has more than one frame.
+pub fn detect(path: &Path) -> bool {
+    let Some((format, reader)) = open(path) else {
+        return false;
+    };
+    match format {
+        // GIFs don't say how many frames they have, so this has to decode the second one to find out.
+        ImageFormat::Gif => frames(format, reader).is_some_and(|frames| frames.take(2).count() > 1),
+        ImageFormat::Png => PngDecoder::new(reader).and_then(|png| png.is_apng()).unwrap_or(false),
+        ImageFormat::WebP => WebPDecoder::new(reader).is_ok_and(|webp| webp.has_animation()),
+        _ => false,
+    }
+}
+
+impl Animation {
+    /// Decodes every frame, with `limits` only as many as fit in 50MiB.
+    pub fn decode(path: &Path, limits: bool, orientation: u8) -> Option<Animation> {
+        let (format, reader) = open(path)?;
+        let mut used = 0;
+        let mut result = Vec::new();
+        for frame in frames(format, reader)? {
+            let frame = match frame {
+                Ok(frame) => frame,
+                Err(err) => {
+                    eprintln!("Failed to decode frame: {}", err);
+                    break;
+                }
+            };
+            let (numerator, denominator) = frame.delay().numer_denom_ms();
+            let delay = Duration::from_secs_f64(numerator as f64 / denominator.max(1) as f64 / 1000.0);
+            let delay = if delay <= Duration::from_millis(10) { DEFAULT_DELAY } else { delay };
+            let image = frame.into_buffer();
+            let buffer = Buffer::from_rgba8(Vec2::from(image.width(), image.height()), image.as_raw()).orient(orientation);
+
+            used += buffer.data.len() * 4;
+            if limits && used > 50 * 1024 * 1024 {
+                break;
+            }
+            result.push((buffer, delay));
+        }
+        if result.is_empty() {
+            return None;
+        }
+        Some(Animation { frames: result, current: 0, elapsed: Duration::ZERO })
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.frames[0].0.size
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn frame(&self) -> &Buffer {
+        &self.frames[self.current].0
+    }
+
+    /// Advances the animation by `elapsed`, returns whether a different frame has to be shown.
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        if self.frames.len() < 2 {
+            return false;
+        }
+        let previous = self.current;
+        // Don't catch up on every frame that was missed while nothing was drawn.
+        self.elapsed += elapsed.min(Duration::from_secs(1));
+        while self.elapsed >= self.frames[self.current].1 {
+            self.elapsed -= self.frames[self.current].1;
+            self.current = (self.current + 1) % self.frames.len();
+        }
+        self.current != previous
+    }
+}
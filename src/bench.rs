@@ -0,0 +1,72 @@
+use crate::buffer::{self, Buffer, BufferView, Kernel};
+use crate::pool::Pool;
+use crate::Vec2;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The fastest of `runs` runs of `f`, and what it returned.
+fn time(runs: usize, mut f: impl FnMut() -> Buffer) -> (Duration, Buffer) {
+    let mut best = (Duration::MAX, Buffer::empty());
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        if elapsed < best.0 {
+            best = (elapsed, result);
+        }
+    }
+    best
+}
+
+/// The largest difference of any channel of any pixel.
+fn difference(a: &Buffer, b: &Buffer) -> u32 {
+    a.data
+        .iter()
+        .zip(&b.data)
+        .map(|(a, b)| (0..4).map(|i| ((a >> (i * 8)) & 0xFF).abs_diff((b >> (i * 8)) & 0xFF)).max().unwrap_or(0))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Scales the image at `path` to `width` pixels wide with every kernel, once with [`BufferView::scale`] and
+/// with [`buffer::scale_rgba8`] on `threads` workers, and prints how long each took and how much they differ.
+pub fn scale(path: &Path, width: u32, threads: usize) {
+    let image = match image::open(path) {
+        Ok(image) => image,
+        Err(err) => {
+            eprintln!("Failed to open {}: {}", path.display(), err);
+            return;
+        }
+    };
+    let source = Vec2::from(image.width(), image.height());
+    let size = Vec2::from(width.max(1), ((source.y as u64 * width as u64 / source.x.max(1) as u64) as u32).max(1));
+    let pixels = image.to_rgba8().into_raw();
+    let mut pool = Pool::new(threads);
+    let handle = pool.handle();
+    println!(
+        "Scaling {}x{} ({:.1}MP) to {}x{} on {} workers",
+        source.x,
+        source.y,
+        (source.x as u64 * source.y as u64) as f64 / 1e6,
+        size.x,
+        size.y,
+        pool.workers()
+    );
+    println!("{:<10} {:>10} {:>10} {:>8} {:>6}", "kernel", "generic", "fast", "speedup", "diff");
+    for kernel in Kernel::ALL {
+        // The generic path is slow enough that one run is plenty.
+        let (generic, expected) = time(1, || image.scale(size, kernel));
+        let (fast, result) = time(3, || buffer::scale_rgba8(pixels.clone(), source, size, kernel, &handle));
+        println!(
+            "{:<10} {:>10.2?} {:>10.2?} {:>7.1}x {:>6}",
+            kernel.name(),
+            generic,
+            fast,
+            generic.as_secs_f64() / fast.as_secs_f64().max(1e-9),
+            difference(&expected, &result)
+        );
+    }
+    for worker in pool.shutdown() {
+        let _ = worker.join();
+    }
+}
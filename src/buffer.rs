@@ -1,51 +1,237 @@
+use crate::pool::Handle;
 use crate::Vec2;
 use allocative::Allocative;
+use std::f32::consts::PI;
 use std::simd::Simd;
+use std::sync::{Arc, LazyLock, Mutex};
 
 #[allow(non_camel_case_types)]
-type f32x3 = Simd<f32, 3>;
+type f32x4 = Simd<f32, 4>;
+
+/// The filter that decides how much every source pixel contributes when scaling.
+#[derive(Copy, Clone, PartialEq, Debug, Allocative)]
+pub enum Kernel {
+    /// The closest pixel, keeps hard pixel edges.
+    Nearest,
+    /// The average of the pixels that are covered.
+    Box,
+    /// A triangle, soft on downscales.
+    Bilinear,
+    /// Catmull-Rom, sharp with a little ringing.
+    Bicubic,
+    /// Mitchell-Netravali, between bilinear and Catmull-Rom.
+    Mitchell,
+    /// The sharpest, but also the slowest.
+    Lanczos3,
+}
+
+impl Kernel {
+    pub const ALL: [Kernel; 6] =
+        [Kernel::Nearest, Kernel::Box, Kernel::Bilinear, Kernel::Bicubic, Kernel::Mitchell, Kernel::Lanczos3];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Kernel::Nearest => "nearest",
+            Kernel::Box => "box",
+            Kernel::Bilinear => "bilinear",
+            Kernel::Bicubic => "bicubic",
+            Kernel::Mitchell => "mitchell",
+            Kernel::Lanczos3 => "lanczos3",
+        }
+    }
+
+    /// How far from the center, in pixels, the weight isn't zero.
+    fn support(self) -> f32 {
+        match self {
+            Kernel::Nearest | Kernel::Box => 0.5,
+            Kernel::Bilinear => 1.0,
+            Kernel::Bicubic | Kernel::Mitchell => 2.0,
+            Kernel::Lanczos3 => 3.0,
+        }
+    }
+
+    fn weight(self, x: f32) -> f32 {
+        let x = x.abs();
+        // Mitchell and Netravali's family of cubics, B = 0 and C = 0.5 is Catmull-Rom.
+        let cubic = |b: f32, c: f32| {
+            if x < 1.0 {
+                ((12.0 - 9.0 * b - 6.0 * c) * x * x * x + (-18.0 + 12.0 * b + 6.0 * c) * x * x + (6.0 - 2.0 * b)) / 6.0
+            } else if x < 2.0 {
+                ((-b - 6.0 * c) * x * x * x + (6.0 * b + 30.0 * c) * x * x + (-12.0 * b - 48.0 * c) * x + (8.0 * b + 24.0 * c))
+                    / 6.0
+            } else {
+                0.0
+            }
+        };
+        let sinc = |x: f32| if x == 0.0 { 1.0 } else { (x * PI).sin() / (x * PI) };
+        match self {
+            Kernel::Nearest | Kernel::Box => (x <= 0.5) as u8 as f32,
+            Kernel::Bilinear => (1.0 - x).max(0.0),
+            Kernel::Bicubic => cubic(0.0, 0.5),
+            Kernel::Mitchell => cubic(1.0 / 3.0, 1.0 / 3.0),
+            Kernel::Lanczos3 if x < 3.0 => sinc(x) * sinc(x / 3.0),
+            Kernel::Lanczos3 => 0.0,
+        }
+    }
+}
+
+/// What shows through the transparent parts of images.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Backdrop {
+    Checkerboard,
+    Color(u32),
+}
+
+impl Backdrop {
+    fn at(self, x: u32, y: u32) -> u32 {
+        match self {
+            Backdrop::Checkerboard if (x / 8 + y / 8).is_multiple_of(2) => 0xFFCCCCCC,
+            Backdrop::Checkerboard => 0xFF999999,
+            Backdrop::Color(color) => color,
+        }
+    }
+}
+
+static TO_LINEAR: LazyLock<[f32; 256]> = LazyLock::new(|| {
+    std::array::from_fn(|i| {
+        let c = i as f32 / 255.0;
+        if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    })
+});
+
+/// Linear light to sRGB, fine enough that neighbouring entries never round to bytes more than one apart.
+static TO_SRGB: LazyLock<[u8; 4096]> = LazyLock::new(|| {
+    std::array::from_fn(|i| {
+        let c = i as f32 / 4095.0;
+        let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+        (c * 255.0).round() as u8
+    })
+});
+
+/// A 0xAARRGGBB pixel as premultiplied linear light.
+fn linear(color: u32) -> f32x4 {
+    let a = (color >> 24) as f32 / 255.0;
+    f32x4::from_array([
+        TO_LINEAR[((color >> 16) & 0xFF) as usize] * a,
+        TO_LINEAR[((color >> 8) & 0xFF) as usize] * a,
+        TO_LINEAR[(color & 0xFF) as usize] * a,
+        a,
+    ])
+}
+
+fn linear_rgba8(pixel: &[u8]) -> f32x4 {
+    let a = pixel[3] as f32 / 255.0;
+    f32x4::from_array([
+        TO_LINEAR[pixel[0] as usize] * a,
+        TO_LINEAR[pixel[1] as usize] * a,
+        TO_LINEAR[pixel[2] as usize] * a,
+        a,
+    ])
+}
+
+fn srgb(color: f32x4) -> u32 {
+    let [r, g, b, a] = color.to_array();
+    let a = a.clamp(0.0, 1.0);
+    if a <= 0.0 {
+        return 0;
+    }
+    let channel = |c: f32| TO_SRGB[((c / a).clamp(0.0, 1.0) * 4095.0).round() as usize] as u32;
+    (((a * 255.0).round() as u32) << 24) | (channel(r) << 16) | (channel(g) << 8) | channel(b)
+}
+
+/// How many rows of the result [`scale_rgba8`] hands to a worker at once.
+const BAND: u32 = 32;
+
+/// Scales RGBA8 pixels, stored row after row, the same way as [`BufferView::scale`] but much faster.
+/// The rows of the result are split into bands that the workers of `pool` scale at the same time. Each band
+/// converts its source rows to linear light once and scales them horizontally first, which leaves few rows
+/// for the vertical pass, and both passes work on whole pixels as SIMD vectors.
+pub fn scale_rgba8(pixels: Vec<u8>, src: Vec2, size: Vec2, kernel: Kernel, pool: &Handle) -> Buffer {
+    if size == src {
+        return Buffer::from_rgba8(src, &pixels);
+    }
+    let pixels = Arc::new(pixels);
+    let weights_x = Arc::new(compute_weights(src.x, size.x, kernel));
+    let weights_y = Arc::new(compute_weights(src.y, size.y, kernel));
+    let bands = size.y.div_ceil(BAND) as usize;
+    let results = Arc::new(Mutex::new(vec![Vec::new(); bands]));
+
+    let done = results.clone();
+    pool.parallel(bands, move |band| {
+        let width = size.x as usize;
+        let rows = band as u32 * BAND..((band as u32 + 1) * BAND).min(size.y);
+        let first = weights_y[rows.start as usize].0;
+        let last = rows.clone().map(|y| weights_y[y as usize].0 + weights_y[y as usize].1.len() as u32).max().unwrap_or(first);
+
+        let mut line = vec![f32x4::splat(0.0); src.x as usize];
+        let mut narrow = Vec::with_capacity((last - first) as usize * width);
+        for y in first..last {
+            let row = &pixels[(y * src.x * 4) as usize..((y + 1) * src.x * 4) as usize];
+            for (linear, pixel) in line.iter_mut().zip(row.chunks_exact(4)) {
+                *linear = linear_rgba8(pixel);
+            }
+            for (start, wts) in weights_x.iter() {
+                let mut acc = f32x4::splat(0.0);
+                for (&pixel, &w) in line[*start as usize..].iter().zip(wts) {
+                    acc += pixel * f32x4::splat(w);
+                }
+                narrow.push(acc);
+            }
+        }
+
+        let mut out = Vec::with_capacity(rows.len() * width);
+        let mut acc = vec![f32x4::splat(0.0); width];
+        for y in rows {
+            let (start, wts) = &weights_y[y as usize];
+            acc.fill(f32x4::splat(0.0));
+            for (i, &w) in wts.iter().enumerate() {
+                let row = (start - first) as usize + i;
+                for (acc, &pixel) in acc.iter_mut().zip(&narrow[row * width..(row + 1) * width]) {
+                    *acc += pixel * f32x4::splat(w);
+                }
+            }
+            out.extend(acc.iter().map(|&color| srgb(color)));
+        }
+        done.lock().unwrap()[band] = out;
+    });
+    // Workers that didn't get a band may still hold on to the closure, so take the results out instead.
+    let data = std::mem::take(&mut *results.lock().unwrap()).concat();
+    Buffer { size, data }
+}
 
 pub trait BufferView {
     fn size(&self) -> Vec2;
     fn get(&self, pos: Vec2) -> u32;
 
-    fn scale(&self, size: Vec2) -> Buffer {
+    /// Scales with `kernel` in linear light and with premultiplied alpha, so edges keep their brightness
+    /// and transparent pixels don't bleed their color into the visible ones.
+    fn scale(&self, size: Vec2, kernel: Kernel) -> Buffer {
         if size == self.size() {
             return self.clone();
         }
         let mut buf = Buffer::new(size);
-        let mut tmp_line: Vec<f32x3> = vec![f32x3::splat(0.0); self.size().x as usize];
+        let mut tmp_line: Vec<f32x4> = vec![f32x4::splat(0.0); self.size().x as usize];
 
-        let weights_x = compute_weights(self.size().x, size.x);
-        let weights_y = compute_weights(self.size().y, size.y);
+        let weights_x = compute_weights(self.size().x, size.x, kernel);
+        let weights_y = compute_weights(self.size().y, size.y, kernel);
         for out_y in 0..size.y {
             let (y_start, y_wts) = &weights_y[out_y as usize];
 
             for x in 0..self.size().x {
-                let mut acc = f32x3::splat(0.0);
+                let mut acc = f32x4::splat(0.0);
                 for (i, &wy) in y_wts.iter().enumerate() {
-                    let color = self.get(Vec2::from(x, y_start + i as u32));
-                    let a = f32x3::from_array([
-                        ((color >> 16) & 0xFF) as f32,
-                        ((color >> 8) & 0xFF) as f32,
-                        ((color) & 0xFF) as f32,
-                    ]);
-                    acc += a * f32x3::splat(wy);
+                    acc += linear(self.get(Vec2::from(x, y_start + i as u32))) * f32x4::splat(wy);
                 }
                 tmp_line[x as usize] = acc;
             }
 
             for out_x in 0..size.x {
                 let (x_start, x_wts) = &weights_x[out_x as usize];
-                let mut acc = f32x3::splat(0.0);
+                let mut acc = f32x4::splat(0.0);
                 for (i, &wx) in x_wts.iter().enumerate() {
-                    let color = tmp_line[*x_start as usize + i];
-                    acc += color * f32x3::splat(wx);
+                    acc += tmp_line[*x_start as usize + i] * f32x4::splat(wx);
                 }
-                let acc = acc.to_array();
-                buf.set(Vec2::from(out_x, out_y),
-                        (0xFF << 24) | ((acc[0] as u32) << 16) | ((acc[1] as u32) << 8) | acc[2] as u32,
-                );
+                buf.set(Vec2::from(out_x, out_y), srgb(acc));
             }
         }
         buf
@@ -61,8 +247,9 @@ pub trait BufferView {
         buf
     }
 
+    /// Rotates by 90 degrees clockwise.
     fn rotate(&self) -> Buffer {
-        let mut buf = Buffer::new(self.size());
+        let mut buf = Buffer::new(Vec2::from(self.size().y, self.size().x));
         for x in 0..self.size().x {
             for y in 0..self.size().y {
                 buf.set(Vec2::from(self.size().y - y - 1, x), self.get(Vec2::from(x, y)));
@@ -92,7 +279,6 @@ pub trait BufferView {
     }
 }
 
-
 #[derive(Allocative)]
 pub struct Buffer {
     pub size: Vec2,
@@ -124,6 +310,40 @@ impl Buffer {
         }
     }
 
+    /// Applies an EXIF orientation, turning the stored pixels into the upright image.
+    pub fn orient(self, orientation: u8) -> Buffer {
+        match orientation {
+            2 => self.flip_g(),
+            3 => self.flip_g().flip_v(),
+            4 => self.flip_v(),
+            5 => self.rotate().flip_g(),
+            6 => self.rotate(),
+            7 => self.rotate().flip_v(),
+            8 => self.rotate().flip_g().flip_v(),
+            _ => self,
+        }
+    }
+
+    pub fn from_rgba8(size: Vec2, pixels: &[u8]) -> Buffer {
+        let data = pixels
+            .chunks_exact(4)
+            .map(|p| ((p[3] as u32) << 24) | ((p[0] as u32) << 16) | ((p[1] as u32) << 8) | p[2] as u32)
+            .collect();
+        Buffer { size, data }
+    }
+
+    /// The rectangle of `size` at `pos` as RGBA8 pixels, row after row, the way [`scale_rgba8`] takes them.
+    pub fn crop_rgba8(&self, pos: Vec2, size: Vec2) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(size.x as usize * size.y as usize * 4);
+        for y in pos.y as usize..(pos.y + size.y) as usize {
+            let start = y * self.size.x as usize + pos.x as usize;
+            for &p in &self.data[start..start + size.x as usize] {
+                pixels.extend([(p >> 16) as u8, (p >> 8) as u8, p as u8, (p >> 24) as u8]);
+            }
+        }
+        pixels
+    }
+
     pub fn empty() -> Buffer {
         Buffer {
             size: Vec2::zero(),
@@ -199,32 +419,81 @@ impl Buffer {
         self.data.fill(color);
     }
 
-    pub fn copy_from(&mut self, other: &impl BufferView, x: u32, y: i32) {
+    /// Fills a rectangle, clipping whatever falls outside the buffer.
+    pub fn fill(&mut self, x: i32, y: i32, size: Vec2, color: u32) {
+        let x0 = x.clamp(0, self.size.x as i32) as usize;
+        let y0 = y.clamp(0, self.size.y as i32) as usize;
+        let x1 = (x + size.x as i32).clamp(0, self.size.x as i32) as usize;
+        let y1 = (y + size.y as i32).clamp(0, self.size.y as i32) as usize;
+        for row in y0..y1 {
+            let start = row * self.size.x as usize;
+            self.data[start + x0..start + x1].fill(color);
+        }
+    }
+
+    /// Blends `color` over the rectangle.
+    pub fn tint(&mut self, x: i32, y: i32, size: Vec2, color: u32, alpha: f32) {
+        let x0 = x.clamp(0, self.size.x as i32) as u32;
+        let y0 = y.clamp(0, self.size.y as i32) as u32;
+        let x1 = (x + size.x as i32).clamp(0, self.size.x as i32) as u32;
+        let y1 = (y + size.y as i32).clamp(0, self.size.y as i32) as u32;
+        for py in y0..y1 {
+            for px in x0..x1 {
+                self.set_transparent(Vec2::from(px, py), color, alpha);
+            }
+        }
+    }
+
+    /// Draws a border of `thickness` pixels just inside the rectangle.
+    pub fn outline(&mut self, x: i32, y: i32, size: Vec2, thickness: u32, color: u32) {
+        let t = thickness.min(size.x / 2).min(size.y / 2);
+        self.fill(x, y, Vec2::from(size.x, t), color);
+        self.fill(x, y + (size.y - t) as i32, Vec2::from(size.x, t), color);
+        self.fill(x, y + t as i32, Vec2::from(t, size.y - 2 * t), color);
+        self.fill(x + (size.x - t) as i32, y + t as i32, Vec2::from(t, size.y - 2 * t), color);
+    }
+
+    /// Draws `other` with its transparent parts blended over `backdrop`.
+    pub fn composite(&mut self, other: &impl BufferView, x: u32, y: i32, backdrop: Backdrop) {
         for j in -y.min(0)..other.size().y as i32 {
             for i in 0..other.size().x {
-                self.set(
-                    Vec2::from(x + i, (y + j) as u32),
-                    other.get(Vec2::from(i, j as u32)),
-                );
+                let color = other.get(Vec2::from(i, j as u32));
+                let alpha = color >> 24;
+                let color = if alpha == 0xFF {
+                    color
+                } else {
+                    let behind = backdrop.at(i, j as u32);
+                    let blend = |shift: u32| {
+                        let (c, b) = ((color >> shift) & 0xFF, (behind >> shift) & 0xFF);
+                        ((c * alpha + b * (0xFF - alpha) + 127) / 0xFF) << shift
+                    };
+                    0xFF000000 | blend(16) | blend(8) | blend(0)
+                };
+                self.set(Vec2::from(x + i, (y + j) as u32), color);
             }
         }
     }
 }
 
-fn compute_weights(src: u32, target: u32) -> Vec<(u32, Vec<f32>)> {
+fn compute_weights(src: u32, target: u32, kernel: Kernel) -> Vec<(u32, Vec<f32>)> {
     let ratio = src as f32 / target as f32;
-    let support = if ratio < 1.0 { 1.0 } else { ratio };
+    // Downscaling stretches the kernel over all the source pixels that end up in one output pixel.
+    let scale = ratio.max(1.0);
+    let support = kernel.support() * scale;
     let mut weights: Vec<(u32, Vec<f32>)> = Vec::with_capacity(target as usize);
     for out in 0..target {
         let input = (out as f32 + 0.5) * ratio;
+        if kernel == Kernel::Nearest {
+            weights.push(((input as u32).min(src - 1), vec![1.0]));
+            continue;
+        }
         let start = ((input - support).floor() as i32).clamp(0, src as i32 - 1) as u32;
         let end = ((input + support).ceil() as i32).clamp(start as i32 + 1, src as i32) as u32;
 
         let mut wts = Vec::with_capacity((end - start) as usize);
         let mut sum = 0.0;
         for i in start..end {
-            let x = ((i as f32 - input - 0.5) / support).abs();
-            let w = if x < 1.0 { 1.0 - x } else { 0.0 };
+            let w = kernel.weight((i as f32 + 0.5 - input) / scale);
             wts.push(w);
             sum += w;
         }
@@ -236,4 +505,4 @@ fn compute_weights(src: u32, target: u32) -> Vec<(u32, Vec<f32>)> {
         weights.push((start, wts));
     }
     weights
-}
\ No newline at end of file
+}
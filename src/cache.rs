@@ -0,0 +1,104 @@
+use crate::buffer::{Buffer, Kernel};
+use crate::util::{hash, Vec2};
+use image::{ImageFormat, RgbaImage};
+use std::collections::HashSet;
+use std::env::{current_exe, var_os};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+    let base = if cfg!(windows) {
+        var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        var_os("HOME").map(|home| PathBuf::from(home).join("Library/Caches"))
+    } else {
+        var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    };
+    match base {
+        Some(base) => base.join("nanogallery"),
+        None => current_exe().unwrap().parent().unwrap().join("cache"),
+    }
+});
+
+pub fn dir() -> &'static Path {
+    &DIR
+}
+
+/// Thumbnails are stored as `<hash>.qoi`, where the hash covers everything that makes an entry stale.
+pub fn key(path: &Path, modified: u64, size: Vec2, kernel: Kernel) -> String {
+    let mut bytes = path.as_os_str().as_encoded_bytes().to_vec();
+    bytes.extend_from_slice(&modified.to_le_bytes());
+    bytes.extend_from_slice(&size.x.to_le_bytes());
+    bytes.extend_from_slice(&size.y.to_le_bytes());
+    bytes.extend_from_slice(kernel.name().as_bytes());
+    format!("{:016x}", hash(&bytes))
+}
+
+fn file(key: &str) -> PathBuf {
+    DIR.join(key).with_extension("qoi")
+}
+
+pub fn read(key: &str) -> Option<Buffer> {
+    let bytes = fs::read(file(key)).ok()?;
+    let image = image::load_from_memory_with_format(&bytes, ImageFormat::Qoi).ok()?.into_rgba8();
+    Some(Buffer::from_rgba8(Vec2::from(image.width(), image.height()), image.as_raw()))
+}
+
+pub fn write(key: &str, buffer: &Buffer) {
+    if buffer.size.x == 0 || buffer.size.y == 0 {
+        return;
+    }
+    let bytes = buffer
+        .data
+        .iter()
+        .flat_map(|c| [(c >> 16) as u8, (c >> 8) as u8, *c as u8, (c >> 24) as u8])
+        .collect();
+    let Some(image) = RgbaImage::from_raw(buffer.size.x, buffer.size.y, bytes) else {
+        return;
+    };
+    let mut encoded = Cursor::new(Vec::new());
+    if image.write_to(&mut encoded, ImageFormat::Qoi).is_err() {
+        return;
+    }
+
+    // Write to a temporary file first so a crash never leaves a truncated entry behind.
+    let _ = fs::create_dir_all(&*DIR);
+    let tmp = file(key).with_extension("tmp");
+    if fs::write(&tmp, encoded.into_inner()).and_then(|_| fs::rename(&tmp, file(key))).is_err() {
+        let _ = fs::remove_file(tmp);
+    }
+}
+
+/// How long an entry that isn't in the keys given to [`clean`] is kept after it was written. Entries for other
+/// thumbnail sizes and filters count as stale too, but only after this, so going back to them doesn't decode
+/// everything again. Orphans of files that are gone can't be told apart from those and go the same way.
+const UNUSED: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// A temporary file this old was left behind by a crash, younger ones may still be written to.
+const WRITING: Duration = Duration::from_secs(5 * 60);
+
+/// Removes the entries that aren't in `keys` and are older than [`UNUSED`], and leftover temporary files.
+pub fn clean(keys: HashSet<String>) {
+    let Ok(entries) = fs::read_dir(&*DIR) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let age = entry.metadata().and_then(|m| m.modified()).ok().and_then(|time| time.elapsed().ok());
+        let stale = match path.extension().and_then(|s| s.to_str()) {
+            Some("qoi") => !keys.contains(stem) && age.is_some_and(|age| age > UNUSED),
+            Some("tmp") => age.is_some_and(|age| age > WRITING),
+            _ => false,
+        };
+        if stale {
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
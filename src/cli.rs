@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::PathBuf;
+
+const HELP: &str = "\
+Usage: nanogallery [OPTIONS] [PATH]...
+
+Shows the images in the given files and folders instead of the libraries in the config.
+
+Options:
+  -c, --config <FILE>          Use FILE instead of nanogallery.cfg next to the executable
+  -s, --set <KEY=VALUE>        Override a config key for this run, can be repeated
+      --view                   Start in the viewer
+      --slideshow              Start a slideshow, see the slideshow_interval key
+      --start <IMAGE>          Open IMAGE in the viewer, or start the slideshow at it, instead of the first file in PATH
+  -h, --help                   Print this help and exit
+  -V, --version                Print the version and exit";
+
+#[derive(Copy, Clone, PartialEq, Default)]
+pub enum Mode {
+    #[default]
+    Gallery,
+    Viewer,
+    Slideshow,
+}
+
+#[derive(Default)]
+pub struct Args {
+    /// Absolute paths that replace the configured libraries, empty to use those.
+    pub paths: Vec<PathBuf>,
+    pub config: Option<PathBuf>,
+    /// Applied on top of the config file, but never saved to it.
+    pub overrides: Vec<(String, String)>,
+    pub mode: Mode,
+    /// The image the viewer or slideshow opens at.
+    pub start: Option<PathBuf>,
+    pub bench: Option<PathBuf>,
+    pub bench_width: u32,
+}
+
+/// An existing file or folder as an absolute path.
+fn existing(path: &str) -> Result<PathBuf, String> {
+    fs::canonicalize(path).map_err(|err| format!("{path}: {err}"))
+}
+
+impl Args {
+    /// Parses the arguments after the program name. `Ok(None)` means there is nothing left to do, like after `--help`.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+        let mut parsed = Args { bench_width: 500, ..Args::default() };
+        let mut args = args.into_iter();
+        let mut options = true;
+        while let Some(arg) = args.next() {
+            if !options || !arg.starts_with('-') {
+                parsed.paths.push(existing(&arg)?);
+                continue;
+            }
+            // `--option=value` works as well as `--option value`.
+            let (option, inline) = match arg.split_once('=') {
+                Some((option, value)) if option.starts_with("--") => (option.to_string(), Some(value.to_string())),
+                _ => (arg, None),
+            };
+            let mut value = || inline.clone().or_else(|| args.next()).ok_or_else(|| format!("{option} needs a value"));
+            match option.as_str() {
+                "--" => options = false,
+                "-c" | "--config" => parsed.config = Some(PathBuf::from(value()?)),
+                "-s" | "--set" => {
+                    let setting = value()?;
+                    let (key, value) =
+                        setting.split_once('=').ok_or_else(|| format!("Expected KEY=VALUE, got \"{setting}\""))?;
+                    parsed.overrides.push((key.to_string(), value.to_string()));
+                }
+                "--view" => parsed.mode = Mode::Viewer,
+                "--slideshow" => parsed.mode = Mode::Slideshow,
+                "--start" => parsed.start = Some(existing(&value()?)?),
+                // For measuring the scalers while working on them, so the help leaves them out.
+                "--bench-scale" => parsed.bench = Some(PathBuf::from(value()?)),
+                "--bench-width" => {
+                    let width = value()?;
+                    parsed.bench_width = width.parse().map_err(|_| format!("Invalid width \"{width}\""))?;
+                }
+                "-h" | "--help" => {
+                    println!("NanoGallery {}\n\n{HELP}", env!("CARGO_PKG_VERSION"));
+                    return Ok(None);
+                }
+                "-V" | "--version" => {
+                    println!("NanoGallery {}", env!("CARGO_PKG_VERSION"));
+                    return Ok(None);
+                }
+                _ => return Err(format!("Unknown option {option}")),
+            }
+        }
+        if parsed.mode == Mode::Gallery && parsed.start.is_some() {
+            parsed.mode = Mode::Viewer;
+        } else if parsed.mode != Mode::Gallery && parsed.start.is_none() {
+            parsed.start = parsed.paths.iter().find(|path| path.is_file()).cloned();
+        }
+        Ok(Some(parsed))
+    }
+}
@@ -1,82 +1,866 @@
-use std::path::Path;
+use crate::buffer::{Backdrop, Kernel};
+use crate::gui::View;
+use crate::library::{Library, Root, Sort};
+use crate::State;
+use std::fmt::Write;
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
 use std::{fs, io};
 
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Layout {
+    /// Thumbnails at their own size, wrapping to the next row when they don't fit.
+    Flow,
+    /// Rows scaled to a shared height so they exactly fill the width of the window.
+    Justified,
+}
+
+impl Layout {
+    pub const ALL: [Layout; 2] = [Layout::Flow, Layout::Justified];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Layout::Flow => "flow",
+            Layout::Justified => "justified",
+        }
+    }
+}
+
+/// A `[[library]]` section.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Source {
+    /// Relative paths are relative to the home folder.
+    pub path: String,
+    /// Pick up files that are added, removed or changed while the gallery is open.
+    pub watch: bool,
+    /// Globs for names of files and folders to leave out, like `*.tmp` or `raw`.
+    pub exclude: Vec<String>,
+}
+
+impl Source {
+    pub fn new(path: &str) -> Source {
+        Source { path: path.to_string(), watch: true, exclude: Vec::new() }
+    }
+
+    fn set(&mut self, key: &str, value: &Value) -> Result<(), String> {
+        match key {
+            "path" => self.path = value.text(key)?.to_string(),
+            "watch" => self.watch = value.bool(key)?,
+            "exclude" => self.exclude = value.list(key)?,
+            _ => {
+                return Err(format!("Unknown key {key} in [[library]], settings for the gallery go above the first one"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
 pub struct Config {
-    pub libraries: Vec<String>,
+    pub libraries: Vec<Source>,
     pub background_color: u32,
     pub text_color: u32,
     pub primary_color: u32,
     pub secondary_color: u32,
     pub pico: bool,
+    pub captions: bool,
+    /// Play animated images in the gallery while the mouse is over them.
+    pub animate_on_hover: bool,
+    pub layout: Layout,
+    pub thumbnail_size: u32,
+    /// How thumbnails are scaled. They are cached, so this can afford to be slow.
+    pub thumbnail_filter: Kernel,
+    /// How the viewer scales, every time the zoom or the window changes.
+    pub viewer_filter: Kernel,
+    /// Show transparent parts of images over a checkerboard instead of the background color.
+    pub checkerboard: bool,
+    /// How many MiB the loaded thumbnails may use before the ones that weren't drawn recently are unloaded.
+    pub memory_budget: u32,
+    /// How many screens of thumbnails to load ahead of the scroll direction.
+    pub prefetch_screens: u32,
+    /// How many threads decode images, zero uses one per CPU.
+    pub threads: u32,
+    pub sort: Sort,
+    pub sort_reverse: bool,
+    /// How many bits the hashes of two images may differ in for the duplicate finder to group them.
+    pub duplicate_threshold: u32,
+    /// How many seconds the slideshow shows every image.
+    pub slideshow_interval: u32,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
-            libraries: vec!["Pictures".to_string()],
+            libraries: vec![Source::new("Pictures")],
             background_color: 0xFF1A1A1A,
             text_color: 0xFFEEEEEE,
             primary_color: 0xFFEEEEEE,
             secondary_color: 0xFF4B4B4B,
             pico: false,
+            captions: true,
+            animate_on_hover: true,
+            layout: Layout::Flow,
+            thumbnail_size: 500,
+            thumbnail_filter: Kernel::Lanczos3,
+            viewer_filter: Kernel::Bicubic,
+            checkerboard: true,
+            memory_budget: 512,
+            prefetch_screens: 2,
+            threads: 0,
+            sort: Sort::Name,
+            sort_reverse: false,
+            duplicate_threshold: 10,
+            slideshow_interval: 5,
+        }
+    }
+}
+
+/// A value in the config file: `true`, `42`, `"text"` or a list like `["a", "b"]`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    Text(String),
+    List(Vec<Value>),
+}
+
+impl Value {
+    /// Parses the value at the start of `text` and returns what comes after it.
+    fn parse(text: &str) -> Result<(Value, &str), String> {
+        let text = text.trim_start();
+        if let Some(rest) = text.strip_prefix('"') {
+            let mut string = String::new();
+            let mut chars = rest.char_indices();
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '"' => return Ok((Value::Text(string), &rest[i + 1..])),
+                    '\\' => string.push(match chars.next() {
+                        Some((_, 'n')) => '\n',
+                        Some((_, 't')) => '\t',
+                        Some((_, c @ ('"' | '\\'))) => c,
+                        Some((_, c)) => return Err(format!("Unknown escape \\{c}, use \\\\ for a backslash")),
+                        None => break,
+                    }),
+                    c => string.push(c),
+                }
+            }
+            return Err("Text is missing its closing quote".to_string());
+        }
+        if let Some(mut rest) = text.strip_prefix('[') {
+            let mut list = Vec::new();
+            loop {
+                rest = rest.trim_start();
+                if let Some(after) = rest.strip_prefix(']') {
+                    return Ok((Value::List(list), after));
+                }
+                let (value, after) = Value::parse(rest)?;
+                list.push(value);
+                rest = after.trim_start();
+                match rest.strip_prefix(',') {
+                    Some(after) => rest = after,
+                    None if rest.starts_with(']') => {}
+                    None => return Err("Expected , or ] in the list".to_string()),
+                }
+            }
+        }
+        let end = text.find(|c: char| c.is_whitespace() || c == ',' || c == ']' || c == '#').unwrap_or(text.len());
+        let (word, rest) = text.split_at(end);
+        let value = match word {
+            "" => return Err("Missing value".to_string()),
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => {
+                let invalid = || format!("Invalid value {word}, expected true, false, a number, \"text\" or a [list]");
+                Value::Int(word.parse().map_err(|_| invalid())?)
+            }
+        };
+        Ok((value, rest))
+    }
+
+    /// The value the way the config file has it.
+    fn write(&self) -> String {
+        match self {
+            Value::Bool(value) => value.to_string(),
+            Value::Int(n) => n.to_string(),
+            Value::Text(text) => quote(text),
+            Value::List(values) => format!("[{}]", values.iter().map(Value::write).collect::<Vec<_>>().join(", ")),
+        }
+    }
+
+    /// Reads a value given on the command line, where quotes around text are optional.
+    pub fn from_arg(arg: &str) -> Value {
+        match Value::parse(arg) {
+            Ok((value, rest)) if rest.trim().is_empty() => value,
+            _ => Value::Text(arg.to_string()),
+        }
+    }
+
+    fn bool(&self, key: &str) -> Result<bool, String> {
+        match *self {
+            Value::Bool(value) => Ok(value),
+            _ => Err(format!("{key} has to be true or false")),
+        }
+    }
+
+    fn int(&self, key: &str, range: RangeInclusive<u32>) -> Result<u32, String> {
+        match *self {
+            Value::Int(n) if (*range.start() as i64..=*range.end() as i64).contains(&n) => Ok(n as u32),
+            _ => Err(format!("{key} has to be a number from {} to {}", range.start(), range.end())),
+        }
+    }
+
+    fn text(&self, key: &str) -> Result<&str, String> {
+        match self {
+            Value::Text(text) => Ok(text),
+            _ => Err(format!("{key} has to be text in quotes")),
         }
     }
+
+    fn list(&self, key: &str) -> Result<Vec<String>, String> {
+        match self {
+            Value::List(values) => values.iter().map(|value| value.text(key).map(str::to_string)).collect(),
+            _ => Err(format!("{key} has to be a list like [\"a\", \"b\"]")),
+        }
+    }
+
+    /// `#RRGGBB`, which is opaque, or `#AARRGGBB`.
+    fn color(&self, key: &str) -> Result<u32, String> {
+        let text = self.text(key)?;
+        let hex = text.strip_prefix('#').unwrap_or(text);
+        match (hex.len(), u32::from_str_radix(hex, 16)) {
+            (6, Ok(rgb)) => Ok(0xFF000000 | rgb),
+            (8, Ok(argb)) => Ok(argb),
+            _ => Err(format!("{key} has to be a color like \"#RRGGBB\" or \"#AARRGGBB\", not \"{text}\"")),
+        }
+    }
+
+    fn choice<T: Copy>(&self, key: &str, all: &[T], name: fn(T) -> &'static str) -> Result<T, String> {
+        let text = self.text(key)?;
+        let found = all.iter().copied().find(|&item| name(item) == text);
+        found.ok_or_else(|| format!("{key} has to be one of {}", names(all, name)))
+    }
+}
+
+fn names<T: Copy>(all: &[T], name: fn(T) -> &'static str) -> String {
+    all.iter().map(|&item| name(item)).collect::<Vec<_>>().join(", ")
+}
+
+/// Text in quotes, with quotes and backslashes escaped so it reads back the same.
+fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The keys of the settings that go above the library sections.
+const KEYS: [&str; 19] = [
+    "background_color",
+    "text_color",
+    "primary_color",
+    "secondary_color",
+    "pico",
+    "captions",
+    "animate_on_hover",
+    "layout",
+    "thumbnail_size",
+    "thumbnail_filter",
+    "viewer_filter",
+    "checkerboard",
+    "memory_budget",
+    "prefetch_screens",
+    "threads",
+    "sort",
+    "sort_reverse",
+    "duplicate_threshold",
+    "slideshow_interval",
+];
+
+/// Whether `line` sets `key`.
+fn sets(line: &str, key: &str) -> bool {
+    let line = line.trim_start();
+    !line.starts_with('#') && line.split_once('=').is_some_and(|(k, _)| k.trim() == key)
+}
+
+/// Whether `line` sets anything, as opposed to being blank, a comment or a section.
+fn is_setting(line: &str) -> bool {
+    let line = line.trim_start();
+    !line.is_empty() && !line.starts_with(['#', '['])
+}
+
+/// Sets `key` to `value` on the last line of `lines[range]` that sets it, keeping its indentation and comment, or
+/// adds a line after the last setting there.
+fn set_line(lines: &mut Vec<String>, range: Range<usize>, key: &str, value: &Value) {
+    let Some(at) = range.clone().rev().find(|&i| sets(&lines[i], key)) else {
+        let at = range.clone().rev().find(|&i| is_setting(&lines[i])).map_or(range.end, |i| i + 1);
+        lines.insert(at, format!("{key} = {}", value.write()));
+        return;
+    };
+    let line = &lines[at];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let after = line.split_once('=').map_or("", |(_, after)| after);
+    // A line that doesn't parse loses whatever came after its value, there is no telling where that starts.
+    let comment = match Value::parse(after) {
+        Ok((_, rest)) if rest.trim_start().starts_with('#') => rest.trim_end(),
+        _ => "",
+    };
+    lines[at] = format!("{indent}{key} = {}{comment}", value.write());
+}
+
+/// Changes `content`, which [`Config::parse`] read as `from`, so it reads as `to`. Only the lines of settings that
+/// changed are touched, so comments, the order and lines with errors all stay.
+fn update(content: &str, from: &Config, to: &Config) -> String {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let is_section = |line: &str| line.split('#').next().unwrap_or_default().trim() == "[[library]]";
+    let starts: Vec<usize> = (0..lines.len()).filter(|&i| is_section(&lines[i])).collect();
+    let ends: Vec<usize> = starts.iter().skip(1).copied().chain([lines.len()]).collect();
+
+    let sources: Vec<Option<Source>> = starts
+        .iter()
+        .zip(&ends)
+        .map(|(&start, &end)| Config::parse(&lines[start..end].join("\n")).0.libraries.pop())
+        .collect();
+    // What happens to every section: `None` leaves it alone, `Some(None)` removes it and `Some(Some(_))` edits it.
+    let mut targets: Vec<Option<Option<Source>>> = vec![None; starts.len()];
+    let mut added: Vec<&Source> = to.libraries.iter().collect();
+    // Unchanged sections are matched first, so an edit never goes to the wrong one of two with the same path.
+    let mut changed = Vec::new();
+    for (k, source) in sources.iter().enumerate() {
+        let Some(source) = source else {
+            continue;
+        };
+        match added.iter().position(|s| *s == source) {
+            Some(i) => {
+                added.remove(i);
+            }
+            None => changed.push((k, &source.path)),
+        }
+    }
+    for (k, path) in changed {
+        targets[k] = Some(added.iter().position(|s| s.path == *path).map(|i| added.remove(i).clone()));
+    }
+
+    // Going backwards keeps the line numbers of the sections that are still to come right.
+    for k in (0..starts.len()).rev() {
+        let (start, end) = (starts[k], ends[k]);
+        match (&targets[k], &sources[k]) {
+            (Some(Some(target)), Some(source)) => {
+                if target.exclude != source.exclude {
+                    let exclude = Value::List(target.exclude.iter().cloned().map(Value::Text).collect());
+                    set_line(&mut lines, start + 1..end, "exclude", &exclude);
+                }
+                if target.watch != source.watch {
+                    set_line(&mut lines, start + 1..end, "watch", &Value::Bool(target.watch));
+                }
+            }
+            (Some(None), _) => {
+                // Blank lines and comments at the end belong to whatever comes next.
+                let end = (start..end).rev().find(|&i| is_setting(&lines[i])).unwrap_or(start) + 1;
+                lines.drain(start..end);
+                let blank = |line: Option<&String>| line.is_none_or(|line| line.trim().is_empty());
+                if start > 0 && blank(lines.get(start - 1)) && blank(lines.get(start)) {
+                    lines.remove(start - 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    for source in added {
+        let exclude = Value::List(source.exclude.iter().cloned().map(Value::Text).collect());
+        lines.extend([
+            String::new(),
+            "[[library]]".to_string(),
+            format!("path = {}", quote(&source.path)),
+            format!("watch = {}", source.watch),
+            format!("exclude = {}", exclude.write()),
+        ]);
+    }
+
+    let top = lines.iter().position(|line| is_section(line)).unwrap_or(lines.len());
+    for key in KEYS {
+        if let Some(value) = to.get(key)
+            && from.get(key).as_ref() != Some(&value)
+        {
+            set_line(&mut lines, 0..top, key, &value);
+        }
+    }
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+/// Whether `content` has the `key;value` lines config files used to have.
+fn is_legacy(content: &str) -> bool {
+    content.lines().any(|line| {
+        line.split_once(';')
+            .is_some_and(|(key, _)| !key.is_empty() && key.chars().all(|c| c.is_ascii_lowercase() || c == '_'))
+    })
+}
+
+/// Reads the old format the way it used to be read, except for the `#` that saving put in front of library paths.
+fn legacy(content: &str) -> Config {
+    let mut config = Config { libraries: Vec::new(), ..Config::default() };
+    for (key, value) in content.lines().filter_map(|line| line.split_once(';')) {
+        let value = match key {
+            "library" => {
+                config.libraries.push(Source::new(value.strip_prefix('#').unwrap_or(value)));
+                continue;
+            }
+            "background_color" | "text_color" | "primary_color" | "secondary_color" => {
+                match u32::from_str_radix(value.trim_start_matches('#'), 16) {
+                    Ok(color) => Value::Text(format!("#{color:08X}")),
+                    Err(_) => continue,
+                }
+            }
+            "pico" => Value::Bool(true),
+            "sort_reverse" => Value::Bool(value == "true"),
+            "captions" | "animate_on_hover" | "checkerboard" => Value::Bool(value != "false"),
+            _ => value.parse().map(Value::Int).unwrap_or_else(|_| Value::Text(value.to_string())),
+        };
+        // Bad values were ignored before as well.
+        let _ = config.set(key, &value);
+    }
+    config
 }
 
 impl Config {
+    pub fn backdrop(&self) -> Backdrop {
+        if self.checkerboard { Backdrop::Checkerboard } else { Backdrop::Color(self.background_color) }
+    }
+
+    /// Reads the config file at `path`, printing every line that can't be used, or writes the defaults there if it
+    /// doesn't exist. Files in the old format are converted, keeping the original next to it.
     pub fn load<P: AsRef<Path>>(&mut self, path: P) {
-        let content = match fs::read_to_string(&path) {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
             Ok(content) => content,
             Err(err) => {
                 if err.kind() == io::ErrorKind::NotFound {
                     self.save(path);
+                } else {
+                    eprintln!("Failed to read {}: {}", path.display(), err);
                 }
                 return;
             }
         };
 
-        self.libraries.clear();
-        for line in content.lines() {
-            if let Some((key, value)) = line.split_once(";") {
-                match key {
-                    "library" => self.libraries.push(value.to_string()),
-                    "background_color" => {
-                        let _ = u32::from_str_radix(&value[1..], 16)
-                            .and_then(|c| Ok(self.background_color = c));
-                    }
-                    "text_color" => {
-                        let _ = u32::from_str_radix(&value[1..], 16)
-                            .and_then(|c| Ok(self.text_color = c));
-                    }
-                    "primary_color" => {
-                        let _ = u32::from_str_radix(&value[1..], 16)
-                            .and_then(|c| Ok(self.primary_color = c));
-                    }
-                    "secondary_color" => {
-                        let _ = u32::from_str_radix(&value[1..], 16)
-                            .and_then(|c| Ok(self.secondary_color = c));
-                    }
-                    "pico" => {
-                        self.pico = true;
+        if is_legacy(&content) {
+            *self = legacy(&content);
+            let mut old = path.as_os_str().to_owned();
+            old.push(".old");
+            match fs::write(&old, &content) {
+                Ok(()) => {
+                    self.save(path);
+                    let old = Path::new(&old).display();
+                    eprintln!("Converted {} to the new format, the old one is in {}", path.display(), old);
+                }
+                Err(err) => {
+                    eprintln!("Failed to keep a copy of {}, leaving it in the old format: {}", path.display(), err)
+                }
+            }
+            return;
+        }
+
+        let (config, errors) = Config::parse(&content);
+        for (line, error) in errors {
+            eprintln!("{}:{}: {}", path.display(), line, error);
+        }
+        *self = config;
+    }
+
+    /// Reads the config file format. Lines with errors are skipped and returned with their line numbers.
+    pub fn parse(content: &str) -> (Config, Vec<(usize, String)>) {
+        let mut config = Config { libraries: Vec::new(), ..Config::default() };
+        let mut errors = Vec::new();
+        // Where every library section starts, to point at the ones without a path.
+        let mut sections = Vec::new();
+        for (number, line) in content.lines().enumerate().map(|(i, line)| (i + 1, line.trim())) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                match line.split('#').next().unwrap_or_default().trim_end() {
+                    "[[library]]" => {
+                        config.libraries.push(Source::new(""));
+                        sections.push(number);
                     }
-                    _ => {}
-                };
+                    section => errors.push((number, format!("Unknown section {section}, only [[library]] exists"))),
+                }
+                continue;
+            }
+            if let Err(err) = config.parse_line(line) {
+                errors.push((number, err));
             }
         }
+        for (i, number) in sections.into_iter().enumerate().rev() {
+            if config.libraries[i].path.is_empty() {
+                config.libraries.remove(i);
+                errors.push((number, "[[library]] needs a path".to_string()));
+            }
+        }
+        errors.sort_by_key(|&(number, _)| number);
+        (config, errors)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        let (key, value) = line.split_once('=').ok_or("Expected key = value")?;
+        let key = key.trim();
+        let (value, rest) = Value::parse(value)?;
+        let rest = rest.trim_start();
+        if !rest.is_empty() && !rest.starts_with('#') {
+            return Err(format!("Unexpected {rest} after the value of {key}"));
+        }
+        match self.libraries.last_mut() {
+            Some(library) => library.set(key, &value),
+            None => self.set(key, &value),
+        }
+    }
+
+    /// Sets one of the settings that go above the library sections.
+    pub fn set(&mut self, key: &str, value: &Value) -> Result<(), String> {
+        match key {
+            "background_color" => self.background_color = value.color(key)?,
+            "text_color" => self.text_color = value.color(key)?,
+            "primary_color" => self.primary_color = value.color(key)?,
+            "secondary_color" => self.secondary_color = value.color(key)?,
+            "pico" => self.pico = value.bool(key)?,
+            "captions" => self.captions = value.bool(key)?,
+            "animate_on_hover" => self.animate_on_hover = value.bool(key)?,
+            "layout" => self.layout = value.choice(key, &Layout::ALL, Layout::name)?,
+            "thumbnail_size" => self.thumbnail_size = value.int(key, 50..=2000)?,
+            "thumbnail_filter" => self.thumbnail_filter = value.choice(key, &Kernel::ALL, Kernel::name)?,
+            "viewer_filter" => self.viewer_filter = value.choice(key, &Kernel::ALL, Kernel::name)?,
+            "checkerboard" => self.checkerboard = value.bool(key)?,
+            "memory_budget" => self.memory_budget = value.int(key, 16..=1 << 20)?,
+            "prefetch_screens" => self.prefetch_screens = value.int(key, 0..=20)?,
+            "threads" => self.threads = value.int(key, 0..=256)?,
+            "sort" => self.sort = value.choice(key, &Sort::ALL, Sort::name)?,
+            "sort_reverse" => self.sort_reverse = value.bool(key)?,
+            "duplicate_threshold" => self.duplicate_threshold = value.int(key, 0..=32)?,
+            "slideshow_interval" => self.slideshow_interval = value.int(key, 1..=3600)?,
+            _ => return Err(format!("Unknown key {key}")),
+        }
+        Ok(())
+    }
+
+    /// The value of one of the settings that go above the library sections, as [`Config::set`] takes it.
+    pub fn get(&self, key: &str) -> Option<Value> {
+        let color = |color: u32| Value::Text(format!("#{color:08X}"));
+        let number = |n: u32| Value::Int(n as i64);
+        let name = |name: &str| Value::Text(name.to_string());
+        Some(match key {
+            "background_color" => color(self.background_color),
+            "text_color" => color(self.text_color),
+            "primary_color" => color(self.primary_color),
+            "secondary_color" => color(self.secondary_color),
+            "pico" => Value::Bool(self.pico),
+            "captions" => Value::Bool(self.captions),
+            "animate_on_hover" => Value::Bool(self.animate_on_hover),
+            "layout" => name(self.layout.name()),
+            "thumbnail_size" => number(self.thumbnail_size),
+            "thumbnail_filter" => name(self.thumbnail_filter.name()),
+            "viewer_filter" => name(self.viewer_filter.name()),
+            "checkerboard" => Value::Bool(self.checkerboard),
+            "memory_budget" => number(self.memory_budget),
+            "prefetch_screens" => number(self.prefetch_screens),
+            "threads" => number(self.threads),
+            "sort" => name(self.sort.name()),
+            "sort_reverse" => Value::Bool(self.sort_reverse),
+            "duplicate_threshold" => number(self.duplicate_threshold),
+            "slideshow_interval" => number(self.slideshow_interval),
+            _ => return None,
+        })
+    }
+
+    /// The config file for these settings, which [`Config::parse`] reads back exactly.
+    pub fn text(&self) -> String {
+        let mut text = String::from("# NanoGallery settings.\n");
+        text.push_str("# Colors are \"#RRGGBB\" or \"#AARRGGBB\", text goes in quotes.\n\n");
+        let color = |color: u32| quote(&format!("#{color:08X}"));
+        let filters = names(&Kernel::ALL, Kernel::name);
+        let _ = writeln!(text, "background_color = {}", color(self.background_color));
+        let _ = writeln!(text, "text_color = {}", color(self.text_color));
+        let _ = writeln!(text, "primary_color = {}", color(self.primary_color));
+        let _ = writeln!(text, "secondary_color = {}", color(self.secondary_color));
+        let _ = writeln!(text, "pico = {}", self.pico);
+        let _ = writeln!(text, "captions = {}", self.captions);
+        let _ = writeln!(text, "animate_on_hover = {}", self.animate_on_hover);
+        let _ = writeln!(text, "layout = {}  # {}", quote(self.layout.name()), names(&Layout::ALL, Layout::name));
+        let _ = writeln!(text, "thumbnail_size = {}  # 50 to 2000", self.thumbnail_size);
+        let _ = writeln!(text, "thumbnail_filter = {}  # {filters}", quote(self.thumbnail_filter.name()));
+        let _ = writeln!(text, "viewer_filter = {}  # {filters}", quote(self.viewer_filter.name()));
+        let _ = writeln!(text, "checkerboard = {}", self.checkerboard);
+        let _ = writeln!(text, "memory_budget = {}  # MiB", self.memory_budget);
+        let _ = writeln!(text, "prefetch_screens = {}", self.prefetch_screens);
+        let _ = writeln!(text, "threads = {}  # 0 uses one per CPU", self.threads);
+        let _ = writeln!(text, "sort = {}  # {}", quote(self.sort.name()), names(&Sort::ALL, Sort::name));
+        let _ = writeln!(text, "sort_reverse = {}", self.sort_reverse);
+        let _ = writeln!(text, "duplicate_threshold = {}  # 0 to 32", self.duplicate_threshold);
+        let _ = writeln!(text, "slideshow_interval = {}  # seconds", self.slideshow_interval);
+        if !self.libraries.is_empty() {
+            text.push_str("\n# Relative paths start in the home folder.\n");
+            text.push_str("# exclude takes globs of names to leave out, like \"*.tmp\" or \"raw\".\n");
+        }
+        for library in self.libraries.iter() {
+            let exclude: Vec<String> = library.exclude.iter().map(|glob| quote(glob)).collect();
+            let _ = writeln!(text, "\n[[library]]\npath = {}", quote(&library.path));
+            let _ = writeln!(text, "watch = {}", library.watch);
+            let _ = writeln!(text, "exclude = [{}]", exclude.join(", "));
+        }
+        text
     }
 
     pub fn save<P: AsRef<Path>>(&self, path: P) {
-        let mut content = String::new();
-        for lib in self.libraries.iter() {
-            content.push_str(&*format!("library;#{lib}\n"));
-        }
-        content.push_str(&*format!("background_color;#{:X}\n", self.background_color));
-        content.push_str(&*format!("text_color;#{:X}\n", self.text_color));
-        content.push_str(&*format!("primary_color;#{:X}\n", self.primary_color));
-        content.push_str(&*format!("secondary_color;#{:X}\n", self.secondary_color));
-        if self.pico {
-            content.push_str("pico;\n");
-        }
-        fs::write(path, content).unwrap_or_else(|err| eprintln!("Failed to save config: {}", err));
+        fs::write(path, self.text()).unwrap_or_else(|err| eprintln!("Failed to save config: {}", err));
+    }
+}
+
+/// Reads the config file again after it changed on disk and applies whatever is different, keeping the command line
+/// overrides.
+pub fn reload(state: &mut State) {
+    let content = match fs::read_to_string(&state.config_path) {
+        Ok(content) if !is_legacy(&content) => content,
+        _ => return,
+    };
+    let (mut config, errors) = Config::parse(&content);
+    for (line, error) in errors {
+        eprintln!("{}:{}: {}", state.config_path.display(), line, error);
+    }
+    for (key, value) in state.args.overrides.iter() {
+        let _ = config.set(key, &Value::from_arg(value));
+    }
+    if config != state.config {
+        let old = std::mem::replace(&mut state.config, config);
+        apply(state, &old);
+    }
+}
+
+/// Changes the settings from inside the app, applying `change` right away and saving it to the config file. A command
+/// line override of a setting that changed is dropped, and the others stay out of the file.
+pub fn edit(state: &mut State, change: impl Fn(&mut Config)) {
+    let old = state.config.clone();
+    change(&mut state.config);
+    if state.config == old {
+        return;
+    }
+    let config = &state.config;
+    state.args.overrides.retain(|(key, value)| {
+        let mut overridden = config.clone();
+        let _ = overridden.set(key, &Value::from_arg(value));
+        overridden == *config
+    });
+    // Only what changed is written, so the comments and formatting of the file stay.
+    match fs::read_to_string(&state.config_path) {
+        Ok(content) if !is_legacy(&content) => {
+            let from = Config::parse(&content).0;
+            let mut to = from.clone();
+            change(&mut to);
+            if let Err(err) = fs::write(&state.config_path, update(&content, &from, &to)) {
+                eprintln!("Failed to save config: {}", err);
+            }
+        }
+        _ => state.config.save(&state.config_path),
+    }
+    apply(state, &old);
+}
+
+/// Updates everything that was set up from `old` to the current config.
+fn apply(state: &mut State, old: &Config) {
+    let new = state.config.clone();
+    if new.threads != old.threads {
+        eprintln!("The number of threads changes after a restart");
+    }
+    if new.thumbnail_filter != old.thumbnail_filter {
+        state.library.kernel = new.thumbnail_filter;
+        for image in state.library.images.iter_mut() {
+            image.unload();
+        }
+    }
+    if new.thumbnail_size != old.thumbnail_size {
+        Library::set_thumbnail_size(state, new.thumbnail_size);
+    }
+    // Paths on the command line replace the libraries, so there is nothing to update.
+    if new.libraries != old.libraries && state.args.paths.is_empty() {
+        libraries(state, &old.libraries);
+    }
+    if new.sort != old.sort || new.sort_reverse != old.sort_reverse {
+        state.library.sort = new.sort;
+        state.library.reverse = new.sort_reverse;
+        let moved = state.library.sort();
+        View::sorted(state, &moved);
+    }
+    View::resize(state);
+    state.update();
+}
+
+/// Brings the library roots in line with the `[[library]]` sections that changed from `old`.
+fn libraries(state: &mut State, old: &[Source]) {
+    let root = |source: &Source| Root::new(Library::resolve(PathBuf::from(&source.path)), &source.exclude);
+    let new = state.config.libraries.clone();
+    // A section that only changed what it excludes is taken out and put back in.
+    let same = |a: &Source, b: &Source| a.path == b.path && a.exclude == b.exclude;
+    let watched = |sources: &[Source], source: &Source| sources.iter().any(|s| s.watch && s.path == source.path);
+
+    let mut changes = Vec::new();
+    for source in old.iter().filter(|s| !new.iter().any(|n| same(s, n))) {
+        changes.extend(state.library.remove(&root(source).path));
+    }
+    state.library.roots = new.iter().map(root).collect();
+    state.library.save_index();
+
+    if let Some(watcher) = &state.watcher {
+        for source in old.iter().filter(|s| s.watch && !watched(&new, s)) {
+            watcher.remove(&root(source).path);
+        }
+        for source in new.iter().filter(|n| n.watch && !watched(old, n)) {
+            watcher.add(&root(source).path);
+        }
+    }
+    View::changed(state, &changes);
+    let added = new.iter().filter(|n| !old.iter().any(|s| same(s, n))).map(|source| root(source).path).collect();
+    Library::add(state, added);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = "\
+# My settings
+captions = false  # too busy
+layout = flow
+thumbnail_size = 300
+
+[[library]]
+path = \"Pictures\"  # the main one
+exclude = [\"*.tmp\"]
+
+# Phone backups
+[[library]]
+path = \"Phone\"
+watch = false
+";
+
+    #[test]
+    fn text_reads_back_the_same() {
+        let config = Config::default();
+        assert_eq!(Config::parse(&config.text()), (config, Vec::new()));
+
+        let mut library = Source::new("Photos/\"Best\" \\ of\tall");
+        library.watch = false;
+        library.exclude = vec!["*.tmp".to_string(), "raw".to_string()];
+        let config = Config {
+            libraries: vec![library, Source::new("/mnt/archive")],
+            background_color: 0x80102030,
+            pico: true,
+            layout: Layout::Justified,
+            thumbnail_size: 320,
+            viewer_filter: Kernel::Nearest,
+            sort: Sort::Taken,
+            sort_reverse: true,
+            slideshow_interval: 12,
+            ..Config::default()
+        };
+        assert_eq!(Config::parse(&config.text()), (config, Vec::new()));
+    }
+
+    #[test]
+    fn parse_values() {
+        let (config, errors) = Config::parse(
+            "text_color = \"#123456\"\nthumbnail_size=250 # small\n  captions = false\n\n\
+             [[library]] # first\npath = \"A\"\n",
+        );
+        assert_eq!(errors, Vec::new());
+        assert_eq!(config.text_color, 0xFF123456);
+        assert_eq!(config.thumbnail_size, 250);
+        assert!(!config.captions);
+        assert_eq!(config.libraries, [Source::new("A")]);
+    }
+
+    #[test]
+    fn parse_reports_lines_and_keeps_going() {
+        let (config, errors) = Config::parse(
+            "colour = 1\nthumbnail_size = 10\nlayout = flow\npico = true false\ncaptions = false\n\
+             [settings]\n[[library]]\nwatch = false\n[[library]]\npath = \"B\"\nsort = \"name\"\n",
+        );
+        let lines: Vec<usize> = errors.iter().map(|&(line, _)| line).collect();
+        assert_eq!(lines, [1, 2, 3, 4, 6, 7, 11]);
+        assert_eq!(errors[0].1, "Unknown key colour");
+        assert_eq!(errors[1].1, "thumbnail_size has to be a number from 50 to 2000");
+        assert_eq!(errors[2].1, "Invalid value flow, expected true, false, a number, \"text\" or a [list]");
+        assert_eq!(errors[4].1, "Unknown section [settings], only [[library]] exists");
+        assert_eq!(errors[5].1, "[[library]] needs a path");
+        // Everything that did parse still applies.
+        assert!(!config.captions);
+        assert_eq!(config.thumbnail_size, Config::default().thumbnail_size);
+        assert_eq!(config.libraries, [Source::new("B")]);
+    }
+
+    #[test]
+    fn migrates_the_old_format() {
+        let old = "library;#Pictures\nlibrary;/mnt/photos\nbackground_color;#FF202020\npico;\ncaptions;false\n\
+                   sort_reverse;true\nthumbnail_size;300\nlayout;justified\nunknown;1\nthreads;lots\n";
+        assert!(is_legacy(old));
+        assert!(!is_legacy(&Config::default().text()));
+        let config = legacy(old);
+        assert_eq!(config.libraries, [Source::new("Pictures"), Source::new("/mnt/photos")]);
+        assert_eq!(config.background_color, 0xFF202020);
+        assert!(config.pico);
+        assert!(!config.captions);
+        assert!(config.sort_reverse);
+        assert_eq!(config.thumbnail_size, 300);
+        assert_eq!(config.layout, Layout::Justified);
+        assert_eq!(config.threads, Config::default().threads);
+        assert_eq!(Config::parse(&config.text()), (config, Vec::new()));
+    }
+
+    fn updated(change: impl Fn(&mut Config)) -> String {
+        let from = Config::parse(FILE).0;
+        let mut to = from.clone();
+        change(&mut to);
+        let text = update(FILE, &from, &to);
+        assert_eq!(Config::parse(&text).0, to);
+        text
+    }
+
+    #[test]
+    fn update_keeps_comments_and_errors() {
+        let text = updated(|config| config.thumbnail_size = 400);
+        assert_eq!(text, FILE.replace("thumbnail_size = 300", "thumbnail_size = 400"));
+        let text = updated(|config| config.captions = true);
+        assert_eq!(text, FILE.replace("captions = false  # too busy", "captions = true  # too busy"));
+    }
+
+    #[test]
+    fn update_adds_missing_keys_after_the_others() {
+        let text = updated(|config| config.pico = true);
+        assert_eq!(text, FILE.replace("thumbnail_size = 300\n", "thumbnail_size = 300\npico = true\n"));
+    }
+
+    #[test]
+    fn update_edits_and_removes_sections() {
+        let text = updated(|config| config.libraries[1].watch = true);
+        assert_eq!(text, FILE.replace("watch = false", "watch = true"));
+        let text = updated(|config| config.libraries[0].exclude.push("raw".to_string()));
+        assert_eq!(text, FILE.replace("[\"*.tmp\"]", "[\"*.tmp\", \"raw\"]"));
+        let text = updated(|config| {
+            config.libraries.remove(1);
+        });
+        let section = "[[library]]\npath = \"Phone\"\nwatch = false\n";
+        assert_eq!(text, FILE.replace(section, ""));
+    }
+
+    #[test]
+    fn update_appends_sections() {
+        let text = updated(|config| config.libraries.push(Source::new("Scans")));
+        assert_eq!(text, format!("{FILE}\n[[library]]\npath = \"Scans\"\nwatch = true\nexclude = []\n"));
     }
 }
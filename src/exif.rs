@@ -0,0 +1,465 @@
+use allocative::Allocative;
+
+/// The parts of the EXIF metadata we care about.
+#[derive(Clone, Debug, PartialEq, Allocative)]
+pub struct Exif {
+    /// When the photo was taken, as the digits of `YYYYMMDDhhmmss` so it sorts naturally.
+    pub taken: Option<u64>,
+    /// How the stored pixels have to be transformed to show the image upright, from 1 to 8.
+    pub orientation: u8,
+    pub camera: Option<String>,
+    pub lens: Option<String>,
+    /// Exposure time in seconds.
+    pub exposure: Option<f32>,
+    pub aperture: Option<f32>,
+    pub iso: Option<u32>,
+    /// Focal length in millimeters.
+    pub focal_length: Option<f32>,
+    /// Latitude and longitude in degrees.
+    pub location: Option<(f64, f64)>,
+}
+
+impl Default for Exif {
+    fn default() -> Exif {
+        Exif {
+            taken: None,
+            orientation: 1,
+            camera: None,
+            lens: None,
+            exposure: None,
+            aperture: None,
+            iso: None,
+            focal_length: None,
+            location: None,
+        }
+    }
+}
+
+const EXIF_IFD: u16 = 0x8769;
+const GPS_IFD: u16 = 0x8825;
+const MAKE: u16 = 0x010F;
+const MODEL: u16 = 0x0110;
+const ORIENTATION: u16 = 0x0112;
+const DATE_TIME: u16 = 0x0132;
+const EXPOSURE_TIME: u16 = 0x829A;
+const F_NUMBER: u16 = 0x829D;
+const ISO: u16 = 0x8827;
+const DATE_TIME_ORIGINAL: u16 = 0x9003;
+const FOCAL_LENGTH: u16 = 0x920A;
+const LENS_MODEL: u16 = 0xA434;
+const GPS_LATITUDE_REF: u16 = 0x0001;
+const GPS_LATITUDE: u16 = 0x0002;
+const GPS_LONGITUDE_REF: u16 = 0x0003;
+const GPS_LONGITUDE: u16 = 0x0004;
+
+/// How many columns [`Exif::columns`] writes.
+pub const COLUMNS: usize = 10;
+
+impl Exif {
+    /// Parses the TIFF structure that EXIF data is stored in, ignoring anything that doesn't make sense.
+    pub fn parse(data: &[u8]) -> Exif {
+        let data = data.strip_prefix(b"Exif\0\0").unwrap_or(data);
+        let mut exif = Exif::default();
+        let Some(tiff) = Tiff::new(data) else {
+            return exif;
+        };
+
+        let ifd0 = tiff.u32(4).map(|offset| tiff.fields(offset as usize)).unwrap_or_default();
+        let sub_ifd = |tag: u16| {
+            ifd0.iter()
+                .find(|f| f.tag == tag)
+                .map(|f| tiff.fields(f.value as usize))
+                .unwrap_or_default()
+        };
+        let sub = sub_ifd(EXIF_IFD);
+        let gps = sub_ifd(GPS_IFD);
+
+        let find = |tag: u16| sub.iter().chain(ifd0.iter()).find(|f| f.tag == tag);
+        let text = |tag: u16| find(tag).and_then(|f| tiff.ascii(f)).filter(|s| !s.is_empty());
+        let number = |tag: u16| find(tag).and_then(|f| tiff.number(f, 0));
+
+        exif.taken = text(DATE_TIME_ORIGINAL).or_else(|| text(DATE_TIME)).and_then(parse_date);
+        exif.orientation = number(ORIENTATION).filter(|o| (1.0..=8.0).contains(o)).map_or(1, |o| o as u8);
+        // Most cameras repeat the make in the model, like "Canon" and "Canon EOS 80D".
+        exif.camera = match (text(MAKE), text(MODEL)) {
+            (Some(make), Some(model)) if !model.starts_with(make) => Some(format!("{make} {model}")),
+            (_, Some(model)) => Some(model.to_string()),
+            (make, None) => make.map(str::to_string),
+        };
+        exif.lens = text(LENS_MODEL).map(str::to_string);
+        exif.exposure = number(EXPOSURE_TIME).filter(|&t| t > 0.0).map(|t| t as f32);
+        exif.aperture = number(F_NUMBER).filter(|&f| f > 0.0).map(|f| f as f32);
+        exif.iso = number(ISO).filter(|&iso| iso > 0.0).map(|iso| iso as u32);
+        exif.focal_length = number(FOCAL_LENGTH).filter(|&f| f > 0.0).map(|f| f as f32);
+
+        let find = |tag: u16| gps.iter().find(|f| f.tag == tag);
+        let coordinate = |tag: u16, reference: u16, negative: &str| {
+            let field = find(tag)?;
+            let degrees = tiff.number(field, 0)? + tiff.number(field, 1)? / 60.0 + tiff.number(field, 2)? / 3600.0;
+            let negative = find(reference).and_then(|f| tiff.ascii(f)) == Some(negative);
+            Some(if negative { -degrees } else { degrees })
+        };
+        exif.location = coordinate(GPS_LATITUDE, GPS_LATITUDE_REF, "S")
+            .zip(coordinate(GPS_LONGITUDE, GPS_LONGITUDE_REF, "W"))
+            .filter(|&(lat, lon)| lat.abs() <= 90.0 && lon.abs() <= 180.0);
+        exif
+    }
+
+    /// Whether the image is shown rotated by 90 degrees, so its width and height are swapped.
+    pub fn transposed(&self) -> bool {
+        self.orientation >= 5
+    }
+
+    /// A single line describing how the photo was taken, empty if nothing is known.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        parts.extend(self.camera.clone());
+        parts.extend(self.lens.clone());
+        if let Some(t) = self.exposure {
+            parts.push(if t < 0.5 { format!("1/{:.0} s", 1.0 / t) } else { format!("{t:.1} s") });
+        }
+        parts.extend(self.aperture.map(|f| format!("f/{f:.1}")));
+        parts.extend(self.iso.map(|iso| format!("ISO {iso}")));
+        parts.extend(self.focal_length.map(|f| format!("{f:.0} mm")));
+        parts.extend(self.location.map(|(lat, lon)| format!("{lat:.5}, {lon:.5}")));
+        parts.join(" · ")
+    }
+
+    /// Tab-separated values for the library index, empty where nothing is known.
+    pub fn columns(&self) -> String {
+        fn column<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(T::to_string).unwrap_or_default()
+        }
+        let clean = |text: &Option<String>| column(text).replace(['\t', '\n', '\r'], " ");
+        [
+            column(&self.taken),
+            self.orientation.to_string(),
+            clean(&self.camera),
+            clean(&self.lens),
+            column(&self.exposure),
+            column(&self.aperture),
+            column(&self.iso),
+            column(&self.focal_length),
+            column(&self.location.map(|l| l.0)),
+            column(&self.location.map(|l| l.1)),
+        ]
+        .join("\t")
+    }
+
+    /// Reads what [`Exif::columns`] wrote, returns `None` if anything doesn't parse.
+    pub fn from_columns<'a>(columns: &mut impl Iterator<Item = &'a str>) -> Option<Exif> {
+        fn parse<T: std::str::FromStr>(column: Option<&str>) -> Option<Option<T>> {
+            match column? {
+                "" => Some(None),
+                value => value.parse().ok().map(Some),
+            }
+        }
+        let text = |column: Option<&str>| column.map(|c| Some(c.to_string()).filter(|c| !c.is_empty()));
+        Some(Exif {
+            taken: parse(columns.next())?,
+            orientation: columns.next()?.parse().ok().filter(|o| (1..=8).contains(o))?,
+            camera: text(columns.next())?,
+            lens: text(columns.next())?,
+            exposure: parse(columns.next())?,
+            aperture: parse(columns.next())?,
+            iso: parse(columns.next())?,
+            focal_length: parse(columns.next())?,
+            location: parse::<f64>(columns.next())?.zip(parse::<f64>(columns.next())?),
+        })
+    }
+}
+
+/// Turns `YYYY:MM:DD hh:mm:ss` into `YYYYMMDDhhmmss`.
+fn parse_date(date: &str) -> Option<u64> {
+    let digits: String = date.chars().filter(char::is_ascii_digit).collect();
+    if digits.len() != 14 || digits.starts_with("0000") {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+struct Field {
+    tag: u16,
+    kind: u16,
+    count: u32,
+    /// The value itself if it fits in four bytes, otherwise where to find it.
+    value: u32,
+    /// Where the value field of this entry is, for values that are stored inline.
+    at: usize,
+}
+
+struct Tiff<'a> {
+    data: &'a [u8],
+    little: bool,
+}
+
+impl<'a> Tiff<'a> {
+    fn new(data: &'a [u8]) -> Option<Tiff<'a>> {
+        let little = match data.get(0..2)? {
+            b"II" => true,
+            b"MM" => false,
+            _ => return None,
+        };
+        Some(Tiff { data, little })
+    }
+
+    fn u16(&self, at: usize) -> Option<u16> {
+        let bytes = self.data.get(at..at + 2)?.try_into().ok()?;
+        Some(if self.little { u16::from_le_bytes(bytes) } else { u16::from_be_bytes(bytes) })
+    }
+
+    fn u32(&self, at: usize) -> Option<u32> {
+        let bytes = self.data.get(at..at + 4)?.try_into().ok()?;
+        Some(if self.little { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+    }
+
+    fn fields(&self, offset: usize) -> Vec<Field> {
+        let count = self.u16(offset).unwrap_or(0) as usize;
+        (0..count)
+            .filter_map(|i| {
+                let at = offset + 2 + i * 12;
+                Some(Field {
+                    tag: self.u16(at)?,
+                    kind: self.u16(at + 2)?,
+                    count: self.u32(at + 4)?,
+                    value: self.u32(at + 8)?,
+                    at: at + 8,
+                })
+            })
+            .collect()
+    }
+
+    /// Where the values of `field` start, if they are all inside the data.
+    fn start(&self, field: &Field, size: usize) -> Option<usize> {
+        let len = field.count as usize * size;
+        let start = if len <= 4 { field.at } else { field.value as usize };
+        (start + len <= self.data.len()).then_some(start)
+    }
+
+    fn bytes(&self, field: &Field, size: usize) -> Option<&'a [u8]> {
+        let start = self.start(field, size)?;
+        self.data.get(start..start + field.count as usize * size)
+    }
+
+    /// Reads the `i`th value of a numeric field, turning rationals into floats.
+    fn number(&self, field: &Field, i: usize) -> Option<f64> {
+        if i >= field.count as usize {
+            return None;
+        }
+        let size = match field.kind {
+            3 => 2,
+            4 => 4,
+            5 | 10 => 8,
+            _ => return None,
+        };
+        let at = self.start(field, size)? + i * size;
+        let number = match field.kind {
+            3 => self.u16(at)? as f64,
+            4 => self.u32(at)? as f64,
+            5 => self.u32(at)? as f64 / self.u32(at + 4)? as f64,
+            _ => self.u32(at)? as i32 as f64 / self.u32(at + 4)? as i32 as f64,
+        };
+        number.is_finite().then_some(number)
+    }
+
+    fn ascii(&self, field: &Field) -> Option<&'a str> {
+        if field.kind != 2 {
+            return None;
+        }
+        let bytes = self.bytes(field, 1)?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        std::str::from_utf8(&bytes[..end]).ok().map(str::trim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes TIFF data in either byte order, one IFD at a time.
+    struct Writer {
+        little: bool,
+        data: Vec<u8>,
+    }
+
+    /// A tag, its type and count, and its values already in the byte order of the file.
+    type Entry = (u16, u16, u32, Vec<u8>);
+
+    impl Writer {
+        fn new(little: bool) -> Writer {
+            let mut writer = Writer { little, data: if little { b"II".to_vec() } else { b"MM".to_vec() } };
+            writer.data.extend(writer.u16(42));
+            writer.data.extend([0; 4]);
+            writer
+        }
+
+        fn u16(&self, n: u16) -> [u8; 2] {
+            if self.little { n.to_le_bytes() } else { n.to_be_bytes() }
+        }
+
+        fn u32(&self, n: u32) -> [u8; 4] {
+            if self.little { n.to_le_bytes() } else { n.to_be_bytes() }
+        }
+
+        fn ascii(&self, tag: u16, text: &str) -> Entry {
+            let mut bytes = text.as_bytes().to_vec();
+            bytes.push(0);
+            (tag, 2, bytes.len() as u32, bytes)
+        }
+
+        fn short(&self, tag: u16, n: u16) -> Entry {
+            (tag, 3, 1, self.u16(n).to_vec())
+        }
+
+        fn long(&self, tag: u16, n: u32) -> Entry {
+            (tag, 4, 1, self.u32(n).to_vec())
+        }
+
+        fn rationals(&self, tag: u16, values: &[(u32, u32)]) -> Entry {
+            let bytes = values.iter().flat_map(|&(n, d)| [self.u32(n), self.u32(d)]).flatten().collect();
+            (tag, 5, values.len() as u32, bytes)
+        }
+
+        /// Appends an IFD with its values after it and returns where it starts.
+        fn ifd(&mut self, entries: &[Entry]) -> u32 {
+            let start = self.data.len();
+            let mut values = start + 2 + entries.len() * 12 + 4;
+            let mut after: Vec<u8> = Vec::new();
+            self.data.extend(self.u16(entries.len() as u16));
+            for (tag, kind, count, bytes) in entries {
+                self.data.extend(self.u16(*tag));
+                self.data.extend(self.u16(*kind));
+                self.data.extend(self.u32(*count));
+                if bytes.len() <= 4 {
+                    self.data.extend(bytes);
+                    self.data.extend(vec![0; 4 - bytes.len()]);
+                } else {
+                    self.data.extend(self.u32(values as u32));
+                    after.extend(bytes);
+                    values += bytes.len();
+                }
+            }
+            self.data.extend([0; 4]);
+            self.data.extend(after);
+            start as u32
+        }
+
+        /// Makes the IFD at `offset` the first one.
+        fn finish(mut self, offset: u32) -> Vec<u8> {
+            let offset = self.u32(offset);
+            self.data[4..8].copy_from_slice(&offset);
+            self.data
+        }
+    }
+
+    fn photo(little: bool) -> Vec<u8> {
+        let mut w = Writer::new(little);
+        let sub = [
+            w.rationals(EXPOSURE_TIME, &[(1, 250)]),
+            w.rationals(F_NUMBER, &[(28, 10)]),
+            w.short(ISO, 400),
+            w.ascii(DATE_TIME_ORIGINAL, "2023:06:14 18:30:05"),
+            w.rationals(FOCAL_LENGTH, &[(50, 1)]),
+            w.ascii(LENS_MODEL, "EF50mm f/1.8 STM"),
+        ];
+        let sub = w.ifd(&sub);
+        let gps = [
+            w.ascii(GPS_LATITUDE_REF, "N"),
+            w.rationals(GPS_LATITUDE, &[(52, 1), (30, 1), (36, 1)]),
+            w.ascii(GPS_LONGITUDE_REF, "W"),
+            w.rationals(GPS_LONGITUDE, &[(13, 1), (24, 1), (0, 1)]),
+        ];
+        let gps = w.ifd(&gps);
+        let ifd0 = [
+            w.ascii(MAKE, "Canon"),
+            w.ascii(MODEL, "Canon EOS 80D"),
+            w.short(ORIENTATION, 6),
+            w.ascii(DATE_TIME, "2024:01:01 00:00:00"),
+            w.long(EXIF_IFD, sub),
+            w.long(GPS_IFD, gps),
+        ];
+        let ifd0 = w.ifd(&ifd0);
+        w.finish(ifd0)
+    }
+
+    fn expected() -> Exif {
+        Exif {
+            taken: Some(20230614183005),
+            orientation: 6,
+            camera: Some("Canon EOS 80D".to_string()),
+            lens: Some("EF50mm f/1.8 STM".to_string()),
+            exposure: Some(1.0 / 250.0),
+            aperture: Some(2.8),
+            iso: Some(400),
+            focal_length: Some(50.0),
+            location: Some((52.51, -13.4)),
+        }
+    }
+
+    #[test]
+    fn both_byte_orders() {
+        for little in [true, false] {
+            let exif = Exif::parse(&photo(little));
+            assert_eq!(exif, expected(), "little endian: {little}");
+            assert!(exif.transposed());
+        }
+        let mut prefixed = b"Exif\0\0".to_vec();
+        prefixed.extend(photo(false));
+        assert_eq!(Exif::parse(&prefixed), expected());
+    }
+
+    #[test]
+    fn invalid_orientations_are_upright() {
+        for little in [true, false] {
+            for orientation in [0, 9, 264, 0xFFFF] {
+                let mut w = Writer::new(little);
+                let entries = [w.short(ORIENTATION, orientation)];
+                let ifd0 = w.ifd(&entries);
+                assert_eq!(Exif::parse(&w.finish(ifd0)).orientation, 1, "{orientation}, little endian: {little}");
+            }
+        }
+    }
+
+    #[test]
+    fn truncated_data_only_loses_what_is_cut_off() {
+        for little in [true, false] {
+            let data = photo(little);
+            for len in 0..data.len() {
+                let exif = Exif::parse(&data[..len]);
+                assert!((1..=8).contains(&exif.orientation));
+                // Values either come through whole or not at all, the model may be cut off without the make.
+                assert!([None, Some("Canon"), Some("Canon EOS 80D")].contains(&exif.camera.as_deref()));
+                assert!(exif.lens.is_none() || exif.lens == expected().lens);
+                assert!(exif.location.is_none() || exif.location == expected().location);
+            }
+        }
+    }
+
+    #[test]
+    fn nonsense_is_ignored() {
+        assert_eq!(Exif::parse(b""), Exif::default());
+        assert_eq!(Exif::parse(b"JFIF\0\0\0\0"), Exif::default());
+
+        let mut w = Writer::new(true);
+        let entries = [
+            w.short(ORIENTATION, 9),
+            w.ascii(DATE_TIME, "0000:00:00 00:00:00"),
+            // A make that claims to go on far past the end of the data.
+            (MAKE, 2, 1000, w.u32(40).to_vec()),
+            w.rationals(F_NUMBER, &[(28, 0)]),
+            w.long(EXIF_IFD, 1 << 30),
+        ];
+        let ifd0 = w.ifd(&entries);
+        let exif = Exif::parse(&w.finish(ifd0));
+        assert_eq!(exif, Exif::default());
+    }
+
+    #[test]
+    fn columns_read_back() {
+        let exif = expected();
+        let columns = exif.columns();
+        assert_eq!(Exif::from_columns(&mut columns.split('\t')), Some(exif));
+        let columns = Exif::default().columns();
+        assert_eq!(Exif::from_columns(&mut columns.split('\t')), Some(Exif::default()));
+    }
+}
@@ -0,0 +1,296 @@
+use crate::gui::View;
+use crate::xmp;
+use crate::{Library, State};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// Something that was done to a file, with enough information to undo it.
+pub enum Operation {
+    /// `info` is the freedesktop `.trashinfo` file, if the trash has one.
+    Trashed { from: PathBuf, to: PathBuf, info: Option<PathBuf> },
+    Moved { from: PathBuf, to: PathBuf },
+    Copied { to: PathBuf },
+    Renamed { from: PathBuf, to: PathBuf },
+}
+
+impl Operation {
+    pub fn undo(&self) -> io::Result<()> {
+        match self {
+            Operation::Trashed { from, to, info } => {
+                move_file(to, from)?;
+                if let Some(info) = info {
+                    let _ = fs::remove_file(info);
+                }
+                Ok(())
+            }
+            Operation::Moved { from, to } | Operation::Renamed { from, to } => move_file(to, from),
+            Operation::Copied { to } => fs::remove_file(to),
+        }
+    }
+
+    /// The paths that disappeared and appeared, swapped when it was undone.
+    fn paths(&self, undone: bool) -> (Vec<&Path>, Vec<&Path>) {
+        let (removed, added) = match self {
+            Operation::Trashed { from, .. } => (vec![from.as_path()], vec![]),
+            Operation::Moved { from, to } | Operation::Renamed { from, to } => (vec![from.as_path()], vec![to.as_path()]),
+            Operation::Copied { to } => (vec![], vec![to.as_path()]),
+        };
+        if undone { (added, removed) } else { (removed, added) }
+    }
+}
+
+/// Does to `sidecar` what `op` did to its image.
+fn follow(op: &Operation, sidecar: &Path) -> io::Result<Operation> {
+    match op {
+        Operation::Trashed { .. } => trash(sidecar),
+        Operation::Moved { to, .. } => {
+            let to = xmp::sidecar_for(sidecar, to);
+            move_file(sidecar, &to)?;
+            Ok(Operation::Moved { from: sidecar.to_path_buf(), to })
+        }
+        Operation::Renamed { to, .. } => {
+            let to = xmp::sidecar_for(sidecar, to);
+            move_file(sidecar, &to)?;
+            Ok(Operation::Renamed { from: sidecar.to_path_buf(), to })
+        }
+        Operation::Copied { to } => {
+            let to = xmp::sidecar_for(sidecar, to);
+            if to.exists() {
+                return Err(io::Error::new(ErrorKind::AlreadyExists, format!("{} already exists", to.display())));
+            }
+            fs::copy(sidecar, &to)?;
+            Ok(Operation::Copied { to })
+        }
+    }
+}
+
+/// Renames `from` to `to` without replacing anything, copying when they are on different file systems.
+fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if to.exists() {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, format!("{} already exists", to.display())));
+    }
+    match fs::rename(from, to) {
+        Err(err) if err.kind() == ErrorKind::CrossesDevices => {
+            fs::copy(from, to)?;
+            fs::remove_file(from)
+        }
+        result => result,
+    }
+}
+
+pub fn transfer(path: &Path, dir: &Path, copy: bool) -> io::Result<Operation> {
+    let name = path.file_name().ok_or_else(|| io::Error::from(ErrorKind::InvalidInput))?;
+    let to = dir.join(name);
+    if copy {
+        if to.exists() {
+            return Err(io::Error::new(ErrorKind::AlreadyExists, format!("{} already exists", to.display())));
+        }
+        fs::copy(path, &to)?;
+        Ok(Operation::Copied { to })
+    } else {
+        move_file(path, &to)?;
+        Ok(Operation::Moved { from: path.to_path_buf(), to })
+    }
+}
+
+pub fn rename(path: &Path, name: &str) -> io::Result<Operation> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return Err(io::Error::new(ErrorKind::InvalidInput, format!("{name:?} is not a file name")));
+    }
+    let to = path.with_file_name(name);
+    move_file(path, &to)?;
+    Ok(Operation::Renamed { from: path.to_path_buf(), to })
+}
+
+/// Picks a file name for `path` that isn't taken yet, like `photo 2.jpg`. `taken` also sees the
+/// candidate and can claim it, so two processes don't pick the same one.
+#[cfg(unix)]
+fn unique_name(path: &Path, mut taken: impl FnMut(&str) -> bool) -> Option<String> {
+    let stem = path.file_stem()?.to_string_lossy();
+    let extension = path.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    (1..10000)
+        .map(|n| if n == 1 { format!("{stem}{extension}") } else { format!("{stem} {n}{extension}") })
+        .find(|name| !taken(name))
+}
+
+/// Moves `path` to the trash of the user, following the freedesktop.org trash specification.
+#[cfg(all(unix, not(target_os = "macos")))]
+pub fn trash(path: &Path) -> io::Result<Operation> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt, OpenOptionsExt};
+
+    let original = path.to_path_buf();
+    let path = fs::canonicalize(path)?;
+    let device = fs::metadata(&path)?.dev();
+    let home = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "HOME is not set"))?;
+
+    // Files on other file systems go to a trash at the top of that file system, so they don't have to be copied.
+    let trash = if fs::metadata(&home).is_ok_and(|meta| meta.dev() == device) {
+        home.join("Trash")
+    } else {
+        let mut top = path.as_path();
+        while let Some(parent) = top.parent()
+            && fs::metadata(parent).is_ok_and(|meta| meta.dev() == device)
+        {
+            top = parent;
+        }
+        let uid = fs::metadata("/proc/self").map(|meta| meta.uid())?;
+        top.join(format!(".Trash-{uid}"))
+    };
+    let (files, info) = (trash.join("files"), trash.join("info"));
+    for dir in [&files, &info] {
+        fs::DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+    }
+
+    let mut claimed = None;
+    let name = unique_name(&path, |name| {
+        if files.join(name).exists() {
+            return true;
+        }
+        let file = info.join(format!("{name}.trashinfo"));
+        match fs::OpenOptions::new().write(true).create_new(true).mode(0o600).open(&file) {
+            Ok(handle) => {
+                claimed = Some((file, handle));
+                false
+            }
+            Err(_) => true,
+        }
+    });
+    let (Some(name), Some((info_file, mut handle))) = (name, claimed) else {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "no free name in the trash"));
+    };
+
+    let content = format!("[Trash Info]\nPath={}\nDeletionDate={}\n", url_encode(&path), timestamp());
+    let to = files.join(&name);
+    let result = io::Write::write_all(&mut handle, content.as_bytes()).and_then(|_| move_file(&path, &to));
+    if let Err(err) = result {
+        let _ = fs::remove_file(&info_file);
+        return Err(err);
+    }
+    Ok(Operation::Trashed { from: original, to, info: Some(info_file) })
+}
+
+#[cfg(target_os = "macos")]
+pub fn trash(path: &Path) -> io::Result<Operation> {
+    let home = std::env::var_os("HOME").ok_or_else(|| io::Error::new(ErrorKind::NotFound, "HOME is not set"))?;
+    let trash = PathBuf::from(home).join(".Trash");
+    let name = unique_name(path, |name| trash.join(name).exists())
+        .ok_or_else(|| io::Error::new(ErrorKind::AlreadyExists, "no free name in the trash"))?;
+    let to = trash.join(name);
+    move_file(path, &to)?;
+    Ok(Operation::Trashed { from: path.to_path_buf(), to, info: None })
+}
+
+#[cfg(not(unix))]
+pub fn trash(_path: &Path) -> io::Result<Operation> {
+    Err(io::Error::new(ErrorKind::Unsupported, "the trash isn't supported on this platform"))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn url_encode(path: &Path) -> String {
+    let mut encoded = String::new();
+    for &byte in path.as_os_str().as_encoded_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// The current time as `YYYY-MM-DDThh:mm:ss`. This is in UTC, the standard library doesn't know the time zone.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn timestamp() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs()) as i64;
+    let date = crate::util::date(secs);
+    let digits = format!("{date:014}");
+    format!("{}-{}-{}T{}:{}:{}", &digits[..4], &digits[4..6], &digits[6..8], &digits[8..10], &digits[10..12], &digits[12..])
+}
+
+/// Runs `operation` on every path and updates the library in place. What succeeded can be
+/// undone with [`undo`], failures are reported in the status bar. `verb` is like `("move", "Moved")`.
+pub fn apply(
+    state: &mut State,
+    paths: Vec<PathBuf>,
+    (verb, done_verb): (&str, &str),
+    operation: impl Fn(&Path) -> io::Result<Operation>,
+) {
+    let mut done = Vec::new();
+    let mut count = 0;
+    let mut failed = None;
+    for path in paths {
+        let sidecar = xmp::sidecar(&path);
+        match operation(&path) {
+            Ok(op) => {
+                // The sidecar goes wherever the image went, so the rating isn't lost.
+                if let Some(sidecar) = sidecar {
+                    match follow(&op, &sidecar) {
+                        Ok(sidecar) => done.push(sidecar),
+                        Err(err) => eprintln!("Failed to {verb} {}: {}", sidecar.display(), err),
+                    }
+                }
+                done.push(op);
+                count += 1;
+            }
+            Err(err) => {
+                eprintln!("Failed to {verb} {}: {}", path.display(), err);
+                failed = Some(format!("Failed to {verb} {}: {err}", path.display()));
+            }
+        }
+    }
+    sync(state, &done, false);
+    state.view.status = failed.unwrap_or_else(|| match count {
+        1 => format!("{done_verb} 1 image, Ctrl+Z to undo"),
+        n => format!("{done_verb} {n} images, Ctrl+Z to undo"),
+    });
+    if !done.is_empty() {
+        state.undo = done;
+    }
+    state.update();
+}
+
+/// Reverts the last [`apply`].
+pub fn undo(state: &mut State) {
+    let done = std::mem::take(&mut state.undo);
+    if done.is_empty() {
+        state.view.status = "Nothing to undo".to_string();
+        return;
+    }
+    let mut undone = Vec::new();
+    for op in done.into_iter().rev() {
+        match op.undo() {
+            Ok(()) => undone.push(op),
+            Err(err) => {
+                eprintln!("Failed to undo: {}", err);
+                state.view.status = format!("Failed to undo: {err}");
+            }
+        }
+    }
+    sync(state, &undone, true);
+    if !undone.is_empty() {
+        state.view.status = format!("Undid {} changes", undone.len());
+    }
+    state.update();
+}
+
+/// Tells the library and the views about the files that were changed, the watcher picks them
+/// up as well but only after the next frame.
+fn sync(state: &mut State, ops: &[Operation], undone: bool) {
+    let roots = state.library.roots.clone();
+    let (mut changes, mut paths) = (Vec::new(), Vec::new());
+    for op in ops {
+        let (removed, added) = op.paths(undone);
+        for path in removed {
+            changes.extend(state.library.remove(path));
+        }
+        let added = added.into_iter().filter(|path| roots.iter().any(|root| path.starts_with(&root.path)));
+        paths.extend(added.map(Path::to_path_buf));
+    }
+    View::changed(state, &changes);
+    Library::add(state, paths);
+}
@@ -0,0 +1,312 @@
+use crate::library::Image;
+use crate::util;
+use crate::xmp::{Flag, Label};
+
+/// A search typed into the gallery, like `beach* rating>=3 ext:jpg,png date:2023-06..2023-08 -portrait`.
+///
+/// Words match the file name, case insensitively, as a substring or as a glob with `*` and `?`.
+/// `key:value` terms match metadata, numbers can be compared with `=`, `<`, `<=`, `>` and `>=` instead of `:`.
+/// A leading `-` inverts a term, quotes keep spaces in a word. Every term has to match.
+#[derive(Clone, Default)]
+pub struct Filter {
+    pub query: String,
+    terms: Vec<(bool, Term)>,
+    /// What was wrong with the first term that couldn't be parsed. The other terms still apply.
+    pub error: Option<String>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Cmp {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Shape {
+    Landscape,
+    Portrait,
+    Square,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Term {
+    Name(String),
+    Glob(Vec<char>),
+    Rating(Cmp, u8),
+    Extension(Vec<String>),
+    Width(Cmp, u32),
+    Height(Cmp, u32),
+    /// Inclusive, in the digits of `YYYYMMDDhhmmss`.
+    Date(u64, u64),
+    Orientation(Shape),
+    Label(Option<Label>),
+    Flag(Flag),
+    Animated,
+}
+
+impl Cmp {
+    fn test<T: Ord>(self, a: T, b: T) -> bool {
+        match self {
+            Cmp::Eq => a == b,
+            Cmp::Lt => a < b,
+            Cmp::Le => a <= b,
+            Cmp::Gt => a > b,
+            Cmp::Ge => a >= b,
+        }
+    }
+}
+
+/// Splits on whitespace outside of double quotes.
+fn words(query: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    for c in query.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => words.extend(Some(std::mem::take(&mut word)).filter(|w| !w.is_empty())),
+            c => word.push(c),
+        }
+    }
+    words.extend(Some(word).filter(|w| !w.is_empty()));
+    words
+}
+
+/// `*` matches any run of characters and `?` any single one.
+pub fn glob(pattern: &[char], name: &[char]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                // Let the last star take one more character and try again from there.
+                Some((sp, sn)) => {
+                    star = Some((sp, sn + 1));
+                    p = sp + 1;
+                    n = sn + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// `2023`, `2023-06` or `2023-06-14` as the first and last moment it covers.
+fn date(text: &str) -> Option<(u64, u64)> {
+    let digits: String = text.chars().filter(|&c| c != '-' && c != '/' && c != '.').collect();
+    if ![4, 6, 8].contains(&digits.len()) || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let first = format!("{digits:0<14}").parse().ok()?;
+    let last = format!("{digits:9<14}").parse().ok()?;
+    Some((first, last))
+}
+
+fn parse_term(word: &str) -> Result<Term, String> {
+    let Some(at) = word.find([':', '=', '<', '>']) else {
+        let lower = word.to_lowercase();
+        return Ok(match lower.as_str() {
+            "landscape" => Term::Orientation(Shape::Landscape),
+            "portrait" => Term::Orientation(Shape::Portrait),
+            "square" => Term::Orientation(Shape::Square),
+            "animated" => Term::Animated,
+            _ if lower.contains(['*', '?']) => Term::Glob(lower.chars().collect()),
+            _ => Term::Name(lower),
+        });
+    };
+    let key = word[..at].to_lowercase();
+    let rest = &word[at..];
+    let (cmp, value) = [("<=", Cmp::Le), (">=", Cmp::Ge), ("<", Cmp::Lt), (">", Cmp::Gt), ("=", Cmp::Eq), (":", Cmp::Eq)]
+        .into_iter()
+        .find_map(|(op, cmp)| rest.strip_prefix(op).map(|value| (cmp, value.to_lowercase())))
+        .unwrap_or((Cmp::Eq, String::new()));
+    let invalid = || format!("Invalid value for {key}: \"{value}\"");
+    let number = || value.parse::<u32>().map_err(|_| invalid());
+
+    Ok(match key.as_str() {
+        "rating" | "stars" => Term::Rating(cmp, number()?.min(5) as u8),
+        "width" | "w" => Term::Width(cmp, number()?),
+        "height" | "h" => Term::Height(cmp, number()?),
+        "ext" => Term::Extension(value.split(',').map(|e| e.trim_start_matches('.').to_string()).collect()),
+        "date" | "taken" => {
+            let (first, last) = match value.split_once("..") {
+                Some((from, to)) => (date(from).ok_or_else(invalid)?.0, date(to).ok_or_else(invalid)?.1),
+                None => date(&value).ok_or_else(invalid)?,
+            };
+            match cmp {
+                Cmp::Eq => Term::Date(first, last),
+                Cmp::Lt => Term::Date(0, first.saturating_sub(1)),
+                Cmp::Le => Term::Date(0, last),
+                Cmp::Gt => Term::Date(last + 1, u64::MAX),
+                Cmp::Ge => Term::Date(first, u64::MAX),
+            }
+        }
+        "orientation" => match value.as_str() {
+            "landscape" => Term::Orientation(Shape::Landscape),
+            "portrait" => Term::Orientation(Shape::Portrait),
+            "square" => Term::Orientation(Shape::Square),
+            _ => return Err(invalid()),
+        },
+        "label" => match value.as_str() {
+            "none" => Term::Label(None),
+            _ => Term::Label(Some(Label::ALL.into_iter().find(|l| l.name().eq_ignore_ascii_case(&value)).ok_or_else(invalid)?)),
+        },
+        "flag" => match value.as_str() {
+            "pick" | "picked" => Term::Flag(Flag::Pick),
+            "reject" | "rejected" => Term::Flag(Flag::Reject),
+            "none" => Term::Flag(Flag::None),
+            _ => return Err(invalid()),
+        },
+        _ => return Err(format!("Unknown filter \"{key}\"")),
+    })
+}
+
+impl Filter {
+    pub fn parse(query: &str) -> Filter {
+        let mut filter = Filter { query: query.to_string(), ..Filter::default() };
+        for word in words(query) {
+            let (negated, word) = match word.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest),
+                _ => (false, word.as_str()),
+            };
+            match parse_term(word) {
+                Ok(term) => filter.terms.push((negated, term)),
+                Err(err) => {
+                    filter.error.get_or_insert(err);
+                }
+            }
+        }
+        filter
+    }
+
+    /// Whether nothing is filtered out.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn matches(&self, image: &Image) -> bool {
+        self.terms.iter().all(|(negated, term)| term.matches(image) != *negated)
+    }
+}
+
+impl Term {
+    fn matches(&self, image: &Image) -> bool {
+        let size = image.dimensions;
+        match self {
+            Term::Name(text) => image.name.to_lowercase().contains(text),
+            Term::Glob(pattern) => glob(pattern, &image.name.to_lowercase().chars().collect::<Vec<_>>()),
+            Term::Rating(cmp, rating) => cmp.test(image.xmp.rating, *rating),
+            Term::Extension(extensions) => image
+                .path
+                .extension()
+                .is_some_and(|ext| extensions.iter().any(|e| ext.eq_ignore_ascii_case(e))),
+            Term::Width(cmp, width) => cmp.test(size.x, *width),
+            Term::Height(cmp, height) => cmp.test(size.y, *height),
+            Term::Date(first, last) => {
+                // Photos without a capture date go by when the file was last modified.
+                let date = image.exif.taken.unwrap_or_else(|| util::date((image.modified / 1000) as i64));
+                (*first..=*last).contains(&date)
+            }
+            Term::Orientation(Shape::Landscape) => size.x > size.y,
+            Term::Orientation(Shape::Portrait) => size.x < size.y,
+            Term::Orientation(Shape::Square) => size.x == size.y,
+            Term::Label(label) => image.xmp.label == *label,
+            Term::Flag(flag) => image.xmp.flag == *flag,
+            Term::Animated => image.animated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        glob(&pattern.chars().collect::<Vec<_>>(), &name.chars().collect::<Vec<_>>())
+    }
+
+    fn terms(query: &str) -> Vec<(bool, Term)> {
+        let filter = Filter::parse(query);
+        assert_eq!(filter.error, None);
+        filter.terms
+    }
+
+    #[test]
+    fn glob_stars_and_question_marks() {
+        assert!(matches("*.jpg", "beach.jpg"));
+        assert!(matches("img_????.png", "img_0042.png"));
+        assert!(matches("a**", "a"));
+        assert!(matches("*", ""));
+        assert!(!matches("*.jpg", "beach.jpeg"));
+        assert!(!matches("img_?", "img_"));
+    }
+
+    #[test]
+    fn glob_backtracks_stars() {
+        assert!(matches("*ab", "aab"));
+        assert!(matches("a*b*c", "axbxbxc"));
+        assert!(matches("*x*x", "xaxbx"));
+        assert!(!matches("a*b*c", "axbxbx"));
+    }
+
+    #[test]
+    fn dates_cover_their_whole_span() {
+        assert_eq!(date("2023"), Some((20230000000000, 20239999999999)));
+        assert_eq!(date("2023-06"), Some((20230600000000, 20230699999999)));
+        assert_eq!(date("2023/06/14"), Some((20230614000000, 20230614999999)));
+        assert_eq!(date("2023-6"), None);
+        assert_eq!(date("june"), None);
+    }
+
+    #[test]
+    fn date_ranges_and_comparisons() {
+        assert_eq!(terms("date:2023-06..2023-08"), [(false, Term::Date(20230600000000, 20230899999999))]);
+        assert_eq!(terms("taken=2023"), [(false, Term::Date(20230000000000, 20239999999999))]);
+        assert_eq!(terms("date<2023"), [(false, Term::Date(0, 20229999999999))]);
+        assert_eq!(terms("date<=2023-06"), [(false, Term::Date(0, 20230699999999))]);
+        assert_eq!(terms("date>2023-06"), [(false, Term::Date(20230700000000, u64::MAX))]);
+        assert_eq!(terms("date>=2023"), [(false, Term::Date(20230000000000, u64::MAX))]);
+        assert!(Filter::parse("date:2023..june").error.is_some());
+    }
+
+    #[test]
+    fn negation() {
+        assert_eq!(terms("-portrait"), [(true, Term::Orientation(Shape::Portrait))]);
+        assert_eq!(terms("-rating>=3"), [(true, Term::Rating(Cmp::Ge, 3))]);
+        // A lone dash is just a word.
+        assert_eq!(terms("-"), [(false, Term::Name("-".to_string()))]);
+    }
+
+    #[test]
+    fn words_and_quotes() {
+        assert_eq!(
+            terms("\"Summer Trip\"  beach*"),
+            [(false, Term::Name("summer trip".to_string())), (false, Term::Glob("beach*".chars().collect()))]
+        );
+        assert_eq!(terms("ext:.JPG,png"), [(false, Term::Extension(vec!["jpg".to_string(), "png".to_string()]))]);
+        assert_eq!(terms("rating:9 w>1000"), [(false, Term::Rating(Cmp::Eq, 5)), (false, Term::Width(Cmp::Gt, 1000))]);
+        assert!(Filter::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn errors_keep_the_other_terms() {
+        let filter = Filter::parse("beach colour:red width:wide");
+        assert_eq!(filter.error.as_deref(), Some("Unknown filter \"colour\""));
+        assert_eq!(filter.terms, [(false, Term::Name("beach".to_string()))]);
+        let filter = Filter::parse("flag:maybe");
+        assert_eq!(filter.error.as_deref(), Some("Invalid value for flag: \"maybe\""));
+        assert!(filter.is_empty());
+    }
+}
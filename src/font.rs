@@ -0,0 +1,1208 @@
+//! Glyphs rasterized from DejaVu Sans at 13px, with 4 bits of coverage per pixel.
+//! DejaVu fonts are free to embed, see https://dejavu-fonts.github.io/License.html.
+
+pub struct Glyph {
+    pub char: char,
+    pub advance: u8,
+    pub x: i8,
+    pub y: i8,
+    pub width: u8,
+    pub height: u8,
+    /// Index of the first pixel in [`COVERAGE`], counted in nibbles.
+    pub offset: u32,
+}
+
+pub const HEIGHT: u32 = 17;
+
+/// Sorted by `char`.
+pub static GLYPHS: [Glyph; 339] = [
+    Glyph { char: ' ', advance: 4, x: 0, y: 13, width: 0, height: 0, offset: 0 },
+    Glyph { char: '!', advance: 5, x: 1, y: 3, width: 3, height: 10, offset: 0 },
+    Glyph { char: '\"', advance: 6, x: 1, y: 3, width: 4, height: 5, offset: 30 },
+    Glyph { char: '#', advance: 11, x: 1, y: 3, width: 9, height: 10, offset: 50 },
+    Glyph { char: '$', advance: 8, x: 1, y: 3, width: 7, height: 12, offset: 140 },
+    Glyph { char: '%', advance: 12, x: 0, y: 3, width: 12, height: 11, offset: 224 },
+    Glyph { char: '&', advance: 10, x: 0, y: 3, width: 10, height: 11, offset: 356 },
+    Glyph { char: '\'', advance: 4, x: 1, y: 3, width: 2, height: 5, offset: 466 },
+    Glyph { char: '(', advance: 5, x: 1, y: 3, width: 4, height: 12, offset: 476 },
+    Glyph { char: ')', advance: 5, x: 1, y: 3, width: 3, height: 12, offset: 524 },
+    Glyph { char: '*', advance: 7, x: 0, y: 3, width: 7, height: 7, offset: 560 },
+    Glyph { char: '+', advance: 11, x: 1, y: 4, width: 9, height: 9, offset: 610 },
+    Glyph { char: ',', advance: 4, x: 1, y: 11, width: 2, height: 4, offset: 692 },
+    Glyph { char: '-', advance: 5, x: 0, y: 8, width: 5, height: 2, offset: 700 },
+    Glyph { char: '.', advance: 4, x: 1, y: 11, width: 2, height: 2, offset: 710 },
+    Glyph { char: '/', advance: 4, x: 0, y: 3, width: 5, height: 12, offset: 714 },
+    Glyph { char: '0', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 774 },
+    Glyph { char: '1', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 862 },
+    Glyph { char: '2', advance: 8, x: 0, y: 3, width: 7, height: 10, offset: 932 },
+    Glyph { char: '3', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 1002 },
+    Glyph { char: '4', advance: 8, x: 0, y: 3, width: 8, height: 10, offset: 1090 },
+    Glyph { char: '5', advance: 8, x: 1, y: 3, width: 7, height: 11, offset: 1170 },
+    Glyph { char: '6', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 1248 },
+    Glyph { char: '7', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 1336 },
+    Glyph { char: '8', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 1406 },
+    Glyph { char: '9', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 1494 },
+    Glyph { char: ':', advance: 4, x: 1, y: 6, width: 2, height: 7, offset: 1582 },
+    Glyph { char: ';', advance: 4, x: 1, y: 6, width: 2, height: 9, offset: 1596 },
+    Glyph { char: '<', advance: 11, x: 1, y: 5, width: 9, height: 8, offset: 1614 },
+    Glyph { char: '=', advance: 11, x: 1, y: 7, width: 9, height: 4, offset: 1686 },
+    Glyph { char: '>', advance: 11, x: 1, y: 5, width: 9, height: 8, offset: 1722 },
+    Glyph { char: '?', advance: 7, x: 0, y: 3, width: 6, height: 10, offset: 1794 },
+    Glyph { char: '@', advance: 13, x: 0, y: 3, width: 13, height: 13, offset: 1854 },
+    Glyph { char: 'A', advance: 9, x: 0, y: 3, width: 9, height: 10, offset: 2024 },
+    Glyph { char: 'B', advance: 9, x: 1, y: 3, width: 7, height: 10, offset: 2114 },
+    Glyph { char: 'C', advance: 9, x: 0, y: 3, width: 9, height: 11, offset: 2184 },
+    Glyph { char: 'D', advance: 10, x: 1, y: 3, width: 9, height: 10, offset: 2284 },
+    Glyph { char: 'E', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 2374 },
+    Glyph { char: 'F', advance: 7, x: 1, y: 3, width: 6, height: 10, offset: 2444 },
+    Glyph { char: 'G', advance: 10, x: 0, y: 3, width: 10, height: 11, offset: 2504 },
+    Glyph { char: 'H', advance: 10, x: 1, y: 3, width: 8, height: 10, offset: 2614 },
+    Glyph { char: 'I', advance: 4, x: 1, y: 3, width: 2, height: 10, offset: 2694 },
+    Glyph { char: 'J', advance: 4, x: -1, y: 3, width: 4, height: 13, offset: 2714 },
+    Glyph { char: 'K', advance: 9, x: 1, y: 3, width: 8, height: 10, offset: 2766 },
+    Glyph { char: 'L', advance: 7, x: 1, y: 3, width: 7, height: 10, offset: 2846 },
+    Glyph { char: 'M', advance: 11, x: 1, y: 3, width: 9, height: 10, offset: 2916 },
+    Glyph { char: 'N', advance: 10, x: 1, y: 3, width: 8, height: 10, offset: 3006 },
+    Glyph { char: 'O', advance: 10, x: 0, y: 3, width: 10, height: 11, offset: 3086 },
+    Glyph { char: 'P', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 3196 },
+    Glyph { char: 'Q', advance: 10, x: 0, y: 3, width: 10, height: 12, offset: 3266 },
+    Glyph { char: 'R', advance: 9, x: 1, y: 3, width: 8, height: 10, offset: 3386 },
+    Glyph { char: 'S', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 3466 },
+    Glyph { char: 'T', advance: 8, x: -1, y: 3, width: 9, height: 10, offset: 3554 },
+    Glyph { char: 'U', advance: 10, x: 1, y: 3, width: 8, height: 11, offset: 3644 },
+    Glyph { char: 'V', advance: 9, x: 0, y: 3, width: 9, height: 10, offset: 3732 },
+    Glyph { char: 'W', advance: 13, x: 0, y: 3, width: 13, height: 10, offset: 3822 },
+    Glyph { char: 'X', advance: 9, x: 0, y: 3, width: 9, height: 10, offset: 3952 },
+    Glyph { char: 'Y', advance: 8, x: -1, y: 3, width: 9, height: 10, offset: 4042 },
+    Glyph { char: 'Z', advance: 9, x: 0, y: 3, width: 9, height: 10, offset: 4132 },
+    Glyph { char: '[', advance: 5, x: 1, y: 3, width: 3, height: 12, offset: 4222 },
+    Glyph { char: '\\', advance: 4, x: 0, y: 3, width: 5, height: 12, offset: 4258 },
+    Glyph { char: ']', advance: 5, x: 1, y: 3, width: 3, height: 12, offset: 4318 },
+    Glyph { char: '^', advance: 11, x: 1, y: 3, width: 9, height: 5, offset: 4354 },
+    Glyph { char: '_', advance: 7, x: -1, y: 15, width: 8, height: 2, offset: 4400 },
+    Glyph { char: '`', advance: 7, x: 1, y: 2, width: 4, height: 3, offset: 4416 },
+    Glyph { char: 'a', advance: 8, x: 0, y: 5, width: 7, height: 9, offset: 4428 },
+    Glyph { char: 'b', advance: 8, x: 1, y: 3, width: 7, height: 11, offset: 4492 },
+    Glyph { char: 'c', advance: 7, x: 0, y: 5, width: 7, height: 9, offset: 4570 },
+    Glyph { char: 'd', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 4634 },
+    Glyph { char: 'e', advance: 8, x: 0, y: 5, width: 8, height: 9, offset: 4722 },
+    Glyph { char: 'f', advance: 5, x: 0, y: 3, width: 5, height: 10, offset: 4794 },
+    Glyph { char: 'g', advance: 8, x: 0, y: 5, width: 8, height: 11, offset: 4844 },
+    Glyph { char: 'h', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 4932 },
+    Glyph { char: 'i', advance: 4, x: 1, y: 3, width: 2, height: 10, offset: 5002 },
+    Glyph { char: 'j', advance: 4, x: -1, y: 3, width: 4, height: 13, offset: 5022 },
+    Glyph { char: 'k', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 5074 },
+    Glyph { char: 'l', advance: 4, x: 1, y: 3, width: 2, height: 10, offset: 5144 },
+    Glyph { char: 'm', advance: 13, x: 1, y: 5, width: 11, height: 8, offset: 5164 },
+    Glyph { char: 'n', advance: 8, x: 1, y: 5, width: 7, height: 8, offset: 5252 },
+    Glyph { char: 'o', advance: 8, x: 0, y: 5, width: 8, height: 9, offset: 5308 },
+    Glyph { char: 'p', advance: 8, x: 1, y: 5, width: 7, height: 11, offset: 5380 },
+    Glyph { char: 'q', advance: 8, x: 0, y: 5, width: 8, height: 11, offset: 5458 },
+    Glyph { char: 'r', advance: 5, x: 1, y: 5, width: 5, height: 8, offset: 5546 },
+    Glyph { char: 's', advance: 7, x: 0, y: 5, width: 7, height: 9, offset: 5586 },
+    Glyph { char: 't', advance: 5, x: 0, y: 3, width: 5, height: 10, offset: 5650 },
+    Glyph { char: 'u', advance: 8, x: 1, y: 5, width: 7, height: 9, offset: 5700 },
+    Glyph { char: 'v', advance: 8, x: 0, y: 5, width: 8, height: 8, offset: 5764 },
+    Glyph { char: 'w', advance: 11, x: 0, y: 5, width: 11, height: 8, offset: 5828 },
+    Glyph { char: 'x', advance: 8, x: 0, y: 5, width: 8, height: 8, offset: 5916 },
+    Glyph { char: 'y', advance: 8, x: 0, y: 5, width: 8, height: 11, offset: 5980 },
+    Glyph { char: 'z', advance: 7, x: 0, y: 5, width: 7, height: 8, offset: 6068 },
+    Glyph { char: '{', advance: 8, x: 1, y: 3, width: 6, height: 13, offset: 6124 },
+    Glyph { char: '|', advance: 4, x: 1, y: 3, width: 2, height: 14, offset: 6202 },
+    Glyph { char: '}', advance: 8, x: 1, y: 3, width: 6, height: 13, offset: 6230 },
+    Glyph { char: '~', advance: 11, x: 1, y: 7, width: 9, height: 4, offset: 6308 },
+    Glyph { char: '\u{a0}', advance: 4, x: 0, y: 13, width: 0, height: 0, offset: 6344 },
+    Glyph { char: '\u{a1}', advance: 5, x: 1, y: 5, width: 3, height: 11, offset: 6344 },
+    Glyph { char: '\u{a2}', advance: 8, x: 1, y: 3, width: 6, height: 12, offset: 6378 },
+    Glyph { char: '\u{a3}', advance: 8, x: 0, y: 3, width: 8, height: 10, offset: 6450 },
+    Glyph { char: '\u{a4}', advance: 8, x: 0, y: 5, width: 8, height: 8, offset: 6530 },
+    Glyph { char: '\u{a5}', advance: 8, x: 0, y: 3, width: 8, height: 10, offset: 6594 },
+    Glyph { char: '\u{a6}', advance: 4, x: 1, y: 3, width: 2, height: 13, offset: 6674 },
+    Glyph { char: '\u{a7}', advance: 7, x: 0, y: 3, width: 6, height: 12, offset: 6700 },
+    Glyph { char: '\u{a8}', advance: 7, x: 1, y: 3, width: 5, height: 2, offset: 6772 },
+    Glyph { char: '\u{a9}', advance: 13, x: 1, y: 3, width: 11, height: 10, offset: 6782 },
+    Glyph { char: '\u{aa}', advance: 6, x: 0, y: 3, width: 6, height: 8, offset: 6892 },
+    Glyph { char: '\u{ab}', advance: 8, x: 1, y: 6, width: 6, height: 7, offset: 6940 },
+    Glyph { char: '\u{ac}', advance: 11, x: 1, y: 7, width: 9, height: 5, offset: 6982 },
+    Glyph { char: '\u{ad}', advance: 5, x: 0, y: 8, width: 5, height: 2, offset: 7028 },
+    Glyph { char: '\u{ae}', advance: 13, x: 1, y: 3, width: 11, height: 10, offset: 7038 },
+    Glyph { char: '\u{af}', advance: 7, x: 1, y: 3, width: 5, height: 2, offset: 7148 },
+    Glyph { char: '\u{b0}', advance: 7, x: 1, y: 3, width: 5, height: 5, offset: 7158 },
+    Glyph { char: '\u{b1}', advance: 11, x: 1, y: 4, width: 9, height: 9, offset: 7184 },
+    Glyph { char: '\u{b2}', advance: 5, x: 0, y: 3, width: 5, height: 6, offset: 7266 },
+    Glyph { char: '\u{b3}', advance: 5, x: 0, y: 3, width: 5, height: 6, offset: 7296 },
+    Glyph { char: '\u{b4}', advance: 7, x: 2, y: 2, width: 4, height: 3, offset: 7326 },
+    Glyph { char: '\u{b5}', advance: 8, x: 1, y: 5, width: 7, height: 11, offset: 7338 },
+    Glyph { char: '\u{b6}', advance: 8, x: 1, y: 3, width: 6, height: 12, offset: 7416 },
+    Glyph { char: '\u{b7}', advance: 4, x: 1, y: 7, width: 2, height: 3, offset: 7488 },
+    Glyph { char: '\u{b8}', advance: 7, x: 1, y: 13, width: 4, height: 3, offset: 7494 },
+    Glyph { char: '\u{b9}', advance: 5, x: 0, y: 3, width: 5, height: 6, offset: 7506 },
+    Glyph { char: '\u{ba}', advance: 6, x: 0, y: 3, width: 6, height: 8, offset: 7536 },
+    Glyph { char: '\u{bb}', advance: 8, x: 1, y: 6, width: 6, height: 7, offset: 7584 },
+    Glyph { char: '\u{bc}', advance: 13, x: 0, y: 3, width: 13, height: 11, offset: 7626 },
+    Glyph { char: '\u{bd}', advance: 13, x: 0, y: 3, width: 12, height: 11, offset: 7770 },
+    Glyph { char: '\u{be}', advance: 13, x: 0, y: 3, width: 13, height: 11, offset: 7902 },
+    Glyph { char: '\u{bf}', advance: 7, x: 0, y: 5, width: 6, height: 11, offset: 8046 },
+    Glyph { char: '\u{c0}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 8112 },
+    Glyph { char: '\u{c1}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 8230 },
+    Glyph { char: '\u{c2}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 8348 },
+    Glyph { char: '\u{c3}', advance: 9, x: 0, y: 1, width: 9, height: 12, offset: 8466 },
+    Glyph { char: '\u{c4}', advance: 9, x: 0, y: 1, width: 9, height: 12, offset: 8574 },
+    Glyph { char: '\u{c5}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 8682 },
+    Glyph { char: '\u{c6}', advance: 13, x: 0, y: 3, width: 12, height: 10, offset: 8800 },
+    Glyph { char: '\u{c7}', advance: 9, x: 0, y: 3, width: 9, height: 13, offset: 8920 },
+    Glyph { char: '\u{c8}', advance: 8, x: 1, y: 0, width: 7, height: 13, offset: 9038 },
+    Glyph { char: '\u{c9}', advance: 8, x: 1, y: 0, width: 7, height: 13, offset: 9130 },
+    Glyph { char: '\u{ca}', advance: 8, x: 1, y: 0, width: 7, height: 13, offset: 9222 },
+    Glyph { char: '\u{cb}', advance: 8, x: 1, y: 1, width: 7, height: 12, offset: 9314 },
+    Glyph { char: '\u{cc}', advance: 4, x: 0, y: 0, width: 3, height: 13, offset: 9398 },
+    Glyph { char: '\u{cd}', advance: 4, x: 1, y: 0, width: 3, height: 13, offset: 9438 },
+    Glyph { char: '\u{ce}', advance: 4, x: -1, y: 0, width: 5, height: 13, offset: 9478 },
+    Glyph { char: '\u{cf}', advance: 4, x: 0, y: 1, width: 4, height: 12, offset: 9544 },
+    Glyph { char: '\u{d0}', advance: 10, x: 0, y: 3, width: 10, height: 10, offset: 9592 },
+    Glyph { char: '\u{d1}', advance: 10, x: 1, y: 1, width: 8, height: 12, offset: 9692 },
+    Glyph { char: '\u{d2}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 9788 },
+    Glyph { char: '\u{d3}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 9928 },
+    Glyph { char: '\u{d4}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 10068 },
+    Glyph { char: '\u{d5}', advance: 10, x: 0, y: 1, width: 10, height: 13, offset: 10208 },
+    Glyph { char: '\u{d6}', advance: 10, x: 0, y: 1, width: 10, height: 13, offset: 10338 },
+    Glyph { char: '\u{d7}', advance: 11, x: 1, y: 5, width: 9, height: 8, offset: 10468 },
+    Glyph { char: '\u{d8}', advance: 10, x: 0, y: 3, width: 10, height: 11, offset: 10540 },
+    Glyph { char: '\u{d9}', advance: 10, x: 1, y: 0, width: 8, height: 14, offset: 10650 },
+    Glyph { char: '\u{da}', advance: 10, x: 1, y: 0, width: 8, height: 14, offset: 10762 },
+    Glyph { char: '\u{db}', advance: 10, x: 1, y: 0, width: 8, height: 14, offset: 10874 },
+    Glyph { char: '\u{dc}', advance: 10, x: 1, y: 1, width: 8, height: 13, offset: 10986 },
+    Glyph { char: '\u{dd}', advance: 8, x: -1, y: 0, width: 9, height: 13, offset: 11090 },
+    Glyph { char: '\u{de}', advance: 8, x: 1, y: 3, width: 7, height: 10, offset: 11208 },
+    Glyph { char: '\u{df}', advance: 8, x: 1, y: 3, width: 7, height: 11, offset: 11278 },
+    Glyph { char: '\u{e0}', advance: 8, x: 0, y: 2, width: 7, height: 12, offset: 11356 },
+    Glyph { char: '\u{e1}', advance: 8, x: 0, y: 2, width: 7, height: 12, offset: 11440 },
+    Glyph { char: '\u{e2}', advance: 8, x: 0, y: 2, width: 7, height: 12, offset: 11524 },
+    Glyph { char: '\u{e3}', advance: 8, x: 0, y: 2, width: 7, height: 12, offset: 11608 },
+    Glyph { char: '\u{e4}', advance: 8, x: 0, y: 3, width: 7, height: 11, offset: 11692 },
+    Glyph { char: '\u{e5}', advance: 8, x: 0, y: 1, width: 7, height: 13, offset: 11770 },
+    Glyph { char: '\u{e6}', advance: 13, x: 0, y: 5, width: 13, height: 9, offset: 11862 },
+    Glyph { char: '\u{e7}', advance: 7, x: 0, y: 5, width: 7, height: 11, offset: 11980 },
+    Glyph { char: '\u{e8}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 12058 },
+    Glyph { char: '\u{e9}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 12154 },
+    Glyph { char: '\u{ea}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 12250 },
+    Glyph { char: '\u{eb}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 12346 },
+    Glyph { char: '\u{ec}', advance: 4, x: -1, y: 2, width: 4, height: 11, offset: 12434 },
+    Glyph { char: '\u{ed}', advance: 4, x: 0, y: 2, width: 4, height: 11, offset: 12478 },
+    Glyph { char: '\u{ee}', advance: 4, x: -1, y: 2, width: 5, height: 11, offset: 12522 },
+    Glyph { char: '\u{ef}', advance: 4, x: -1, y: 3, width: 5, height: 10, offset: 12578 },
+    Glyph { char: '\u{f0}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 12628 },
+    Glyph { char: '\u{f1}', advance: 8, x: 1, y: 2, width: 7, height: 11, offset: 12716 },
+    Glyph { char: '\u{f2}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 12794 },
+    Glyph { char: '\u{f3}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 12890 },
+    Glyph { char: '\u{f4}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 12986 },
+    Glyph { char: '\u{f5}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 13082 },
+    Glyph { char: '\u{f6}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 13178 },
+    Glyph { char: '\u{f7}', advance: 11, x: 1, y: 5, width: 9, height: 8, offset: 13266 },
+    Glyph { char: '\u{f8}', advance: 8, x: 0, y: 5, width: 8, height: 9, offset: 13338 },
+    Glyph { char: '\u{f9}', advance: 8, x: 1, y: 2, width: 7, height: 12, offset: 13410 },
+    Glyph { char: '\u{fa}', advance: 8, x: 1, y: 2, width: 7, height: 12, offset: 13494 },
+    Glyph { char: '\u{fb}', advance: 8, x: 1, y: 2, width: 7, height: 12, offset: 13578 },
+    Glyph { char: '\u{fc}', advance: 8, x: 1, y: 3, width: 7, height: 11, offset: 13662 },
+    Glyph { char: '\u{fd}', advance: 8, x: 0, y: 2, width: 8, height: 14, offset: 13740 },
+    Glyph { char: '\u{fe}', advance: 8, x: 1, y: 3, width: 7, height: 13, offset: 13852 },
+    Glyph { char: '\u{ff}', advance: 8, x: 0, y: 3, width: 8, height: 13, offset: 13944 },
+    Glyph { char: '\u{100}', advance: 9, x: 0, y: 1, width: 9, height: 12, offset: 14048 },
+    Glyph { char: '\u{101}', advance: 8, x: 0, y: 3, width: 7, height: 11, offset: 14156 },
+    Glyph { char: '\u{102}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 14234 },
+    Glyph { char: '\u{103}', advance: 8, x: 0, y: 3, width: 7, height: 11, offset: 14352 },
+    Glyph { char: '\u{104}', advance: 9, x: 0, y: 3, width: 10, height: 13, offset: 14430 },
+    Glyph { char: '\u{105}', advance: 8, x: 0, y: 5, width: 8, height: 11, offset: 14560 },
+    Glyph { char: '\u{106}', advance: 9, x: 0, y: 0, width: 9, height: 14, offset: 14648 },
+    Glyph { char: '\u{107}', advance: 7, x: 0, y: 2, width: 7, height: 12, offset: 14774 },
+    Glyph { char: '\u{108}', advance: 9, x: 0, y: 0, width: 9, height: 14, offset: 14858 },
+    Glyph { char: '\u{109}', advance: 7, x: 0, y: 2, width: 7, height: 12, offset: 14984 },
+    Glyph { char: '\u{10a}', advance: 9, x: 0, y: 1, width: 9, height: 13, offset: 15068 },
+    Glyph { char: '\u{10b}', advance: 7, x: 0, y: 3, width: 7, height: 11, offset: 15186 },
+    Glyph { char: '\u{10c}', advance: 9, x: 0, y: 0, width: 9, height: 14, offset: 15264 },
+    Glyph { char: '\u{10d}', advance: 7, x: 0, y: 2, width: 7, height: 12, offset: 15390 },
+    Glyph { char: '\u{10e}', advance: 10, x: 1, y: 0, width: 9, height: 13, offset: 15474 },
+    Glyph { char: '\u{10f}', advance: 8, x: 0, y: 3, width: 10, height: 11, offset: 15592 },
+    Glyph { char: '\u{110}', advance: 10, x: 0, y: 3, width: 10, height: 10, offset: 15702 },
+    Glyph { char: '\u{111}', advance: 8, x: 0, y: 3, width: 9, height: 11, offset: 15802 },
+    Glyph { char: '\u{112}', advance: 8, x: 1, y: 1, width: 7, height: 12, offset: 15902 },
+    Glyph { char: '\u{113}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 15986 },
+    Glyph { char: '\u{114}', advance: 8, x: 1, y: 0, width: 7, height: 13, offset: 16074 },
+    Glyph { char: '\u{115}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 16166 },
+    Glyph { char: '\u{116}', advance: 8, x: 1, y: 1, width: 7, height: 12, offset: 16262 },
+    Glyph { char: '\u{117}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 16346 },
+    Glyph { char: '\u{118}', advance: 8, x: 1, y: 3, width: 7, height: 13, offset: 16434 },
+    Glyph { char: '\u{119}', advance: 8, x: 0, y: 5, width: 8, height: 11, offset: 16526 },
+    Glyph { char: '\u{11a}', advance: 8, x: 1, y: 0, width: 7, height: 13, offset: 16614 },
+    Glyph { char: '\u{11b}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 16706 },
+    Glyph { char: '\u{11c}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 16802 },
+    Glyph { char: '\u{11d}', advance: 8, x: 0, y: 2, width: 8, height: 14, offset: 16942 },
+    Glyph { char: '\u{11e}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 17054 },
+    Glyph { char: '\u{11f}', advance: 8, x: 0, y: 2, width: 8, height: 14, offset: 17194 },
+    Glyph { char: '\u{120}', advance: 10, x: 0, y: 1, width: 10, height: 13, offset: 17306 },
+    Glyph { char: '\u{121}', advance: 8, x: 0, y: 3, width: 8, height: 13, offset: 17436 },
+    Glyph { char: '\u{122}', advance: 10, x: 0, y: 3, width: 10, height: 14, offset: 17540 },
+    Glyph { char: '\u{123}', advance: 8, x: 0, y: 2, width: 8, height: 14, offset: 17680 },
+    Glyph { char: '\u{124}', advance: 10, x: 1, y: 0, width: 8, height: 13, offset: 17792 },
+    Glyph { char: '\u{125}', advance: 8, x: -1, y: 0, width: 9, height: 13, offset: 17896 },
+    Glyph { char: '\u{126}', advance: 12, x: 1, y: 3, width: 10, height: 10, offset: 18014 },
+    Glyph { char: '\u{127}', advance: 9, x: 0, y: 3, width: 8, height: 10, offset: 18114 },
+    Glyph { char: '\u{128}', advance: 4, x: -1, y: 1, width: 6, height: 12, offset: 18194 },
+    Glyph { char: '\u{129}', advance: 4, x: -1, y: 2, width: 5, height: 11, offset: 18266 },
+    Glyph { char: '\u{12a}', advance: 4, x: 0, y: 1, width: 4, height: 12, offset: 18322 },
+    Glyph { char: '\u{12b}', advance: 4, x: -1, y: 3, width: 5, height: 10, offset: 18370 },
+    Glyph { char: '\u{12c}', advance: 4, x: -1, y: 0, width: 5, height: 13, offset: 18420 },
+    Glyph { char: '\u{12d}', advance: 4, x: -1, y: 2, width: 5, height: 11, offset: 18486 },
+    Glyph { char: '\u{12e}', advance: 4, x: 1, y: 3, width: 3, height: 13, offset: 18542 },
+    Glyph { char: '\u{12f}', advance: 4, x: 0, y: 3, width: 4, height: 13, offset: 18582 },
+    Glyph { char: '\u{130}', advance: 4, x: 1, y: 1, width: 2, height: 12, offset: 18634 },
+    Glyph { char: '\u{131}', advance: 4, x: 1, y: 5, width: 2, height: 8, offset: 18658 },
+    Glyph { char: '\u{132}', advance: 8, x: 1, y: 3, width: 6, height: 13, offset: 18674 },
+    Glyph { char: '\u{133}', advance: 7, x: 1, y: 3, width: 5, height: 13, offset: 18752 },
+    Glyph { char: '\u{134}', advance: 4, x: -1, y: 0, width: 5, height: 16, offset: 18818 },
+    Glyph { char: '\u{135}', advance: 4, x: -1, y: 2, width: 5, height: 14, offset: 18898 },
+    Glyph { char: '\u{136}', advance: 9, x: 1, y: 3, width: 8, height: 14, offset: 18968 },
+    Glyph { char: '\u{137}', advance: 8, x: 1, y: 3, width: 7, height: 14, offset: 19080 },
+    Glyph { char: '\u{138}', advance: 8, x: 1, y: 5, width: 7, height: 8, offset: 19178 },
+    Glyph { char: '\u{139}', advance: 7, x: 1, y: 0, width: 7, height: 13, offset: 19234 },
+    Glyph { char: '\u{13a}', advance: 4, x: 1, y: 0, width: 3, height: 13, offset: 19326 },
+    Glyph { char: '\u{13b}', advance: 7, x: 1, y: 3, width: 7, height: 14, offset: 19366 },
+    Glyph { char: '\u{13c}', advance: 4, x: 0, y: 3, width: 3, height: 14, offset: 19464 },
+    Glyph { char: '\u{13d}', advance: 7, x: 1, y: 3, width: 7, height: 10, offset: 19506 },
+    Glyph { char: '\u{13e}', advance: 5, x: 1, y: 3, width: 4, height: 10, offset: 19576 },
+    Glyph { char: '\u{13f}', advance: 7, x: 1, y: 3, width: 7, height: 10, offset: 19616 },
+    Glyph { char: '\u{140}', advance: 4, x: 1, y: 3, width: 4, height: 10, offset: 19686 },
+    Glyph { char: '\u{141}', advance: 7, x: -1, y: 3, width: 9, height: 10, offset: 19726 },
+    Glyph { char: '\u{142}', advance: 4, x: 0, y: 3, width: 4, height: 10, offset: 19816 },
+    Glyph { char: '\u{143}', advance: 10, x: 1, y: 0, width: 8, height: 13, offset: 19856 },
+    Glyph { char: '\u{144}', advance: 8, x: 1, y: 2, width: 7, height: 11, offset: 19960 },
+    Glyph { char: '\u{145}', advance: 10, x: 1, y: 3, width: 8, height: 14, offset: 20038 },
+    Glyph { char: '\u{146}', advance: 8, x: 1, y: 5, width: 7, height: 12, offset: 20150 },
+    Glyph { char: '\u{147}', advance: 10, x: 1, y: 1, width: 8, height: 12, offset: 20234 },
+    Glyph { char: '\u{148}', advance: 8, x: 1, y: 2, width: 7, height: 11, offset: 20330 },
+    Glyph { char: '\u{149}', advance: 11, x: 1, y: 3, width: 9, height: 10, offset: 20408 },
+    Glyph { char: '\u{14a}', advance: 10, x: 1, y: 3, width: 8, height: 13, offset: 20498 },
+    Glyph { char: '\u{14b}', advance: 8, x: 1, y: 5, width: 7, height: 11, offset: 20602 },
+    Glyph { char: '\u{14c}', advance: 10, x: 0, y: 1, width: 10, height: 13, offset: 20680 },
+    Glyph { char: '\u{14d}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 20810 },
+    Glyph { char: '\u{14e}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 20898 },
+    Glyph { char: '\u{14f}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 21038 },
+    Glyph { char: '\u{150}', advance: 10, x: 0, y: 0, width: 10, height: 14, offset: 21134 },
+    Glyph { char: '\u{151}', advance: 8, x: 0, y: 2, width: 8, height: 12, offset: 21274 },
+    Glyph { char: '\u{152}', advance: 14, x: 0, y: 3, width: 14, height: 10, offset: 21370 },
+    Glyph { char: '\u{153}', advance: 13, x: 0, y: 5, width: 13, height: 9, offset: 21510 },
+    Glyph { char: '\u{154}', advance: 9, x: 1, y: 0, width: 8, height: 13, offset: 21628 },
+    Glyph { char: '\u{155}', advance: 5, x: 1, y: 2, width: 5, height: 11, offset: 21732 },
+    Glyph { char: '\u{156}', advance: 9, x: 1, y: 3, width: 8, height: 14, offset: 21788 },
+    Glyph { char: '\u{157}', advance: 5, x: 0, y: 5, width: 6, height: 12, offset: 21900 },
+    Glyph { char: '\u{158}', advance: 9, x: 1, y: 1, width: 8, height: 12, offset: 21972 },
+    Glyph { char: '\u{159}', advance: 5, x: 1, y: 2, width: 5, height: 11, offset: 22068 },
+    Glyph { char: '\u{15a}', advance: 8, x: 0, y: 0, width: 8, height: 14, offset: 22124 },
+    Glyph { char: '\u{15b}', advance: 7, x: 0, y: 2, width: 7, height: 12, offset: 22236 },
+    Glyph { char: '\u{15c}', advance: 8, x: 0, y: 0, width: 8, height: 14, offset: 22320 },
+    Glyph { char: '\u{15d}', advance: 7, x: 0, y: 2, width: 7, height: 12, offset: 22432 },
+    Glyph { char: '\u{15e}', advance: 8, x: 0, y: 3, width: 8, height: 13, offset: 22516 },
+    Glyph { char: '\u{15f}', advance: 7, x: 0, y: 5, width: 7, height: 11, offset: 22620 },
+    Glyph { char: '\u{160}', advance: 8, x: 0, y: 0, width: 8, height: 14, offset: 22698 },
+    Glyph { char: '\u{161}', advance: 7, x: 0, y: 2, width: 7, height: 12, offset: 22810 },
+    Glyph { char: '\u{162}', advance: 8, x: -1, y: 3, width: 9, height: 13, offset: 22894 },
+    Glyph { char: '\u{163}', advance: 5, x: 0, y: 3, width: 5, height: 13, offset: 23012 },
+    Glyph { char: '\u{164}', advance: 8, x: -1, y: 1, width: 9, height: 12, offset: 23078 },
+    Glyph { char: '\u{165}', advance: 5, x: 0, y: 2, width: 5, height: 11, offset: 23186 },
+    Glyph { char: '\u{166}', advance: 8, x: -1, y: 3, width: 9, height: 10, offset: 23242 },
+    Glyph { char: '\u{167}', advance: 5, x: 0, y: 3, width: 5, height: 10, offset: 23332 },
+    Glyph { char: '\u{168}', advance: 10, x: 1, y: 1, width: 8, height: 13, offset: 23382 },
+    Glyph { char: '\u{169}', advance: 8, x: 1, y: 2, width: 7, height: 12, offset: 23486 },
+    Glyph { char: '\u{16a}', advance: 10, x: 1, y: 1, width: 8, height: 13, offset: 23570 },
+    Glyph { char: '\u{16b}', advance: 8, x: 1, y: 3, width: 7, height: 11, offset: 23674 },
+    Glyph { char: '\u{16c}', advance: 10, x: 1, y: 0, width: 8, height: 14, offset: 23752 },
+    Glyph { char: '\u{16d}', advance: 8, x: 1, y: 2, width: 7, height: 12, offset: 23864 },
+    Glyph { char: '\u{16e}', advance: 10, x: 1, y: 0, width: 8, height: 14, offset: 23948 },
+    Glyph { char: '\u{16f}', advance: 8, x: 1, y: 1, width: 7, height: 13, offset: 24060 },
+    Glyph { char: '\u{170}', advance: 10, x: 1, y: 0, width: 8, height: 14, offset: 24152 },
+    Glyph { char: '\u{171}', advance: 8, x: 1, y: 2, width: 7, height: 12, offset: 24264 },
+    Glyph { char: '\u{172}', advance: 10, x: 1, y: 3, width: 8, height: 13, offset: 24348 },
+    Glyph { char: '\u{173}', advance: 8, x: 1, y: 5, width: 7, height: 11, offset: 24452 },
+    Glyph { char: '\u{174}', advance: 13, x: 0, y: 0, width: 13, height: 13, offset: 24530 },
+    Glyph { char: '\u{175}', advance: 11, x: 0, y: 2, width: 11, height: 11, offset: 24700 },
+    Glyph { char: '\u{176}', advance: 8, x: -1, y: 0, width: 9, height: 13, offset: 24822 },
+    Glyph { char: '\u{177}', advance: 8, x: 0, y: 2, width: 8, height: 14, offset: 24940 },
+    Glyph { char: '\u{178}', advance: 8, x: -1, y: 1, width: 9, height: 12, offset: 25052 },
+    Glyph { char: '\u{179}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 25160 },
+    Glyph { char: '\u{17a}', advance: 7, x: 0, y: 2, width: 7, height: 11, offset: 25278 },
+    Glyph { char: '\u{17b}', advance: 9, x: 0, y: 1, width: 9, height: 12, offset: 25356 },
+    Glyph { char: '\u{17c}', advance: 7, x: 0, y: 3, width: 7, height: 10, offset: 25464 },
+    Glyph { char: '\u{17d}', advance: 9, x: 0, y: 0, width: 9, height: 13, offset: 25534 },
+    Glyph { char: '\u{17e}', advance: 7, x: 0, y: 2, width: 7, height: 11, offset: 25652 },
+    Glyph { char: '\u{17f}', advance: 5, x: 0, y: 3, width: 5, height: 10, offset: 25730 },
+    Glyph { char: '\u{2013}', advance: 7, x: 0, y: 8, width: 6, height: 2, offset: 25780 },
+    Glyph { char: '\u{2014}', advance: 13, x: 0, y: 8, width: 13, height: 2, offset: 25792 },
+    Glyph { char: '\u{2018}', advance: 4, x: 1, y: 3, width: 2, height: 4, offset: 25818 },
+    Glyph { char: '\u{2019}', advance: 4, x: 1, y: 3, width: 2, height: 4, offset: 25826 },
+    Glyph { char: '\u{201c}', advance: 7, x: 1, y: 3, width: 5, height: 4, offset: 25834 },
+    Glyph { char: '\u{201d}', advance: 7, x: 1, y: 3, width: 5, height: 4, offset: 25854 },
+    Glyph { char: '\u{2022}', advance: 8, x: 1, y: 6, width: 5, height: 5, offset: 25874 },
+    Glyph { char: '\u{2026}', advance: 13, x: 1, y: 11, width: 11, height: 2, offset: 25900 },
+    Glyph { char: '\u{20ac}', advance: 8, x: 0, y: 3, width: 8, height: 11, offset: 25922 },
+    Glyph { char: '\u{2190}', advance: 11, x: 0, y: 6, width: 11, height: 6, offset: 26010 },
+    Glyph { char: '\u{2191}', advance: 11, x: 2, y: 3, width: 7, height: 10, offset: 26076 },
+    Glyph { char: '\u{2192}', advance: 11, x: 0, y: 6, width: 11, height: 6, offset: 26146 },
+    Glyph { char: '\u{2193}', advance: 11, x: 2, y: 3, width: 7, height: 11, offset: 26212 },
+    Glyph { char: '\u{25cf}', advance: 11, x: 0, y: 4, width: 11, height: 11, offset: 26760 },
+    Glyph { char: '\u{2605}', advance: 12, x: 0, y: 3, width: 11, height: 11, offset: 26290 },
+    Glyph { char: '\u{2606}', advance: 12, x: 0, y: 3, width: 11, height: 11, offset: 26412 },
+    Glyph { char: '\u{2691}', advance: 12, x: 2, y: 3, width: 8, height: 10, offset: 26534 },
+    Glyph { char: '\u{2713}', advance: 11, x: 1, y: 4, width: 8, height: 8, offset: 26614 },
+    Glyph { char: '\u{2715}', advance: 11, x: 1, y: 4, width: 9, height: 9, offset: 26678 },
+    Glyph { char: '\u{fffd}', advance: 13, x: 0, y: 1, width: 14, height: 14, offset: 26882 },
+];
+
+pub static COVERAGE: [u8; 13539] = [
+    0x07, 0x21, 0xF4, 0x1F, 0x41, 0xF4, 0x1F, 0x40, 0xF3, 0x0D, 0x20, 0x00, 0x09, 0x21, 0xF4, 0x52,
+    0x25, 0xB5, 0x5B, 0xB5, 0x5B, 0xB5, 0x5B, 0x00, 0x00, 0x00, 0x02, 0x30, 0x23, 0x00, 0x00, 0x87,
+    0x0A, 0x50, 0x00, 0x0C, 0x40, 0xE2, 0x03, 0xAA, 0xFB, 0xBF, 0xA9, 0x14, 0x8C, 0x49, 0xA4, 0x40,
+    0x08, 0x70, 0xA6, 0x00, 0x99, 0xDB, 0x9E, 0xA9, 0x16, 0x6E, 0x67, 0xD6, 0x61, 0x04, 0xB0, 0x69,
+    0x00, 0x00, 0x87, 0x0A, 0x50, 0x00, 0x00, 0x35, 0x00, 0x00, 0x27, 0x94, 0x10, 0x6E, 0xAB, 0xA9,
+    0x0D, 0x54, 0x60, 0x00, 0xC8, 0x46, 0x00, 0x03, 0xDF, 0xC7, 0x10, 0x00, 0x5A, 0xBD, 0x00, 0x04,
+    0x61, 0xF2, 0x72, 0x46, 0x5E, 0x18, 0xDE, 0xEC, 0x40, 0x00, 0x46, 0x00, 0x00, 0x03, 0x50, 0x00,
+    0x03, 0x98, 0x10, 0x00, 0x54, 0x00, 0x1D, 0x57, 0xC0, 0x02, 0xD1, 0x00, 0x4C, 0x00, 0xE1, 0x0A,
+    0x50, 0x00, 0x3C, 0x00, 0xE1, 0x4B, 0x00, 0x00, 0x0D, 0x78, 0xA0, 0xC2, 0x00, 0x00, 0x01, 0x76,
+    0x17, 0x81, 0xBC, 0xA1, 0x00, 0x00, 0x2D, 0x18, 0x80, 0x97, 0x00, 0x00, 0xA5, 0x0B, 0x40, 0x69,
+    0x00, 0x04, 0xB0, 0x09, 0x60, 0x88, 0x00, 0x0C, 0x20, 0x02, 0xDA, 0xD2, 0x00, 0x12, 0x00, 0x00,
+    0x12, 0x00, 0x00, 0x17, 0x98, 0x30, 0x00, 0x00, 0xCB, 0x68, 0x70, 0x00, 0x01, 0xF2, 0x00, 0x00,
+    0x00, 0x00, 0xD7, 0x00, 0x00, 0x00, 0x01, 0xCF, 0x50, 0x00, 0x10, 0x0B, 0x94, 0xE5, 0x00, 0xE3,
+    0x2F, 0x20, 0x5E, 0x53, 0xD0, 0x2F, 0x20, 0x05, 0xED, 0x60, 0x0C, 0xA1, 0x01, 0xCF, 0x40, 0x02,
+    0xCE, 0xDE, 0x96, 0xE4, 0x00, 0x01, 0x21, 0x00, 0x00, 0x52, 0xB5, 0xB5, 0xB5, 0x00, 0x03, 0xA0,
+    0x0B, 0x50, 0x3E, 0x00, 0x8A, 0x00, 0xB7, 0x00, 0xD6, 0x00, 0xD6, 0x00, 0xB7, 0x00, 0x7A, 0x00,
+    0x2E, 0x10, 0x0A, 0x60, 0x02, 0x90, 0x94, 0x04, 0xC0, 0x0D, 0x40, 0x99, 0x06, 0xC0, 0x4E, 0x05,
+    0xE0, 0x6C, 0x09, 0x80, 0xE3, 0x5B, 0x08, 0x30, 0x00, 0x16, 0x00, 0x03, 0x22, 0x90, 0x50, 0x2A,
+    0x9B, 0xA6, 0x00, 0x1B, 0xF6, 0x00, 0x5A, 0x49, 0x6A, 0x00, 0x02, 0x90, 0x00, 0x00, 0x03, 0x00,
+    0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x01, 0xF0, 0x00, 0x00, 0x00,
+    0x1F, 0x00, 0x00, 0x69, 0x9A, 0xF9, 0x99, 0x54, 0x77, 0x8F, 0x77, 0x74, 0x00, 0x01, 0xF0, 0x00,
+    0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x01, 0xF0, 0x00, 0x00, 0x48, 0x8C, 0xB5, 0x70, 0x01, 0x11,
+    0x05, 0xEE, 0xE1, 0x67, 0x9B, 0x00, 0x06, 0x20, 0x01, 0xE1, 0x00, 0x5C, 0x00, 0x09, 0x70, 0x00,
+    0xE2, 0x00, 0x4D, 0x00, 0x08, 0x80, 0x00, 0xD3, 0x00, 0x2E, 0x00, 0x07, 0x90, 0x00, 0xC4, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x39, 0x95, 0x00, 0x03, 0xE8, 0x7E, 0x70, 0x0B, 0x90, 0x05, 0xE1, 0x0F,
+    0x40, 0x01, 0xF4, 0x2F, 0x30, 0x00, 0xE6, 0x2F, 0x20, 0x00, 0xD6, 0x1F, 0x30, 0x00, 0xE5, 0x0D,
+    0x60, 0x02, 0xF2, 0x08, 0xC1, 0x09, 0xC0, 0x01, 0xBE, 0xED, 0x20, 0x00, 0x02, 0x20, 0x00, 0x14,
+    0x67, 0x00, 0x09, 0xEC, 0xF0, 0x00, 0x10, 0x4F, 0x00, 0x00, 0x04, 0xF0, 0x00, 0x00, 0x4F, 0x00,
+    0x00, 0x04, 0xF0, 0x00, 0x00, 0x4F, 0x00, 0x00, 0x04, 0xF0, 0x00, 0x01, 0x5F, 0x11, 0x06, 0xFF,
+    0xFF, 0xF1, 0x03, 0x79, 0x94, 0x00, 0xEA, 0x79, 0xF6, 0x01, 0x00, 0x08, 0xD0, 0x00, 0x00, 0x7D,
+    0x00, 0x00, 0x1D, 0x80, 0x00, 0x0A, 0xB0, 0x00, 0x0A, 0xC1, 0x00, 0x0A, 0xC1, 0x00, 0x09, 0xD2,
+    0x11, 0x11, 0xFF, 0xFF, 0xFF, 0x04, 0x89, 0x95, 0x00, 0x09, 0x97, 0x8E, 0x90, 0x00, 0x00, 0x05,
+    0xE0, 0x00, 0x00, 0x06, 0xD0, 0x00, 0x39, 0xAC, 0x30, 0x00, 0x37, 0x8E, 0x70, 0x00, 0x00, 0x04,
+    0xF2, 0x00, 0x00, 0x01, 0xF3, 0x04, 0x00, 0x19, 0xE0, 0x0D, 0xFE, 0xFC, 0x30, 0x00, 0x13, 0x10,
+    0x00, 0x00, 0x00, 0x47, 0x10, 0x00, 0x02, 0xEF, 0x30, 0x00, 0x0B, 0x7F, 0x30, 0x00, 0x6B, 0x1F,
+    0x30, 0x01, 0xD2, 0x1F, 0x30, 0x0A, 0x70, 0x1F, 0x30, 0x4E, 0x54, 0x5F, 0x62, 0x4B, 0xBB, 0xCF,
+    0xC6, 0x00, 0x00, 0x1F, 0x30, 0x00, 0x00, 0x1F, 0x30, 0x47, 0x77, 0x73, 0x09, 0xC9, 0x99, 0x40,
+    0x99, 0x00, 0x00, 0x09, 0x94, 0x30, 0x00, 0x9E, 0xCE, 0xD2, 0x01, 0x00, 0x1B, 0xC0, 0x00, 0x00,
+    0x3F, 0x10, 0x00, 0x03, 0xF1, 0x40, 0x01, 0xBC, 0x0E, 0xFE, 0xFB, 0x20, 0x02, 0x31, 0x00, 0x00,
+    0x00, 0x06, 0x99, 0x50, 0x01, 0xCC, 0x77, 0x90, 0x08, 0xC0, 0x00, 0x00, 0x0D, 0x62, 0x41, 0x00,
+    0x1F, 0xAD, 0xCF, 0x70, 0x1F, 0xC1, 0x04, 0xF3, 0x0F, 0x70, 0x00, 0xD6, 0x0D, 0x70, 0x00, 0xD6,
+    0x07, 0xD1, 0x05, 0xF2, 0x00, 0x9E, 0xDE, 0x50, 0x00, 0x01, 0x20, 0x00, 0x77, 0x77, 0x77, 0x18,
+    0x99, 0x9C, 0xE1, 0x00, 0x00, 0xB9, 0x00, 0x00, 0x2F, 0x30, 0x00, 0x07, 0xD0, 0x00, 0x00, 0xD7,
+    0x00, 0x00, 0x4F, 0x10, 0x00, 0x0A, 0xA0, 0x00, 0x01, 0xF4, 0x00, 0x00, 0x6D, 0x00, 0x00, 0x00,
+    0x59, 0x96, 0x10, 0x07, 0xE7, 0x6C, 0xB0, 0x0D, 0x70, 0x03, 0xF2, 0x0C, 0x80, 0x04, 0xF1, 0x03,
+    0xC9, 0x8C, 0x50, 0x04, 0xD9, 0x8D, 0x80, 0x0E, 0x60, 0x02, 0xF3, 0x1F, 0x30, 0x00, 0xE5, 0x0D,
+    0x90, 0x05, 0xF2, 0x04, 0xDE, 0xDE, 0x60, 0x00, 0x02, 0x20, 0x00, 0x00, 0x59, 0x94, 0x00, 0x08,
+    0xE7, 0x7E, 0x60, 0x1F, 0x50, 0x05, 0xE0, 0x2F, 0x20, 0x02, 0xF3, 0x1F, 0x40, 0x05, 0xF5, 0x0A,
+    0xD4, 0x5D, 0xF5, 0x00, 0x7B, 0xB4, 0xF4, 0x00, 0x00, 0x04, 0xE1, 0x02, 0x00, 0x2D, 0x80, 0x08,
+    0xFE, 0xF8, 0x00, 0x00, 0x13, 0x10, 0x00, 0x59, 0x6C, 0x00, 0x00, 0x00, 0x48, 0x7D, 0x59, 0x6C,
+    0x00, 0x00, 0x00, 0x48, 0x8C, 0xB5, 0x70, 0x00, 0x00, 0x00, 0x03, 0x40, 0x00, 0x02, 0x7C, 0xE5,
+    0x01, 0x6B, 0xEA, 0x50, 0x07, 0xEB, 0x61, 0x00, 0x00, 0x5D, 0xD8, 0x30, 0x00, 0x00, 0x03, 0x9E,
+    0xC7, 0x20, 0x00, 0x00, 0x05, 0xAE, 0x70, 0x00, 0x00, 0x00, 0x12, 0x8E, 0xEE, 0xEE, 0xEE, 0x71,
+    0x22, 0x22, 0x22, 0x21, 0x35, 0x55, 0x55, 0x55, 0x27, 0xBB, 0xBB, 0xBB, 0xB6, 0x42, 0x00, 0x00,
+    0x00, 0x07, 0xEC, 0x61, 0x00, 0x00, 0x01, 0x5B, 0xEA, 0x50, 0x00, 0x00, 0x01, 0x6C, 0xE5, 0x00,
+    0x00, 0x39, 0xEC, 0x40, 0x28, 0xDD, 0x83, 0x00, 0x8E, 0xA4, 0x00, 0x00, 0x03, 0x10, 0x00, 0x00,
+    0x00, 0x03, 0x89, 0x71, 0x1D, 0x87, 0xDA, 0x01, 0x00, 0x5E, 0x00, 0x00, 0xAB, 0x00, 0x09, 0xD1,
+    0x00, 0x4E, 0x20, 0x00, 0x7B, 0x00, 0x00, 0x35, 0x00, 0x00, 0x57, 0x00, 0x00, 0x8C, 0x00, 0x00,
+    0x00, 0x01, 0x21, 0x00, 0x00, 0x00, 0x00, 0x4B, 0xDC, 0xDD, 0x60, 0x00, 0x00, 0x7C, 0x40, 0x00,
+    0x2A, 0xA0, 0x00, 0x4C, 0x10, 0x24, 0x11, 0x0A, 0x70, 0x0B, 0x30, 0x6E, 0xAC, 0xD3, 0x1D, 0x01,
+    0xD0, 0x1E, 0x20, 0x2F, 0x30, 0xD1, 0x2B, 0x02, 0xD0, 0x00, 0xC3, 0x0D, 0x11, 0xD0, 0x1E, 0x10,
+    0x1E, 0x34, 0xB0, 0x0C, 0x20, 0x9C, 0x7B, 0xDA, 0xC2, 0x00, 0x5B, 0x00, 0x46, 0x25, 0x40, 0x00,
+    0x00, 0x9B, 0x20, 0x00, 0x17, 0x00, 0x00, 0x00, 0x6D, 0xCA, 0xBD, 0x70, 0x00, 0x00, 0x00, 0x02,
+    0x43, 0x00, 0x00, 0x00, 0x00, 0x03, 0x72, 0x00, 0x00, 0x00, 0xAF, 0x80, 0x00, 0x00, 0x1F, 0x7E,
+    0x00, 0x00, 0x06, 0xC0, 0xE5, 0x00, 0x00, 0xC7, 0x08, 0xA0, 0x00, 0x3F, 0x10, 0x3F, 0x10, 0x08,
+    0xD7, 0x77, 0xE7, 0x00, 0xEA, 0x99, 0x9B, 0xC0, 0x5E, 0x10, 0x00, 0x1F, 0x3B, 0x90, 0x00, 0x00,
+    0xB9, 0x57, 0x77, 0x61, 0x0B, 0xC9, 0x9C, 0xE3, 0xB8, 0x00, 0x0C, 0x8B, 0x80, 0x00, 0xC8, 0xBC,
+    0x99, 0xBB, 0x1B, 0xC7, 0x79, 0xD4, 0xB8, 0x00, 0x07, 0xDB, 0x80, 0x00, 0x6F, 0xB9, 0x11, 0x3C,
+    0xBB, 0xFF, 0xFE, 0xA1, 0x00, 0x05, 0x99, 0x83, 0x00, 0x1C, 0xD8, 0x68, 0xE5, 0x09, 0xD1, 0x00,
+    0x01, 0x21, 0xF5, 0x00, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x04, 0xF1, 0x00, 0x00, 0x00, 0x2F,
+    0x30, 0x00, 0x00, 0x00, 0xD8, 0x00, 0x00, 0x00, 0x05, 0xF6, 0x00, 0x17, 0x50, 0x05, 0xDE, 0xDF,
+    0xB2, 0x00, 0x00, 0x22, 0x10, 0x00, 0x57, 0x77, 0x52, 0x00, 0x0B, 0xC9, 0x9B, 0xFA, 0x10, 0xB8,
+    0x00, 0x02, 0xD9, 0x0B, 0x80, 0x00, 0x06, 0xF1, 0xB8, 0x00, 0x00, 0x2F, 0x3B, 0x80, 0x00, 0x02,
+    0xF3, 0xB8, 0x00, 0x00, 0x4F, 0x2B, 0x80, 0x00, 0x09, 0xD0, 0xB9, 0x11, 0x39, 0xF4, 0x0B, 0xFF,
+    0xFD, 0xA3, 0x00, 0x57, 0x77, 0x77, 0x2B, 0xC9, 0x99, 0x92, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00,
+    0x00, 0xBC, 0x99, 0x99, 0x1B, 0xC7, 0x77, 0x70, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9,
+    0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF6, 0x57, 0x77, 0x75, 0xBC, 0x99, 0x97, 0xB8, 0x00, 0x00, 0xB8,
+    0x00, 0x00, 0xBC, 0x99, 0x93, 0xBC, 0x77, 0x72, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00,
+    0x00, 0xB8, 0x00, 0x00, 0x00, 0x05, 0x9A, 0x85, 0x00, 0x01, 0xBD, 0x86, 0x8C, 0xB0, 0x09, 0xC1,
+    0x00, 0x00, 0x40, 0x1F, 0x50, 0x00, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x00, 0x4F, 0x10, 0x05,
+    0xEE, 0xE0, 0x2F, 0x30, 0x01, 0x25, 0xF0, 0x0D, 0x80, 0x00, 0x04, 0xF0, 0x05, 0xF6, 0x00, 0x06,
+    0xF0, 0x00, 0x5D, 0xFD, 0xED, 0x60, 0x00, 0x00, 0x23, 0x10, 0x00, 0x54, 0x00, 0x00, 0x64, 0xB8,
+    0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xBC, 0x99, 0x99, 0xE7, 0xBC,
+    0x77, 0x77, 0xD7, 0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xB8,
+    0x00, 0x00, 0xC7, 0x54, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0x00, 0x54, 0x00,
+    0xB8, 0x00, 0xB8, 0x00, 0xB8, 0x00, 0xB8, 0x00, 0xB8, 0x00, 0xB8, 0x00, 0xB8, 0x00, 0xB8, 0x00,
+    0xB8, 0x00, 0xD7, 0x5A, 0xE2, 0x68, 0x30, 0x54, 0x00, 0x03, 0x71, 0xB8, 0x00, 0x4E, 0x60, 0xB8,
+    0x05, 0xE6, 0x00, 0xB8, 0x5E, 0x50, 0x00, 0xBD, 0xE4, 0x00, 0x00, 0xBD, 0xF5, 0x00, 0x00, 0xB8,
+    0x6F, 0x50, 0x00, 0xB8, 0x06, 0xF5, 0x00, 0xB8, 0x00, 0x6F, 0x50, 0xB8, 0x00, 0x06, 0xF5, 0x54,
+    0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00,
+    0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B, 0xFF,
+    0xFF, 0xF3, 0x57, 0x20, 0x00, 0x07, 0x7B, 0xF8, 0x00, 0x05, 0xFE, 0xBA, 0xE0, 0x00, 0xBA, 0xEB,
+    0x8C, 0x50, 0x1E, 0x5E, 0xB8, 0x6A, 0x07, 0xA5, 0xEB, 0x81, 0xE1, 0xD4, 0x5E, 0xB8, 0x0A, 0xAD,
+    0x05, 0xEB, 0x80, 0x4F, 0x80, 0x5E, 0xB8, 0x00, 0x31, 0x05, 0xEB, 0x80, 0x00, 0x00, 0x5E, 0x57,
+    0x10, 0x00, 0x63, 0xBF, 0x80, 0x00, 0xC7, 0xBC, 0xE1, 0x00, 0xC7, 0xB8, 0xB9, 0x00, 0xC7, 0xB8,
+    0x3F, 0x20, 0xC7, 0xB8, 0x0A, 0xA0, 0xC7, 0xB8, 0x02, 0xF3, 0xC7, 0xB8, 0x00, 0x9B, 0xC7, 0xB8,
+    0x00, 0x2E, 0xE7, 0xB8, 0x00, 0x08, 0xF7, 0x00, 0x05, 0x99, 0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE,
+    0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7,
+    0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05,
+    0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x57, 0x77,
+    0x40, 0x0B, 0xC9, 0x9E, 0xB0, 0xB8, 0x00, 0x2F, 0x4B, 0x80, 0x00, 0xF6, 0xB8, 0x00, 0x5F, 0x3B,
+    0xED, 0xEF, 0x70, 0xBA, 0x33, 0x10, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00,
+    0x00, 0x00, 0x05, 0x99, 0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0,
+    0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F,
+    0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D,
+    0xEE, 0xF7, 0x00, 0x00, 0x00, 0x23, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x3A, 0x30, 0x57, 0x77, 0x50,
+    0x00, 0xBC, 0x99, 0xEC, 0x00, 0xB8, 0x00, 0x2F, 0x40, 0xB8, 0x00, 0x0F, 0x60, 0xB9, 0x11, 0x8E,
+    0x20, 0xBF, 0xFF, 0xF4, 0x00, 0xB8, 0x01, 0xAC, 0x00, 0xB8, 0x00, 0x1E, 0x60, 0xB8, 0x00, 0x07,
+    0xD0, 0xB8, 0x00, 0x01, 0xE6, 0x00, 0x59, 0x98, 0x40, 0x09, 0xD7, 0x68, 0xD0, 0x1F, 0x40, 0x00,
+    0x00, 0x1F, 0x40, 0x00, 0x00, 0x0A, 0xFA, 0x73, 0x00, 0x00, 0x59, 0xDF, 0xB0, 0x00, 0x00, 0x04,
+    0xF6, 0x00, 0x00, 0x00, 0xD8, 0x17, 0x10, 0x04, 0xF5, 0x1C, 0xFD, 0xEE, 0x80, 0x00, 0x12, 0x20,
+    0x00, 0x07, 0x77, 0x77, 0x77, 0x70, 0x99, 0x9D, 0xD9, 0x99, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00,
+    0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00,
+    0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x63, 0x00,
+    0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00,
+    0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4, 0x7E, 0x20,
+    0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x64, 0x00, 0x00, 0x05, 0x58, 0xC0,
+    0x00, 0x00, 0xE6, 0x2F, 0x30, 0x00, 0x4F, 0x10, 0xB8, 0x00, 0x0A, 0xA0, 0x06, 0xE0, 0x01, 0xF4,
+    0x00, 0x1E, 0x50, 0x6D, 0x00, 0x00, 0x9A, 0x0C, 0x80, 0x00, 0x04, 0xF4, 0xF2, 0x00, 0x00, 0x0D,
+    0xEB, 0x00, 0x00, 0x00, 0x7F, 0x50, 0x00, 0x46, 0x00, 0x03, 0x71, 0x00, 0x07, 0x35, 0xE0, 0x00,
+    0x8F, 0x60, 0x02, 0xF3, 0x1F, 0x30, 0x0C, 0xAA, 0x00, 0x5E, 0x00, 0xC7, 0x01, 0xE3, 0xD0, 0x09,
+    0xA0, 0x09, 0xB0, 0x4C, 0x0E, 0x20, 0xD6, 0x00, 0x5E, 0x08, 0x80, 0xA6, 0x2F, 0x30, 0x01, 0xF3,
+    0xC4, 0x06, 0xA5, 0xE0, 0x00, 0x0C, 0x8E, 0x10, 0x3D, 0x9A, 0x00, 0x00, 0x9E, 0xC0, 0x00, 0xEE,
+    0x60, 0x00, 0x05, 0xF8, 0x00, 0x0A, 0xF3, 0x00, 0x07, 0x30, 0x00, 0x27, 0x10, 0x8C, 0x00, 0x0B,
+    0xA0, 0x00, 0xC8, 0x06, 0xE1, 0x00, 0x03, 0xF5, 0xE5, 0x00, 0x00, 0x08, 0xFA, 0x00, 0x00, 0x00,
+    0x9F, 0x80, 0x00, 0x00, 0x4E, 0x5F, 0x30, 0x00, 0x1D, 0x70, 0x8C, 0x00, 0x09, 0xB0, 0x00, 0xD8,
+    0x04, 0xE2, 0x00, 0x03, 0xF3, 0x06, 0x40, 0x00, 0x04, 0x60, 0x5E, 0x10, 0x02, 0xE5, 0x00, 0xAA,
+    0x00, 0xB9, 0x00, 0x01, 0xE5, 0x6D, 0x10, 0x00, 0x05, 0xEE, 0x40, 0x00, 0x00, 0x0B, 0xA0, 0x00,
+    0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A,
+    0x90, 0x00, 0x27, 0x77, 0x77, 0x77, 0x12, 0x99, 0x99, 0x9D, 0xF2, 0x00, 0x00, 0x03, 0xE6, 0x00,
+    0x00, 0x01, 0xD8, 0x00, 0x00, 0x00, 0xBB, 0x00, 0x00, 0x00, 0x9D, 0x10, 0x00, 0x00, 0x6E, 0x30,
+    0x00, 0x00, 0x3F, 0x50, 0x00, 0x00, 0x1D, 0x91, 0x11, 0x11, 0x06, 0xFF, 0xFF, 0xFF, 0xF5, 0xCD,
+    0xBD, 0x50, 0xD4, 0x0D, 0x40, 0xD4, 0x0D, 0x40, 0xD4, 0x0D, 0x40, 0xD4, 0x0D, 0x40, 0xD6, 0x29,
+    0xB9, 0x71, 0x00, 0x0A, 0x60, 0x00, 0x6A, 0x00, 0x01, 0xE1, 0x00, 0x0C, 0x50, 0x00, 0x79, 0x00,
+    0x02, 0xE0, 0x00, 0x0D, 0x40, 0x00, 0x88, 0x00, 0x03, 0xD0, 0x00, 0x0E, 0x20, 0x00, 0x21, 0xAD,
+    0xD0, 0x4E, 0x03, 0xE0, 0x3E, 0x03, 0xE0, 0x3E, 0x03, 0xE0, 0x3E, 0x03, 0xE0, 0x3E, 0x26, 0xE8,
+    0xBA, 0x00, 0x03, 0x72, 0x00, 0x00, 0x03, 0xEC, 0xD2, 0x00, 0x03, 0xE6, 0x07, 0xD2, 0x02, 0xD5,
+    0x00, 0x06, 0xD2, 0x01, 0x00, 0x00, 0x01, 0x00, 0x2D, 0xDD, 0xDD, 0xD8, 0x01, 0x11, 0x11, 0x11,
+    0x43, 0x00, 0x3D, 0x10, 0x04, 0xB0, 0x00, 0x24, 0x30, 0x00, 0x9D, 0xBD, 0xD1, 0x01, 0x00, 0x0A,
+    0x80, 0x04, 0x77, 0xAB, 0x0B, 0xC7, 0x6A, 0xC2, 0xF1, 0x00, 0x7C, 0x2F, 0x20, 0x2D, 0xC0, 0x9E,
+    0xCD, 0x9C, 0x00, 0x12, 0x00, 0x00, 0xB5, 0x00, 0x00, 0x0C, 0x50, 0x00, 0x00, 0xC5, 0x14, 0x20,
+    0x0C, 0xAD, 0xBE, 0x70, 0xCD, 0x10, 0x3F, 0x2C, 0x70, 0x00, 0xC7, 0xC6, 0x00, 0x0A, 0x8C, 0x70,
+    0x00, 0xC6, 0xCD, 0x10, 0x4F, 0x2C, 0x9D, 0xCE, 0x60, 0x00, 0x02, 0x10, 0x00, 0x00, 0x03, 0x42,
+    0x00, 0x3D, 0xDB, 0xD5, 0x0D, 0x90, 0x00, 0x13, 0xF1, 0x00, 0x00, 0x4E, 0x00, 0x00, 0x02, 0xF1,
+    0x00, 0x00, 0x0C, 0xA0, 0x01, 0x10, 0x2C, 0xEC, 0xE4, 0x00, 0x02, 0x21, 0x00, 0x00, 0x00, 0x01,
+    0xD1, 0x00, 0x00, 0x01, 0xF1, 0x00, 0x14, 0x21, 0xF1, 0x04, 0xEC, 0xC9, 0xF1, 0x0D, 0x70, 0x09,
+    0xF1, 0x3F, 0x10, 0x03, 0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x3F, 0x10, 0x03, 0xF1, 0x0D, 0x80, 0x0A,
+    0xF1, 0x03, 0xDD, 0xD8, 0xF1, 0x00, 0x02, 0x10, 0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE,
+    0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00,
+    0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31, 0x00, 0x01, 0xAD, 0xB0,
+    0x7C, 0x21, 0x19, 0xA2, 0x18, 0xEE, 0xC6, 0x09, 0x90, 0x00, 0x99, 0x00, 0x09, 0x90, 0x00, 0x99,
+    0x00, 0x09, 0x90, 0x00, 0x99, 0x00, 0x00, 0x14, 0x20, 0x20, 0x04, 0xEB, 0xC9, 0xF1, 0x0E, 0x60,
+    0x09, 0xF1, 0x3F, 0x00, 0x03, 0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x2F, 0x10, 0x04, 0xF1, 0x0C, 0xA1,
+    0x1B, 0xF1, 0x02, 0xBF, 0xD6, 0xF1, 0x00, 0x00, 0x05, 0xE0, 0x04, 0x74, 0x6E, 0x70, 0x03, 0x9A,
+    0xA5, 0x00, 0xB5, 0x00, 0x00, 0x0C, 0x50, 0x00, 0x00, 0xC5, 0x14, 0x20, 0x0C, 0x9D, 0xBF, 0x70,
+    0xCC, 0x10, 0x5E, 0x0C, 0x60, 0x01, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0xC5, 0x00,
+    0x0F, 0x2C, 0x50, 0x00, 0xF2, 0xA5, 0x74, 0x11, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0x00,
+    0xA5, 0x00, 0x74, 0x00, 0x11, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00,
+    0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x15, 0xE3, 0x2A, 0x50, 0xB5, 0x00, 0x00, 0x0C, 0x50, 0x00, 0x00,
+    0xC5, 0x00, 0x02, 0x0C, 0x50, 0x1C, 0x90, 0xC5, 0x2D, 0x80, 0x0C, 0x8D, 0x60, 0x00, 0xCD, 0xD1,
+    0x00, 0x0C, 0x59, 0xC1, 0x00, 0xC5, 0x09, 0xD1, 0x0C, 0x50, 0x08, 0xD2, 0xA5, 0xC6, 0xC6, 0xC6,
+    0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0x11, 0x14, 0x20, 0x03, 0x40, 0x0C, 0x9D, 0xCF, 0x5A, 0xCD,
+    0xD1, 0xCC, 0x00, 0x7F, 0x60, 0x0D, 0x6C, 0x60, 0x04, 0xF0, 0x00, 0x98, 0xC5, 0x00, 0x3E, 0x00,
+    0x09, 0x8C, 0x50, 0x03, 0xE0, 0x00, 0x98, 0xC5, 0x00, 0x3E, 0x00, 0x09, 0x8C, 0x50, 0x03, 0xE0,
+    0x00, 0x98, 0x11, 0x14, 0x20, 0x0C, 0x9D, 0xBF, 0x70, 0xCC, 0x10, 0x5E, 0x0C, 0x60, 0x01, 0xF2,
+    0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0x00, 0x03,
+    0x30, 0x00, 0x03, 0xEC, 0xCD, 0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00,
+    0x00, 0xF3, 0x3F, 0x10, 0x02, 0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02,
+    0x20, 0x00, 0x11, 0x14, 0x20, 0x0C, 0xAD, 0xBE, 0x70, 0xCD, 0x10, 0x3F, 0x2C, 0x70, 0x00, 0xC7,
+    0xC6, 0x00, 0x0A, 0x8C, 0x70, 0x00, 0xC6, 0xCD, 0x10, 0x4F, 0x2C, 0x9D, 0xCE, 0x60, 0xC5, 0x02,
+    0x10, 0x0C, 0x50, 0x00, 0x00, 0x94, 0x00, 0x00, 0x00, 0x00, 0x14, 0x20, 0x20, 0x04, 0xEC, 0xC9,
+    0xF1, 0x0D, 0x70, 0x09, 0xF1, 0x3F, 0x10, 0x03, 0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x3F, 0x10, 0x03,
+    0xF1, 0x0D, 0x80, 0x0A, 0xF1, 0x03, 0xDD, 0xD8, 0xF1, 0x00, 0x02, 0x11, 0xF1, 0x00, 0x00, 0x01,
+    0xF1, 0x00, 0x00, 0x01, 0xB1, 0x11, 0x14, 0x1C, 0x9D, 0xC5, 0xCC, 0x10, 0x0C, 0x70, 0x00, 0xC5,
+    0x00, 0x0C, 0x50, 0x00, 0xC5, 0x00, 0x0C, 0x50, 0x00, 0x00, 0x24, 0x30, 0x00, 0xBD, 0xBC, 0xB0,
+    0x3E, 0x00, 0x01, 0x02, 0xF7, 0x20, 0x00, 0x04, 0xBE, 0xD5, 0x00, 0x00, 0x06, 0xF1, 0x22, 0x00,
+    0x3F, 0x13, 0xED, 0xCE, 0x70, 0x00, 0x22, 0x10, 0x00, 0x01, 0x10, 0x00, 0xC6, 0x00, 0x1C, 0x72,
+    0x18, 0xED, 0xC9, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0B, 0x80, 0x00,
+    0x4D, 0xFC, 0x10, 0x00, 0x02, 0x0D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1,
+    0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x03, 0xF1, 0xB9, 0x00, 0xAF, 0x13, 0xED, 0xD7, 0xF1, 0x00, 0x21,
+    0x00, 0x00, 0x11, 0x00, 0x00, 0x20, 0x6D, 0x00, 0x02, 0xF1, 0x1E, 0x30, 0x08, 0xB0, 0x0A, 0x90,
+    0x0D, 0x50, 0x04, 0xE0, 0x4E, 0x00, 0x00, 0xD5, 0xA9, 0x00, 0x00, 0x8B, 0xE3, 0x00, 0x00, 0x2F,
+    0xC0, 0x00, 0x11, 0x00, 0x12, 0x00, 0x02, 0x04, 0xD0, 0x08, 0xF2, 0x04, 0xE0, 0x1F, 0x20, 0xCC,
+    0x60, 0x8A, 0x00, 0xC6, 0x1E, 0x5A, 0x0B, 0x60, 0x08, 0xA5, 0xB1, 0xE1, 0xF2, 0x00, 0x4E, 0x97,
+    0x0C, 0x8D, 0x00, 0x00, 0xEE, 0x30, 0x8E, 0x90, 0x00, 0x0B, 0xE0, 0x04, 0xF5, 0x00, 0x12, 0x00,
+    0x00, 0x20, 0x1D, 0x70, 0x0A, 0xA0, 0x03, 0xE3, 0x7D, 0x10, 0x00, 0x7D, 0xE3, 0x00, 0x00, 0x2F,
+    0xB0, 0x00, 0x00, 0xBA, 0xD6, 0x00, 0x08, 0xC1, 0x4E, 0x30, 0x4E, 0x20, 0x07, 0xD1, 0x11, 0x00,
+    0x00, 0x20, 0x5D, 0x00, 0x03, 0xF1, 0x0E, 0x40, 0x09, 0xA0, 0x08, 0xA0, 0x1E, 0x40, 0x02, 0xF1,
+    0x6D, 0x00, 0x00, 0xB7, 0xC7, 0x00, 0x00, 0x5E, 0xF1, 0x00, 0x00, 0x0D, 0xA0, 0x00, 0x00, 0x1E,
+    0x40, 0x00, 0x04, 0x9C, 0x00, 0x00, 0x0A, 0x92, 0x00, 0x00, 0x02, 0x22, 0x22, 0x03, 0xCC, 0xCD,
+    0xF4, 0x00, 0x00, 0x9C, 0x00, 0x00, 0x7D, 0x10, 0x00, 0x5E, 0x20, 0x00, 0x4E, 0x40, 0x00, 0x2E,
+    0x50, 0x00, 0x07, 0xFE, 0xEE, 0xE4, 0x00, 0x09, 0xC8, 0x00, 0x4E, 0x20, 0x00, 0x6C, 0x00, 0x00,
+    0x6C, 0x00, 0x00, 0x7B, 0x00, 0x26, 0xD6, 0x00, 0x39, 0xD4, 0x00, 0x00, 0x8B, 0x00, 0x00, 0x6C,
+    0x00, 0x00, 0x6C, 0x00, 0x00, 0x5D, 0x00, 0x00, 0x1C, 0xD8, 0x00, 0x00, 0x11, 0x5A, 0x5B, 0x5B,
+    0x5B, 0x5B, 0x5B, 0x5B, 0x5B, 0x5B, 0x5B, 0x5B, 0x5B, 0x5B, 0x01, 0x5D, 0xA2, 0x00, 0x01, 0xB8,
+    0x00, 0x00, 0x8A, 0x00, 0x00, 0x8A, 0x00, 0x00, 0x7B, 0x00, 0x00, 0x3E, 0x73, 0x00, 0x2C, 0xA6,
+    0x00, 0x7C, 0x00, 0x00, 0x8A, 0x00, 0x00, 0x8A, 0x00, 0x00, 0x99, 0x00, 0x4C, 0xE4, 0x00, 0x11,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xBF, 0xE9, 0x43, 0x87, 0x75, 0x13, 0x8C, 0xC8, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0xF4, 0x08, 0x20, 0x10, 0x0E, 0x20, 0xF3, 0x1F, 0x41, 0xF4,
+    0x1F, 0x41, 0xF4, 0x06, 0x10, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x2B, 0x30, 0x09, 0xEE,
+    0xCA, 0x7D, 0x1A, 0x01, 0xC7, 0x0A, 0x00, 0xD5, 0x0A, 0x00, 0xC7, 0x0A, 0x00, 0x6E, 0x2A, 0x02,
+    0x08, 0xEE, 0xE9, 0x00, 0x0A, 0x10, 0x00, 0x0A, 0x00, 0x00, 0x04, 0x99, 0x60, 0x00, 0x4F, 0x86,
+    0xA0, 0x00, 0x9B, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x0D, 0xFE, 0xEE,
+    0x10, 0x00, 0xA9, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x01, 0xB9, 0x11, 0x10, 0x3F, 0xFF, 0xFF,
+    0xF2, 0x05, 0x00, 0x00, 0x33, 0x2D, 0x8A, 0xB7, 0xE5, 0x04, 0xE6, 0x4C, 0x80, 0x08, 0x80, 0x03,
+    0xC0, 0x07, 0x80, 0x04, 0xB0, 0x04, 0xF8, 0x6D, 0x90, 0x2E, 0x68, 0x95, 0xD6, 0x03, 0x00, 0x00,
+    0x21, 0x26, 0x00, 0x00, 0x44, 0x1E, 0x40, 0x01, 0xE3, 0x06, 0xC0, 0x08, 0xA0, 0x00, 0xD5, 0x2E,
+    0x20, 0x2B, 0xDD, 0xAE, 0xB4, 0x00, 0x0C, 0xE2, 0x00, 0x1A, 0xAD, 0xEA, 0xA4, 0x02, 0x28, 0xC2,
+    0x21, 0x00, 0x08, 0xC0, 0x00, 0x00, 0x08, 0xC0, 0x00, 0x01, 0x5B, 0x5B, 0x5B, 0x5B, 0x48, 0x00,
+    0x36, 0x5B, 0x5B, 0x5B, 0x5B, 0x12, 0x02, 0x89, 0x71, 0x0C, 0x94, 0x73, 0x0E, 0x50, 0x00, 0x08,
+    0xE7, 0x00, 0x3D, 0x3A, 0xD2, 0x6B, 0x00, 0x7C, 0x1D, 0x81, 0x3C, 0x01, 0xAD, 0xC4, 0x00, 0x04,
+    0xE4, 0x01, 0x00, 0xC7, 0x0D, 0xBC, 0xD2, 0x00, 0x33, 0x00, 0x88, 0x2D, 0x24, 0x41, 0x61, 0x00,
+    0x01, 0x56, 0x51, 0x00, 0x00, 0x05, 0xA5, 0x45, 0xA5, 0x00, 0x04, 0x90, 0x68, 0x72, 0x84, 0x00,
+    0xA0, 0xB9, 0x34, 0x30, 0xA0, 0x28, 0x3D, 0x00, 0x00, 0x08, 0x23, 0x74, 0xB0, 0x00, 0x00, 0x73,
+    0x19, 0x1E, 0x10, 0x00, 0x09, 0x10, 0x92, 0x5D, 0x9A, 0x52, 0x90, 0x01, 0xB3, 0x02, 0x13, 0xB1,
+    0x00, 0x01, 0x8A, 0xAA, 0x81, 0x00, 0x06, 0x99, 0x30, 0x04, 0x23, 0xD1, 0x05, 0xAB, 0xE3, 0x3C,
+    0x10, 0xB4, 0x2D, 0x46, 0xE4, 0x04, 0x85, 0x52, 0x1B, 0xBB, 0xB3, 0x00, 0x00, 0x00, 0x00, 0x40,
+    0x04, 0x06, 0xC0, 0x99, 0x8C, 0x2B, 0x90, 0xC7, 0x2E, 0x40, 0x1B, 0x83, 0xD5, 0x00, 0xA0, 0x28,
+    0x00, 0x00, 0x00, 0x47, 0x77, 0x77, 0x77, 0x46, 0x99, 0x99, 0x99, 0xC8, 0x00, 0x00, 0x00, 0x08,
+    0x80, 0x00, 0x00, 0x00, 0x88, 0x00, 0x00, 0x00, 0x01, 0x10, 0x01, 0x11, 0x05, 0xEE, 0xE1, 0x00,
+    0x01, 0x56, 0x51, 0x00, 0x00, 0x05, 0xA5, 0x45, 0xA5, 0x00, 0x04, 0x93, 0x77, 0x40, 0x84, 0x00,
+    0xA0, 0x7A, 0x3A, 0x70, 0xA0, 0x28, 0x07, 0x91, 0x97, 0x08, 0x23, 0x70, 0x7C, 0xCB, 0x00, 0x73,
+    0x19, 0x07, 0x80, 0xD3, 0x09, 0x10, 0x92, 0x67, 0x04, 0xA2, 0x90, 0x01, 0xB3, 0x00, 0x03, 0xB1,
+    0x00, 0x01, 0x8A, 0xAA, 0x81, 0x00, 0x7A, 0xAA, 0x12, 0x44, 0x41, 0x07, 0x93, 0x08, 0x84, 0xC1,
+    0xB1, 0x09, 0x36, 0xB8, 0xC0, 0x03, 0x51, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x1F, 0x00,
+    0x00, 0x00, 0x01, 0xF0, 0x00, 0x06, 0xAA, 0xBF, 0xAA, 0xA5, 0x46, 0x67, 0xF6, 0x66, 0x30, 0x00,
+    0x1F, 0x00, 0x00, 0x00, 0x01, 0xC0, 0x00, 0x01, 0x11, 0x11, 0x11, 0x11, 0x9F, 0xFF, 0xFF, 0xFF,
+    0x80, 0x28, 0x96, 0x02, 0x22, 0xC4, 0x00, 0x0C, 0x20, 0x0A, 0x60, 0x1B, 0x50, 0x05, 0xBB, 0xB4,
+    0x18, 0x97, 0x01, 0x21, 0xB5, 0x02, 0x7B, 0x20, 0x15, 0xB4, 0x00, 0x08, 0x74, 0xAB, 0x91, 0x00,
+    0x61, 0x08, 0x90, 0x4B, 0x00, 0x10, 0x00, 0x02, 0x0D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D,
+    0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD9, 0x00, 0x8F, 0x1D, 0xBD, 0xDA,
+    0xEE, 0xD4, 0x12, 0x01, 0x1D, 0x40, 0x00, 0x00, 0x93, 0x00, 0x00, 0x00, 0x02, 0x67, 0x76, 0x5F,
+    0xFD, 0x5D, 0xDF, 0xFB, 0x0D, 0xEF, 0xFB, 0x0D, 0xAF, 0xFB, 0x0D, 0x18, 0xDB, 0x0D, 0x00, 0x2B,
+    0x0D, 0x00, 0x2B, 0x0D, 0x00, 0x2B, 0x0D, 0x00, 0x2B, 0x0D, 0x00, 0x2B, 0x0D, 0x00, 0x13, 0x03,
+    0x33, 0x9B, 0x33, 0x00, 0x93, 0x15, 0xA6, 0x17, 0x71, 0x16, 0x82, 0x01, 0x5C, 0x30, 0x00, 0xB3,
+    0x00, 0x0B, 0x30, 0x00, 0xB3, 0x00, 0xAB, 0xB5, 0x02, 0x99, 0x30, 0x1D, 0x43, 0xD2, 0x5A, 0x00,
+    0x87, 0x5A, 0x00, 0x87, 0x1D, 0x54, 0xD2, 0x02, 0x78, 0x30, 0x1B, 0xBB, 0xB3, 0x00, 0x00, 0x00,
+    0x40, 0x04, 0x00, 0x99, 0x0D, 0x60, 0x09, 0xA2, 0xC7, 0x04, 0xE2, 0x7C, 0x5D, 0x29, 0xB1, 0x91,
+    0x0A, 0x00, 0x00, 0x00, 0x00, 0x16, 0x82, 0x00, 0x00, 0x46, 0x00, 0x01, 0x5C, 0x30, 0x00, 0x1D,
+    0x20, 0x00, 0x00, 0xB3, 0x00, 0x08, 0x70, 0x00, 0x00, 0x0B, 0x30, 0x02, 0xC0, 0x00, 0x00, 0x00,
+    0xB3, 0x00, 0xB4, 0x00, 0x41, 0x00, 0xAB, 0xB5, 0x5A, 0x00, 0x5D, 0x50, 0x00, 0x00, 0x1D, 0x20,
+    0x29, 0x95, 0x00, 0x00, 0x08, 0x70, 0x0A, 0x19, 0x50, 0x00, 0x03, 0xC0, 0x03, 0xBA, 0xDC, 0x20,
+    0x00, 0xB4, 0x00, 0x00, 0x09, 0x50, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x82, 0x00,
+    0x00, 0x46, 0x00, 0x15, 0xC3, 0x00, 0x01, 0xD2, 0x00, 0x00, 0xB3, 0x00, 0x08, 0x70, 0x00, 0x00,
+    0xB3, 0x00, 0x2C, 0x00, 0x00, 0x00, 0xB3, 0x00, 0xB4, 0x36, 0x40, 0x0A, 0xBB, 0x55, 0xA0, 0x85,
+    0xA8, 0x00, 0x00, 0x1D, 0x20, 0x00, 0x69, 0x00, 0x00, 0x87, 0x00, 0x03, 0xC1, 0x00, 0x03, 0xC0,
+    0x00, 0x3C, 0x20, 0x00, 0x0B, 0x40, 0x00, 0xEC, 0xB8, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x18,
+    0x97, 0x00, 0x00, 0x46, 0x00, 0x01, 0x21, 0xB5, 0x00, 0x1D, 0x20, 0x00, 0x02, 0x7B, 0x20, 0x08,
+    0x70, 0x00, 0x00, 0x15, 0xB4, 0x02, 0xC0, 0x00, 0x00, 0x00, 0x08, 0x70, 0xB4, 0x00, 0x41, 0x04,
+    0xAB, 0x91, 0x5A, 0x00, 0x5D, 0x50, 0x00, 0x00, 0x1D, 0x20, 0x29, 0x95, 0x00, 0x00, 0x08, 0x70,
+    0x0A, 0x19, 0x50, 0x00, 0x03, 0xC0, 0x03, 0xBA, 0xDC, 0x20, 0x00, 0xB4, 0x00, 0x00, 0x09, 0x50,
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0x0D, 0x60, 0x00, 0x07, 0x30,
+    0x00, 0x07, 0x30, 0x00, 0x0D, 0x60, 0x00, 0x3F, 0x20, 0x03, 0xE6, 0x00, 0x0D, 0x70, 0x00, 0x1F,
+    0x40, 0x02, 0x0B, 0xD8, 0xAE, 0x00, 0x68, 0x61, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x97, 0x00,
+    0x00, 0x00, 0x00, 0x81, 0x00, 0x00, 0x00, 0x37, 0x20, 0x00, 0x00, 0x0A, 0xF8, 0x00, 0x00, 0x01,
+    0xF7, 0xE0, 0x00, 0x00, 0x6C, 0x0E, 0x50, 0x00, 0x0C, 0x70, 0x8A, 0x00, 0x03, 0xF1, 0x03, 0xF1,
+    0x00, 0x8D, 0x77, 0x7E, 0x70, 0x0E, 0xA9, 0x99, 0xBC, 0x05, 0xE1, 0x00, 0x01, 0xF3, 0xB9, 0x00,
+    0x00, 0x0B, 0x90, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x09, 0x70, 0x00, 0x00, 0x02, 0x70, 0x00,
+    0x00, 0x00, 0x37, 0x20, 0x00, 0x00, 0x0A, 0xF8, 0x00, 0x00, 0x01, 0xF7, 0xE0, 0x00, 0x00, 0x6C,
+    0x0E, 0x50, 0x00, 0x0C, 0x70, 0x8A, 0x00, 0x03, 0xF1, 0x03, 0xF1, 0x00, 0x8D, 0x77, 0x7E, 0x70,
+    0x0E, 0xA9, 0x99, 0xBC, 0x05, 0xE1, 0x00, 0x01, 0xF3, 0xB9, 0x00, 0x00, 0x0B, 0x90, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x9B, 0x70, 0x00, 0x00, 0x26, 0x07, 0x10, 0x00, 0x00, 0x37, 0x20, 0x00,
+    0x00, 0x0A, 0xF8, 0x00, 0x00, 0x01, 0xF7, 0xE0, 0x00, 0x00, 0x6C, 0x0E, 0x50, 0x00, 0x0C, 0x70,
+    0x8A, 0x00, 0x03, 0xF1, 0x03, 0xF1, 0x00, 0x8D, 0x77, 0x7E, 0x70, 0x0E, 0xA9, 0x99, 0xBC, 0x05,
+    0xE1, 0x00, 0x01, 0xF3, 0xB9, 0x00, 0x00, 0x0B, 0x90, 0x00, 0x5C, 0xA9, 0x60, 0x00, 0x04, 0x13,
+    0x60, 0x00, 0x00, 0x03, 0x72, 0x00, 0x00, 0x00, 0xAF, 0x80, 0x00, 0x00, 0x1F, 0x7E, 0x00, 0x00,
+    0x06, 0xC0, 0xE5, 0x00, 0x00, 0xC7, 0x08, 0xA0, 0x00, 0x3F, 0x10, 0x3F, 0x10, 0x08, 0xD7, 0x77,
+    0xE7, 0x00, 0xEA, 0x99, 0x9B, 0xC0, 0x5E, 0x10, 0x00, 0x1F, 0x3B, 0x90, 0x00, 0x00, 0xB9, 0x00,
+    0x6B, 0x0D, 0x40, 0x00, 0x03, 0x50, 0x62, 0x00, 0x00, 0x03, 0x72, 0x00, 0x00, 0x00, 0xAF, 0x80,
+    0x00, 0x00, 0x1F, 0x7E, 0x00, 0x00, 0x06, 0xC0, 0xE5, 0x00, 0x00, 0xC7, 0x08, 0xA0, 0x00, 0x3F,
+    0x10, 0x3F, 0x10, 0x08, 0xD7, 0x77, 0xE7, 0x00, 0xEA, 0x99, 0x9B, 0xC0, 0x5E, 0x10, 0x00, 0x1F,
+    0x3B, 0x90, 0x00, 0x00, 0xB9, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0xBC, 0xA0, 0x00, 0x00, 0x48,
+    0x0A, 0x20, 0x00, 0x02, 0xC6, 0xC1, 0x00, 0x00, 0x0A, 0xF8, 0x00, 0x00, 0x01, 0xF7, 0xE0, 0x00,
+    0x00, 0x6C, 0x0E, 0x50, 0x00, 0x0C, 0x70, 0x8A, 0x00, 0x03, 0xF1, 0x03, 0xF1, 0x00, 0x8E, 0x77,
+    0x7E, 0x70, 0x0E, 0xA9, 0x99, 0xBD, 0x05, 0xE1, 0x00, 0x01, 0xF3, 0xB9, 0x00, 0x00, 0x0B, 0x90,
+    0x00, 0x01, 0x77, 0x77, 0x77, 0x75, 0x00, 0x06, 0xEA, 0xF9, 0x99, 0x96, 0x00, 0x0C, 0x74, 0xF0,
+    0x00, 0x00, 0x00, 0x3F, 0x14, 0xF0, 0x00, 0x00, 0x00, 0xAA, 0x04, 0xF9, 0x99, 0x95, 0x01, 0xF3,
+    0x04, 0xF7, 0x77, 0x74, 0x07, 0xE7, 0x7A, 0xF0, 0x00, 0x00, 0x0D, 0xA9, 0x9A, 0xF0, 0x00, 0x00,
+    0x5E, 0x10, 0x04, 0xF1, 0x11, 0x11, 0xB8, 0x00, 0x04, 0xFF, 0xFF, 0xFC, 0x00, 0x05, 0x99, 0x83,
+    0x00, 0x1C, 0xD8, 0x68, 0xE5, 0x09, 0xD1, 0x00, 0x01, 0x21, 0xF5, 0x00, 0x00, 0x00, 0x3F, 0x20,
+    0x00, 0x00, 0x04, 0xF1, 0x00, 0x00, 0x00, 0x2F, 0x30, 0x00, 0x00, 0x00, 0xD8, 0x00, 0x00, 0x00,
+    0x05, 0xF6, 0x00, 0x17, 0x50, 0x05, 0xDE, 0xDF, 0xB2, 0x00, 0x00, 0x2D, 0x30, 0x00, 0x00, 0x25,
+    0xB5, 0x00, 0x00, 0x01, 0x76, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x1C, 0x50, 0x00, 0x00, 0x18,
+    0x00, 0x05, 0x77, 0x77, 0x72, 0xBC, 0x99, 0x99, 0x2B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B,
+    0xC9, 0x99, 0x91, 0xBC, 0x77, 0x77, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x91, 0x11,
+    0x10, 0xBF, 0xFF, 0xFF, 0x60, 0x00, 0x00, 0x10, 0x00, 0x00, 0xC5, 0x00, 0x00, 0x46, 0x00, 0x05,
+    0x77, 0x77, 0x72, 0xBC, 0x99, 0x99, 0x2B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0xC9, 0x99,
+    0x91, 0xBC, 0x77, 0x77, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x91, 0x11, 0x10, 0xBF,
+    0xFF, 0xFF, 0x60, 0x00, 0x01, 0x00, 0x00, 0x0B, 0xC5, 0x00, 0x04, 0x51, 0x80, 0x05, 0x77, 0x77,
+    0x72, 0xBC, 0x99, 0x99, 0x2B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0xC9, 0x99, 0x91, 0xBC,
+    0x77, 0x77, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x91, 0x11, 0x10, 0xBF, 0xFF, 0xFF,
+    0x60, 0x08, 0x92, 0xD2, 0x00, 0x44, 0x16, 0x10, 0x57, 0x77, 0x77, 0x2B, 0xC9, 0x99, 0x92, 0xB8,
+    0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xBC, 0x99, 0x99, 0x1B, 0xC7, 0x77, 0x70, 0xB8, 0x00, 0x00,
+    0x0B, 0x80, 0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF6, 0x00, 0x03, 0xD1, 0x04, 0x50,
+    0x54, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0x01,
+    0x03, 0xD1, 0x72, 0x05, 0x40, 0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0xB8, 0x0B,
+    0x80, 0xB8, 0x00, 0x00, 0x11, 0x00, 0x3C, 0xC1, 0x07, 0x23, 0x60, 0x05, 0x40, 0x00, 0xB8, 0x00,
+    0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B,
+    0x80, 0x00, 0xB8, 0x00, 0xD4, 0x6B, 0x62, 0x35, 0x05, 0x40, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80,
+    0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x05, 0x77, 0x75, 0x20,
+    0x00, 0x0A, 0xD9, 0x9B, 0xFA, 0x10, 0x0A, 0x90, 0x00, 0x2D, 0xA0, 0x0A, 0x90, 0x00, 0x05, 0xF1,
+    0x5C, 0xB5, 0x40, 0x01, 0xF4, 0x8D, 0xD8, 0x60, 0x01, 0xF4, 0x0A, 0x90, 0x00, 0x03, 0xF3, 0x0A,
+    0x90, 0x00, 0x08, 0xE0, 0x0A, 0xA1, 0x13, 0x9F, 0x50, 0x0A, 0xFF, 0xFD, 0xA3, 0x00, 0x01, 0xCB,
+    0x7C, 0x00, 0x01, 0x41, 0x52, 0x00, 0x57, 0x10, 0x00, 0x63, 0xBF, 0x80, 0x00, 0xC7, 0xBC, 0xE1,
+    0x00, 0xC7, 0xB8, 0xB9, 0x00, 0xC7, 0xB8, 0x3F, 0x20, 0xC7, 0xB8, 0x0A, 0xA0, 0xC7, 0xB8, 0x02,
+    0xF3, 0xC7, 0xB8, 0x00, 0x9B, 0xC7, 0xB8, 0x00, 0x2E, 0xE7, 0xB8, 0x00, 0x08, 0xF7, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x01, 0xD3, 0x00, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x05, 0x99,
+    0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02,
+    0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6,
+    0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00,
+    0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1D, 0x30, 0x00, 0x00, 0x00,
+    0x54, 0x00, 0x00, 0x00, 0x05, 0x99, 0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00,
+    0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00,
+    0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80,
+    0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x01, 0xCC, 0x30, 0x00, 0x00, 0x05, 0x31, 0x70, 0x00, 0x00, 0x05, 0x99, 0x71, 0x00, 0x01, 0xCD,
+    0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20, 0x00,
+    0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00, 0x05,
+    0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00,
+    0x00, 0x09, 0xC8, 0xB2, 0x00, 0x00, 0x05, 0x04, 0x40, 0x00, 0x00, 0x05, 0x99, 0x71, 0x00, 0x01,
+    0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20,
+    0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00,
+    0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00, 0x00, 0x22, 0x00,
+    0x00, 0x00, 0x0A, 0x74, 0xD0, 0x00, 0x00, 0x05, 0x32, 0x60, 0x00, 0x00, 0x05, 0x99, 0x71, 0x00,
+    0x01, 0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F,
+    0x20, 0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80,
+    0x00, 0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00, 0x00, 0x22,
+    0x00, 0x00, 0x07, 0x10, 0x00, 0x17, 0x00, 0xBB, 0x10, 0x1C, 0x90, 0x00, 0xBB, 0x2C, 0x90, 0x00,
+    0x00, 0xBF, 0xA0, 0x00, 0x00, 0x1C, 0xEB, 0x10, 0x00, 0x1C, 0x91, 0xBB, 0x10, 0x1C, 0x90, 0x00,
+    0xBB, 0x00, 0x50, 0x00, 0x00, 0x50, 0x00, 0x05, 0x99, 0x71, 0x64, 0x01, 0xCD, 0x77, 0xBE, 0xC1,
+    0x09, 0xD1, 0x00, 0x2F, 0xD0, 0x1F, 0x50, 0x01, 0xC5, 0xF4, 0x3F, 0x20, 0x0B, 0x50, 0xE7, 0x4F,
+    0x10, 0xA7, 0x00, 0xD7, 0x2F, 0x37, 0xA0, 0x00, 0xE6, 0x0D, 0xCB, 0x00, 0x05, 0xF2, 0x07, 0xF7,
+    0x00, 0x3E, 0x80, 0x2D, 0x7D, 0xEE, 0xE8, 0x00, 0x03, 0x00, 0x22, 0x00, 0x00, 0x00, 0x10, 0x00,
+    0x00, 0x00, 0x5C, 0x00, 0x00, 0x00, 0x06, 0x40, 0x00, 0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED,
+    0x30, 0x00, 0x13, 0x20, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x05, 0xC0, 0x00, 0x00, 0x08, 0x10,
+    0x00, 0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01,
+    0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x00, 0x01, 0x00,
+    0x00, 0x00, 0x4C, 0xB0, 0x00, 0x00, 0x81, 0x44, 0x00, 0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED,
+    0x30, 0x00, 0x13, 0x20, 0x00, 0x02, 0xD2, 0x88, 0x00, 0x01, 0x61, 0x44, 0x00, 0x63, 0x00, 0x00,
+    0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4, 0x7E, 0x20, 0x08,
+    0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02,
+    0xD2, 0x00, 0x00, 0x00, 0x63, 0x00, 0x00, 0x64, 0x00, 0x00, 0x46, 0x05, 0xE1, 0x00, 0x2E, 0x50,
+    0x0A, 0xA0, 0x0B, 0x90, 0x00, 0x1E, 0x56, 0xD1, 0x00, 0x00, 0x5E, 0xE4, 0x00, 0x00, 0x00, 0xBA,
+    0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00,
+    0x00, 0xA9, 0x00, 0x00, 0x54, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xBE, 0xCC, 0x92, 0x0B, 0xA4,
+    0x4B, 0xE1, 0xB8, 0x00, 0x1F, 0x5B, 0x80, 0x01, 0xF5, 0xBA, 0x34, 0xAE, 0x1B, 0xED, 0xDA, 0x30,
+    0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0x08, 0xCD, 0x80, 0x08, 0xC3, 0x2B, 0x70, 0xC6, 0x02,
+    0xAB, 0x0C, 0x62, 0xE5, 0x10, 0xC6, 0x5D, 0x00, 0x0C, 0x62, 0xE9, 0x10, 0xC6, 0x02, 0xCD, 0x1C,
+    0x60, 0x00, 0xC8, 0xC6, 0x10, 0x0B, 0x8C, 0x6D, 0xCD, 0xC1, 0x00, 0x02, 0x20, 0x00, 0x01, 0x60,
+    0x00, 0x00, 0x09, 0x80, 0x00, 0x00, 0x0B, 0x40, 0x00, 0x02, 0x43, 0x00, 0x09, 0xDB, 0xDD, 0x10,
+    0x10, 0x00, 0xA8, 0x00, 0x47, 0x7A, 0xB0, 0xBC, 0x76, 0xAC, 0x2F, 0x10, 0x07, 0xC2, 0xF2, 0x02,
+    0xDC, 0x09, 0xEC, 0xD9, 0xC0, 0x01, 0x20, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x1D, 0x30, 0x00,
+    0x0B, 0x40, 0x00, 0x02, 0x43, 0x00, 0x09, 0xDB, 0xDD, 0x10, 0x10, 0x00, 0xA8, 0x00, 0x47, 0x7A,
+    0xB0, 0xBC, 0x76, 0xAC, 0x2F, 0x10, 0x07, 0xC2, 0xF2, 0x02, 0xDC, 0x09, 0xEC, 0xD9, 0xC0, 0x01,
+    0x20, 0x00, 0x00, 0x05, 0x20, 0x00, 0x04, 0xCC, 0x00, 0x01, 0xC1, 0x77, 0x00, 0x02, 0x43, 0x00,
+    0x09, 0xDB, 0xDD, 0x10, 0x10, 0x00, 0xA8, 0x00, 0x47, 0x7A, 0xB0, 0xBC, 0x76, 0xAC, 0x2F, 0x10,
+    0x07, 0xC2, 0xF2, 0x02, 0xDC, 0x09, 0xEC, 0xD9, 0xC0, 0x01, 0x20, 0x00, 0x00, 0x10, 0x01, 0x00,
+    0x1D, 0xC4, 0xC0, 0x03, 0x52, 0xA4, 0x00, 0x02, 0x43, 0x00, 0x09, 0xDB, 0xDD, 0x10, 0x10, 0x00,
+    0xA8, 0x00, 0x47, 0x7A, 0xB0, 0xBC, 0x76, 0xAC, 0x2F, 0x10, 0x07, 0xC2, 0xF2, 0x02, 0xDC, 0x09,
+    0xEC, 0xD9, 0xC0, 0x01, 0x20, 0x00, 0x01, 0xD2, 0x88, 0x00, 0x16, 0x14, 0x40, 0x00, 0x24, 0x30,
+    0x00, 0x9D, 0xBD, 0xD1, 0x01, 0x00, 0x0A, 0x80, 0x04, 0x77, 0xAB, 0x0B, 0xC7, 0x6A, 0xC2, 0xF1,
+    0x00, 0x7C, 0x2F, 0x20, 0x2D, 0xC0, 0x9E, 0xCD, 0x9C, 0x00, 0x12, 0x00, 0x00, 0x00, 0x15, 0x30,
+    0x00, 0x0B, 0x7B, 0x40, 0x00, 0xC0, 0x57, 0x00, 0x07, 0xCC, 0x20, 0x00, 0x24, 0x30, 0x00, 0x9D,
+    0xBD, 0xD1, 0x01, 0x00, 0x0A, 0x80, 0x04, 0x77, 0xAB, 0x0B, 0xC7, 0x6A, 0xC2, 0xF1, 0x00, 0x7C,
+    0x2F, 0x20, 0x2D, 0xC0, 0x9E, 0xCD, 0x9C, 0x00, 0x12, 0x00, 0x00, 0x00, 0x24, 0x30, 0x01, 0x43,
+    0x00, 0x00, 0x9D, 0xBD, 0xD6, 0xEC, 0xCD, 0x20, 0x01, 0x00, 0x0A, 0xF4, 0x00, 0x8B, 0x00, 0x04,
+    0x77, 0xAD, 0x33, 0x35, 0xF0, 0x0B, 0xC7, 0x6A, 0xEB, 0xBB, 0xBB, 0x12, 0xF1, 0x00, 0x7D, 0x00,
+    0x00, 0x00, 0x2F, 0x20, 0x2D, 0xE6, 0x00, 0x03, 0x00, 0x9E, 0xCE, 0x53, 0xDD, 0xCE, 0x90, 0x00,
+    0x12, 0x00, 0x00, 0x22, 0x10, 0x00, 0x00, 0x03, 0x42, 0x00, 0x3D, 0xDB, 0xD5, 0x0D, 0x90, 0x00,
+    0x13, 0xF1, 0x00, 0x00, 0x4E, 0x00, 0x00, 0x02, 0xF1, 0x00, 0x00, 0x0C, 0xA0, 0x01, 0x10, 0x2C,
+    0xEC, 0xE4, 0x00, 0x02, 0xD2, 0x00, 0x02, 0x5B, 0x50, 0x00, 0x17, 0x60, 0x00, 0x00, 0x52, 0x00,
+    0x00, 0x00, 0x4C, 0x10, 0x00, 0x00, 0x06, 0x90, 0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE,
+    0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00,
+    0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31, 0x00, 0x00, 0x00, 0x16,
+    0x10, 0x00, 0x00, 0xA7, 0x00, 0x00, 0x06, 0x90, 0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE,
+    0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00,
+    0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31, 0x00, 0x00, 0x03, 0x40,
+    0x00, 0x00, 0x1C, 0xC3, 0x00, 0x00, 0x85, 0x2B, 0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE,
+    0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00,
+    0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31, 0x00, 0x00, 0xA7, 0x3D,
+    0x00, 0x00, 0x53, 0x26, 0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE, 0x50, 0x0D, 0x70, 0x04,
+    0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00, 0x00, 0x0C, 0xA0, 0x00,
+    0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31, 0x00, 0x16, 0x00, 0x09, 0x80, 0x00, 0xA5, 0x00,
+    0x11, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00,
+    0x35, 0x01, 0xD3, 0x0A, 0x50, 0x01, 0x10, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0C,
+    0x60, 0x0C, 0x60, 0x0C, 0x60, 0x00, 0x52, 0x00, 0x4C, 0xC0, 0x1C, 0x26, 0x80, 0x01, 0x10, 0x00,
+    0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6,
+    0x00, 0x1D, 0x38, 0x90, 0x61, 0x44, 0x00, 0x11, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60,
+    0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0x8A, 0x16, 0x40, 0x01, 0x6F,
+    0xE5, 0x00, 0x05, 0x52, 0xD7, 0x00, 0x01, 0xAE, 0xEF, 0x40, 0x0B, 0xB1, 0x08, 0xD0, 0x2F, 0x20,
+    0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02, 0xF2, 0x0C, 0x80, 0x09, 0xC0, 0x02, 0xCD,
+    0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x10, 0x10, 0x00, 0x9C, 0x7A, 0x30, 0x08, 0x07, 0x80,
+    0x01, 0x11, 0x42, 0x00, 0xC9, 0xDB, 0xF7, 0x0C, 0xC1, 0x05, 0xE0, 0xC6, 0x00, 0x1F, 0x2C, 0x50,
+    0x00, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0xC5, 0x00, 0x0F, 0x20, 0x00, 0x61, 0x00,
+    0x00, 0x00, 0x6B, 0x00, 0x00, 0x00, 0x08, 0x70, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD,
+    0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02,
+    0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0x26,
+    0x00, 0x00, 0x00, 0xC5, 0x00, 0x00, 0x08, 0x70, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD,
+    0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02,
+    0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x04, 0x30,
+    0x00, 0x00, 0x2C, 0xC1, 0x00, 0x00, 0xA3, 0x4A, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD,
+    0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02,
+    0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x01, 0x01,
+    0x00, 0x00, 0xCC, 0x5C, 0x00, 0x01, 0x71, 0x96, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD,
+    0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02,
+    0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0xC5, 0x5B,
+    0x00, 0x00, 0x62, 0x36, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD, 0x30, 0x0D, 0x70, 0x08,
+    0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02, 0xF2, 0x0D, 0x90, 0x09,
+    0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x01, 0x31, 0x00, 0x00, 0x00, 0x5F,
+    0x30, 0x00, 0x00, 0x02, 0x51, 0x00, 0x06, 0x99, 0x99, 0x99, 0x95, 0x47, 0x77, 0x77, 0x77, 0x40,
+    0x00, 0x38, 0x20, 0x00, 0x00, 0x05, 0xF3, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x30,
+    0x43, 0x03, 0xEC, 0xCD, 0xC1, 0x0D, 0x80, 0x1E, 0xD0, 0x3F, 0x10, 0xB6, 0xF2, 0x4E, 0x09, 0x70,
+    0xF3, 0x3F, 0x79, 0x02, 0xF2, 0x0D, 0xD0, 0x09, 0xC0, 0x2C, 0xDD, 0xDC, 0x20, 0x33, 0x02, 0x20,
+    0x00, 0x06, 0x20, 0x00, 0x00, 0x5B, 0x00, 0x00, 0x00, 0x78, 0x00, 0x01, 0x00, 0x00, 0x20, 0xD4,
+    0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x3F,
+    0x1B, 0x90, 0x0A, 0xF1, 0x3E, 0xDD, 0x7F, 0x10, 0x02, 0x10, 0x00, 0x00, 0x01, 0x60, 0x00, 0x00,
+    0xB6, 0x00, 0x00, 0x78, 0x00, 0x01, 0x00, 0x00, 0x20, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1,
+    0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x3F, 0x1B, 0x90, 0x0A, 0xF1, 0x3E, 0xDD,
+    0x7F, 0x10, 0x02, 0x10, 0x00, 0x00, 0x34, 0x00, 0x00, 0x1C, 0xC2, 0x00, 0x0A, 0x43, 0xB0, 0x01,
+    0x00, 0x00, 0x20, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02,
+    0xF1, 0xD4, 0x00, 0x3F, 0x1B, 0x90, 0x0A, 0xF1, 0x3E, 0xDD, 0x7F, 0x10, 0x02, 0x10, 0x00, 0x0B,
+    0x65, 0xC0, 0x00, 0x63, 0x26, 0x00, 0x10, 0x00, 0x02, 0x0D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F,
+    0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x03, 0xF1, 0xB9, 0x00, 0xAF, 0x13, 0xED,
+    0xD7, 0xF1, 0x00, 0x21, 0x00, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x01, 0xD4, 0x00, 0x00, 0x0A,
+    0x50, 0x00, 0x11, 0x00, 0x00, 0x20, 0x5D, 0x00, 0x03, 0xF1, 0x0E, 0x40, 0x09, 0xA0, 0x08, 0xA0,
+    0x1E, 0x40, 0x02, 0xF1, 0x6D, 0x00, 0x00, 0xB7, 0xC7, 0x00, 0x00, 0x5E, 0xF1, 0x00, 0x00, 0x0D,
+    0xA0, 0x00, 0x00, 0x1E, 0x40, 0x00, 0x04, 0x9C, 0x00, 0x00, 0x0A, 0x92, 0x00, 0x00, 0xB5, 0x00,
+    0x00, 0x0C, 0x50, 0x00, 0x00, 0xC5, 0x14, 0x20, 0x0C, 0xAD, 0xBE, 0x70, 0xCD, 0x10, 0x3F, 0x2C,
+    0x70, 0x00, 0xC7, 0xC6, 0x00, 0x0A, 0x8C, 0x70, 0x00, 0xC6, 0xCD, 0x10, 0x4F, 0x2C, 0x9D, 0xCE,
+    0x60, 0xC5, 0x02, 0x10, 0x0C, 0x50, 0x00, 0x00, 0x94, 0x00, 0x00, 0x00, 0x00, 0xD3, 0x79, 0x00,
+    0x00, 0x62, 0x45, 0x00, 0x11, 0x00, 0x00, 0x20, 0x5D, 0x00, 0x03, 0xF1, 0x0E, 0x40, 0x09, 0xA0,
+    0x08, 0xA0, 0x1E, 0x40, 0x02, 0xF1, 0x6D, 0x00, 0x00, 0xB7, 0xC7, 0x00, 0x00, 0x5E, 0xF1, 0x00,
+    0x00, 0x0D, 0xA0, 0x00, 0x00, 0x1E, 0x40, 0x00, 0x04, 0x9C, 0x00, 0x00, 0x0A, 0x92, 0x00, 0x00,
+    0x00, 0x5A, 0xAA, 0x30, 0x00, 0x02, 0x44, 0x41, 0x00, 0x00, 0x03, 0x72, 0x00, 0x00, 0x00, 0xAF,
+    0x80, 0x00, 0x00, 0x1F, 0x7E, 0x00, 0x00, 0x06, 0xC0, 0xE5, 0x00, 0x00, 0xC7, 0x08, 0xA0, 0x00,
+    0x3F, 0x10, 0x3F, 0x10, 0x08, 0xD7, 0x77, 0xE7, 0x00, 0xEA, 0x99, 0x9B, 0xC0, 0x5E, 0x10, 0x00,
+    0x1F, 0x3B, 0x90, 0x00, 0x00, 0xB9, 0x02, 0xAA, 0xA6, 0x00, 0x14, 0x44, 0x20, 0x00, 0x24, 0x30,
+    0x00, 0x9D, 0xBD, 0xD1, 0x01, 0x00, 0x0A, 0x80, 0x04, 0x77, 0xAB, 0x0B, 0xC7, 0x6A, 0xC2, 0xF1,
+    0x00, 0x7C, 0x2F, 0x20, 0x2D, 0xC0, 0x9E, 0xCD, 0x9C, 0x00, 0x12, 0x00, 0x00, 0x00, 0x22, 0x01,
+    0x20, 0x00, 0x03, 0xC6, 0xB5, 0x00, 0x00, 0x04, 0x85, 0x00, 0x00, 0x00, 0x37, 0x20, 0x00, 0x00,
+    0x0A, 0xF8, 0x00, 0x00, 0x01, 0xF7, 0xE0, 0x00, 0x00, 0x6C, 0x0E, 0x50, 0x00, 0x0C, 0x70, 0x8A,
+    0x00, 0x03, 0xF1, 0x03, 0xF1, 0x00, 0x8D, 0x77, 0x7E, 0x70, 0x0E, 0xA9, 0x99, 0xBC, 0x05, 0xE1,
+    0x00, 0x01, 0xF3, 0xB9, 0x00, 0x00, 0x0B, 0x90, 0x02, 0xA1, 0x49, 0x00, 0x07, 0xDB, 0x20, 0x00,
+    0x24, 0x30, 0x00, 0x9D, 0xBD, 0xD1, 0x01, 0x00, 0x0A, 0x80, 0x04, 0x77, 0xAB, 0x0B, 0xC7, 0x6A,
+    0xC2, 0xF1, 0x00, 0x7C, 0x2F, 0x20, 0x2D, 0xC0, 0x9E, 0xCD, 0x9C, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x03, 0x72, 0x00, 0x00, 0x00, 0x0A, 0xF8, 0x00, 0x00, 0x00, 0x1F, 0x7E, 0x00, 0x00, 0x00, 0x6C,
+    0x0E, 0x50, 0x00, 0x00, 0xC7, 0x08, 0xA0, 0x00, 0x03, 0xF1, 0x03, 0xF1, 0x00, 0x08, 0xD7, 0x77,
+    0xE7, 0x00, 0x0E, 0xA9, 0x99, 0xBC, 0x00, 0x5E, 0x10, 0x00, 0x1F, 0x30, 0xB9, 0x00, 0x00, 0x0B,
+    0x90, 0x00, 0x00, 0x00, 0x0B, 0x10, 0x00, 0x00, 0x00, 0x2D, 0x51, 0x00, 0x00, 0x00, 0x05, 0x71,
+    0x00, 0x24, 0x30, 0x00, 0x09, 0xDB, 0xDD, 0x10, 0x01, 0x00, 0x0A, 0x80, 0x00, 0x47, 0x7A, 0xB0,
+    0x0B, 0xC7, 0x6A, 0xC0, 0x2F, 0x10, 0x07, 0xC0, 0x2F, 0x20, 0x2D, 0xC0, 0x09, 0xEC, 0xD9, 0xC0,
+    0x00, 0x12, 0x0A, 0x20, 0x00, 0x00, 0x0E, 0x52, 0x00, 0x00, 0x04, 0x72, 0x00, 0x00, 0x00, 0x10,
+    0x00, 0x00, 0x01, 0xC4, 0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x59, 0x98, 0x30, 0x01, 0xCD,
+    0x86, 0x8E, 0x50, 0x9D, 0x10, 0x00, 0x12, 0x1F, 0x50, 0x00, 0x00, 0x03, 0xF2, 0x00, 0x00, 0x00,
+    0x4F, 0x10, 0x00, 0x00, 0x02, 0xF3, 0x00, 0x00, 0x00, 0x0D, 0x80, 0x00, 0x00, 0x00, 0x5F, 0x60,
+    0x01, 0x75, 0x00, 0x5D, 0xED, 0xFB, 0x20, 0x00, 0x02, 0x21, 0x00, 0x00, 0x00, 0x16, 0x10, 0x00,
+    0x0A, 0x70, 0x00, 0x06, 0x90, 0x00, 0x00, 0x34, 0x20, 0x03, 0xDD, 0xBD, 0x50, 0xD9, 0x00, 0x01,
+    0x3F, 0x10, 0x00, 0x04, 0xE0, 0x00, 0x00, 0x2F, 0x10, 0x00, 0x00, 0xCA, 0x00, 0x11, 0x02, 0xCE,
+    0xCE, 0x40, 0x00, 0x22, 0x10, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0A, 0xC6, 0x00, 0x00, 0x03,
+    0x60, 0x81, 0x00, 0x00, 0x59, 0x98, 0x30, 0x01, 0xCD, 0x86, 0x8E, 0x50, 0x9D, 0x10, 0x00, 0x12,
+    0x1F, 0x50, 0x00, 0x00, 0x03, 0xF2, 0x00, 0x00, 0x00, 0x4F, 0x10, 0x00, 0x00, 0x02, 0xF3, 0x00,
+    0x00, 0x00, 0x0D, 0x80, 0x00, 0x00, 0x00, 0x5F, 0x60, 0x01, 0x75, 0x00, 0x5D, 0xED, 0xFB, 0x20,
+    0x00, 0x02, 0x21, 0x00, 0x00, 0x02, 0x50, 0x00, 0x00, 0xBC, 0x50, 0x00, 0x68, 0x1C, 0x10, 0x00,
+    0x34, 0x20, 0x03, 0xDD, 0xBD, 0x50, 0xD9, 0x00, 0x01, 0x3F, 0x10, 0x00, 0x04, 0xE0, 0x00, 0x00,
+    0x2F, 0x10, 0x00, 0x00, 0xCA, 0x00, 0x11, 0x02, 0xCE, 0xCE, 0x40, 0x00, 0x22, 0x10, 0x00, 0x00,
+    0x4D, 0x00, 0x00, 0x00, 0x02, 0x60, 0x00, 0x00, 0x05, 0x99, 0x83, 0x00, 0x1C, 0xD8, 0x68, 0xE5,
+    0x09, 0xD1, 0x00, 0x01, 0x21, 0xF5, 0x00, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x04, 0xF1, 0x00,
+    0x00, 0x00, 0x2F, 0x30, 0x00, 0x00, 0x00, 0xD8, 0x00, 0x00, 0x00, 0x05, 0xF6, 0x00, 0x17, 0x50,
+    0x05, 0xDE, 0xDF, 0xB2, 0x00, 0x00, 0x22, 0x10, 0x00, 0x00, 0x04, 0xB0, 0x00, 0x00, 0x38, 0x00,
+    0x00, 0x03, 0x42, 0x00, 0x3D, 0xDB, 0xD5, 0x0D, 0x90, 0x00, 0x13, 0xF1, 0x00, 0x00, 0x4E, 0x00,
+    0x00, 0x02, 0xF1, 0x00, 0x00, 0x0C, 0xA0, 0x01, 0x10, 0x2C, 0xEC, 0xE4, 0x00, 0x02, 0x21, 0x00,
+    0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x59, 0x5A, 0x00, 0x00, 0x00, 0x69, 0x10, 0x00, 0x00, 0x59,
+    0x98, 0x30, 0x01, 0xCD, 0x86, 0x8E, 0x50, 0x9D, 0x10, 0x00, 0x12, 0x1F, 0x50, 0x00, 0x00, 0x03,
+    0xF2, 0x00, 0x00, 0x00, 0x4F, 0x10, 0x00, 0x00, 0x02, 0xF3, 0x00, 0x00, 0x00, 0x0D, 0x80, 0x00,
+    0x00, 0x00, 0x5F, 0x60, 0x01, 0x75, 0x00, 0x5D, 0xED, 0xFB, 0x20, 0x00, 0x02, 0x21, 0x00, 0x00,
+    0x51, 0x05, 0x00, 0x05, 0xA6, 0x80, 0x00, 0x0A, 0xD1, 0x00, 0x00, 0x34, 0x20, 0x03, 0xDD, 0xBD,
+    0x50, 0xD9, 0x00, 0x01, 0x3F, 0x10, 0x00, 0x04, 0xE0, 0x00, 0x00, 0x2F, 0x10, 0x00, 0x00, 0xCA,
+    0x00, 0x11, 0x02, 0xCE, 0xCE, 0x40, 0x00, 0x22, 0x10, 0x00, 0x10, 0x01, 0x00, 0x00, 0x0A, 0x4A,
+    0x40, 0x00, 0x00, 0x19, 0x60, 0x00, 0x05, 0x77, 0x75, 0x20, 0x00, 0xBC, 0x99, 0xBF, 0xA1, 0x0B,
+    0x80, 0x00, 0x2D, 0x90, 0xB8, 0x00, 0x00, 0x6F, 0x1B, 0x80, 0x00, 0x02, 0xF3, 0xB8, 0x00, 0x00,
+    0x2F, 0x3B, 0x80, 0x00, 0x04, 0xF2, 0xB8, 0x00, 0x00, 0x9D, 0x0B, 0x91, 0x13, 0x9F, 0x40, 0xBF,
+    0xFF, 0xDA, 0x30, 0x00, 0x00, 0x00, 0x01, 0xD1, 0xB5, 0x00, 0x00, 0x01, 0xF1, 0xF1, 0x00, 0x14,
+    0x21, 0xF2, 0x70, 0x04, 0xEC, 0xC9, 0xF1, 0x00, 0x0D, 0x70, 0x09, 0xF1, 0x00, 0x3F, 0x10, 0x03,
+    0xF1, 0x00, 0x4E, 0x00, 0x02, 0xF1, 0x00, 0x3F, 0x10, 0x03, 0xF1, 0x00, 0x0D, 0x80, 0x0A, 0xF1,
+    0x00, 0x03, 0xDD, 0xD8, 0xF1, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00, 0x05, 0x77, 0x75, 0x20, 0x00,
+    0x0A, 0xD9, 0x9B, 0xFA, 0x10, 0x0A, 0x90, 0x00, 0x2D, 0xA0, 0x0A, 0x90, 0x00, 0x05, 0xF1, 0x5C,
+    0xB5, 0x40, 0x01, 0xF4, 0x8D, 0xD8, 0x60, 0x01, 0xF4, 0x0A, 0x90, 0x00, 0x03, 0xF3, 0x0A, 0x90,
+    0x00, 0x08, 0xE0, 0x0A, 0xA1, 0x13, 0x9F, 0x50, 0x0A, 0xFF, 0xFD, 0xA3, 0x00, 0x00, 0x00, 0x01,
+    0xD1, 0x00, 0x00, 0x2C, 0xCF, 0xC1, 0x00, 0x14, 0x21, 0xF1, 0x00, 0x4E, 0xCC, 0x9F, 0x10, 0x0D,
+    0x70, 0x09, 0xF1, 0x03, 0xF1, 0x00, 0x3F, 0x10, 0x4E, 0x00, 0x02, 0xF1, 0x03, 0xF1, 0x00, 0x3F,
+    0x10, 0x0D, 0x80, 0x0A, 0xF1, 0x00, 0x3D, 0xDD, 0x8F, 0x10, 0x00, 0x02, 0x10, 0x00, 0x00, 0x07,
+    0xAA, 0xA2, 0x00, 0x24, 0x44, 0x10, 0x57, 0x77, 0x77, 0x2B, 0xC9, 0x99, 0x92, 0xB8, 0x00, 0x00,
+    0x0B, 0x80, 0x00, 0x00, 0xBC, 0x99, 0x99, 0x1B, 0xC7, 0x77, 0x70, 0xB8, 0x00, 0x00, 0x0B, 0x80,
+    0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF6, 0x00, 0x7A, 0xAA, 0x10, 0x00, 0x34, 0x44,
+    0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE, 0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33,
+    0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00, 0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE,
+    0xC0, 0x00, 0x01, 0x31, 0x00, 0x01, 0x00, 0x00, 0x00, 0x89, 0x6C, 0x20, 0x00, 0x57, 0x30, 0x05,
+    0x77, 0x77, 0x72, 0xBC, 0x99, 0x99, 0x2B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0xC9, 0x99,
+    0x91, 0xBC, 0x77, 0x77, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x91, 0x11, 0x10, 0xBF,
+    0xFF, 0xFF, 0x60, 0x00, 0x20, 0x02, 0x10, 0x00, 0x97, 0x5D, 0x10, 0x00, 0x18, 0x93, 0x00, 0x00,
+    0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE, 0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F,
+    0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00, 0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00,
+    0x01, 0x31, 0x00, 0x00, 0x5C, 0x00, 0x00, 0x02, 0x60, 0x00, 0x57, 0x77, 0x77, 0x2B, 0xC9, 0x99,
+    0x92, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xBC, 0x99, 0x99, 0x1B, 0xC7, 0x77, 0x70, 0xB8,
+    0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF6, 0x00, 0x05, 0xA0,
+    0x00, 0x00, 0x04, 0x70, 0x00, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE, 0x50, 0x0D, 0x70, 0x04,
+    0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00, 0x00, 0x0C, 0xA0, 0x00,
+    0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31, 0x00, 0x57, 0x77, 0x77, 0x2B, 0xC9, 0x99, 0x92,
+    0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xBC, 0x99, 0x99, 0x1B, 0xC7, 0x77, 0x70, 0xB8, 0x00,
+    0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF6, 0x00, 0x00, 0xA2, 0x00,
+    0x00, 0x0E, 0x52, 0x00, 0x00, 0x47, 0x20, 0x00, 0x03, 0x41, 0x00, 0x03, 0xDC, 0xBE, 0x50, 0x0D,
+    0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB, 0xB3, 0x3F, 0x10, 0x00, 0x00, 0x0C,
+    0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x6A, 0x00, 0x00, 0x00, 0x79, 0x40, 0x00,
+    0x00, 0x17, 0x60, 0x00, 0x00, 0x00, 0x00, 0x3A, 0x3B, 0x00, 0x00, 0x5A, 0x20, 0x05, 0x77, 0x77,
+    0x72, 0xBC, 0x99, 0x99, 0x2B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0xC9, 0x99, 0x91, 0xBC,
+    0x77, 0x77, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x91, 0x11, 0x10, 0xBF, 0xFF, 0xFF,
+    0x60, 0x00, 0x41, 0x04, 0x10, 0x00, 0x4A, 0x5A, 0x00, 0x00, 0x09, 0xD1, 0x00, 0x00, 0x03, 0x41,
+    0x00, 0x03, 0xDC, 0xBE, 0x50, 0x0D, 0x70, 0x04, 0xE1, 0x3F, 0x33, 0x33, 0xE4, 0x4F, 0xBB, 0xBB,
+    0xB3, 0x3F, 0x10, 0x00, 0x00, 0x0C, 0xA0, 0x00, 0x30, 0x02, 0xBE, 0xCE, 0xC0, 0x00, 0x01, 0x31,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x9B, 0x80, 0x00, 0x00, 0x02, 0x60, 0x71, 0x00,
+    0x00, 0x05, 0x9A, 0x85, 0x00, 0x01, 0xBD, 0x86, 0x8C, 0xB0, 0x09, 0xC1, 0x00, 0x00, 0x40, 0x1F,
+    0x50, 0x00, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x00, 0x4F, 0x10, 0x05, 0xEE, 0xE0, 0x2F, 0x30,
+    0x01, 0x25, 0xF0, 0x0D, 0x80, 0x00, 0x04, 0xF0, 0x05, 0xF6, 0x00, 0x06, 0xF0, 0x00, 0x5D, 0xFD,
+    0xED, 0x60, 0x00, 0x00, 0x23, 0x10, 0x00, 0x00, 0x04, 0x30, 0x00, 0x00, 0x3C, 0xC1, 0x00, 0x00,
+    0xB2, 0x59, 0x00, 0x00, 0x14, 0x20, 0x20, 0x04, 0xEB, 0xC9, 0xF1, 0x0E, 0x60, 0x09, 0xF1, 0x3F,
+    0x00, 0x03, 0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x2F, 0x10, 0x04, 0xF1, 0x0C, 0xA1, 0x1B, 0xF1, 0x02,
+    0xBF, 0xD6, 0xF1, 0x00, 0x00, 0x05, 0xE0, 0x04, 0x74, 0x6E, 0x70, 0x03, 0x9A, 0xA5, 0x00, 0x00,
+    0x01, 0x00, 0x10, 0x00, 0x00, 0x0B, 0x77, 0xC0, 0x00, 0x00, 0x01, 0x66, 0x10, 0x00, 0x00, 0x05,
+    0x9A, 0x85, 0x00, 0x01, 0xBD, 0x86, 0x8C, 0xB0, 0x09, 0xC1, 0x00, 0x00, 0x40, 0x1F, 0x50, 0x00,
+    0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x00, 0x4F, 0x10, 0x05, 0xEE, 0xE0, 0x2F, 0x30, 0x01, 0x25,
+    0xF0, 0x0D, 0x80, 0x00, 0x04, 0xF0, 0x05, 0xF6, 0x00, 0x06, 0xF0, 0x00, 0x5D, 0xFD, 0xED, 0x60,
+    0x00, 0x00, 0x23, 0x10, 0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0xA6, 0x5D, 0x00, 0x00, 0x18, 0x93,
+    0x00, 0x00, 0x14, 0x20, 0x20, 0x04, 0xEB, 0xC9, 0xF1, 0x0E, 0x60, 0x09, 0xF1, 0x3F, 0x00, 0x03,
+    0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x2F, 0x10, 0x04, 0xF1, 0x0C, 0xA1, 0x1B, 0xF1, 0x02, 0xBF, 0xD6,
+    0xF1, 0x00, 0x00, 0x05, 0xE0, 0x04, 0x74, 0x6E, 0x70, 0x03, 0x9A, 0xA5, 0x00, 0x00, 0x00, 0x2D,
+    0x10, 0x00, 0x00, 0x00, 0x16, 0x10, 0x00, 0x00, 0x05, 0x9A, 0x85, 0x00, 0x01, 0xBD, 0x86, 0x8C,
+    0xB0, 0x09, 0xC1, 0x00, 0x00, 0x40, 0x1F, 0x50, 0x00, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x00,
+    0x4F, 0x10, 0x05, 0xEE, 0xE0, 0x2F, 0x30, 0x01, 0x25, 0xF0, 0x0D, 0x80, 0x00, 0x04, 0xF0, 0x05,
+    0xF6, 0x00, 0x06, 0xF0, 0x00, 0x5D, 0xFD, 0xED, 0x60, 0x00, 0x00, 0x23, 0x10, 0x00, 0x00, 0x09,
+    0x60, 0x00, 0x00, 0x06, 0x40, 0x00, 0x00, 0x14, 0x20, 0x20, 0x04, 0xEB, 0xC9, 0xF1, 0x0E, 0x60,
+    0x09, 0xF1, 0x3F, 0x00, 0x03, 0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x2F, 0x10, 0x04, 0xF1, 0x0C, 0xA1,
+    0x1B, 0xF1, 0x02, 0xBF, 0xD6, 0xF1, 0x00, 0x00, 0x05, 0xE0, 0x04, 0x74, 0x6E, 0x70, 0x03, 0x9A,
+    0xA5, 0x00, 0x00, 0x05, 0x9A, 0x85, 0x00, 0x01, 0xBD, 0x86, 0x8C, 0xB0, 0x09, 0xC1, 0x00, 0x00,
+    0x40, 0x1F, 0x50, 0x00, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x00, 0x00, 0x4F, 0x10, 0x05, 0xEE, 0xE0,
+    0x2F, 0x30, 0x01, 0x25, 0xF0, 0x0D, 0x80, 0x00, 0x04, 0xF0, 0x05, 0xF6, 0x00, 0x06, 0xF0, 0x00,
+    0x5D, 0xFD, 0xED, 0x60, 0x00, 0x00, 0x23, 0x10, 0x00, 0x00, 0x00, 0x1C, 0x20, 0x00, 0x00, 0x00,
+    0x4B, 0x00, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x01, 0xD0, 0x00,
+    0x00, 0x07, 0xA0, 0x00, 0x00, 0x14, 0x20, 0x20, 0x04, 0xEB, 0xC9, 0xF1, 0x0E, 0x60, 0x09, 0xF1,
+    0x3F, 0x00, 0x03, 0xF1, 0x4E, 0x00, 0x02, 0xF1, 0x2F, 0x10, 0x04, 0xF1, 0x0C, 0xA1, 0x1B, 0xF1,
+    0x02, 0xBF, 0xD6, 0xF1, 0x00, 0x00, 0x05, 0xE0, 0x04, 0x74, 0x6E, 0x70, 0x03, 0x9A, 0xA5, 0x00,
+    0x00, 0x01, 0x00, 0x00, 0x00, 0x3C, 0xC1, 0x00, 0x00, 0x71, 0x35, 0x00, 0x54, 0x00, 0x00, 0x64,
+    0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xBC, 0x99, 0x99, 0xE7,
+    0xBC, 0x77, 0x77, 0xD7, 0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7, 0xB8, 0x00, 0x00, 0xC7,
+    0xB8, 0x00, 0x00, 0xC7, 0x00, 0x10, 0x00, 0x00, 0x00, 0x4C, 0xB0, 0x00, 0x00, 0x08, 0x14, 0x40,
+    0x00, 0x00, 0x0B, 0x50, 0x00, 0x00, 0x00, 0xC5, 0x00, 0x00, 0x00, 0x0C, 0x51, 0x42, 0x00, 0x00,
+    0xC9, 0xDB, 0xF7, 0x00, 0x0C, 0xC1, 0x05, 0xE0, 0x00, 0xC6, 0x00, 0x1F, 0x20, 0x0C, 0x50, 0x00,
+    0xF2, 0x00, 0xC5, 0x00, 0x0F, 0x20, 0x0C, 0x50, 0x00, 0xF2, 0x00, 0xC5, 0x00, 0x0F, 0x20, 0x05,
+    0x40, 0x00, 0x05, 0x40, 0x1A, 0xA1, 0x11, 0x1B, 0x90, 0xBF, 0xFF, 0xFF, 0xFF, 0xF9, 0x0A, 0x90,
+    0x00, 0x0B, 0x80, 0x0A, 0xD9, 0x99, 0x9D, 0x80, 0x0A, 0xC7, 0x77, 0x7D, 0x80, 0x0A, 0x90, 0x00,
+    0x0B, 0x80, 0x0A, 0x90, 0x00, 0x0B, 0x80, 0x0A, 0x90, 0x00, 0x0B, 0x80, 0x0A, 0x90, 0x00, 0x0B,
+    0x80, 0x07, 0xA2, 0x10, 0x00, 0x3F, 0xFE, 0xE0, 0x00, 0x07, 0xB0, 0x33, 0x00, 0x07, 0xCB, 0xCD,
+    0xC1, 0x07, 0xF3, 0x01, 0xD5, 0x07, 0xC0, 0x00, 0xA7, 0x07, 0xB0, 0x00, 0xA8, 0x07, 0xB0, 0x00,
+    0xA8, 0x07, 0xB0, 0x00, 0xA8, 0x07, 0xB0, 0x00, 0xA8, 0x0B, 0xC7, 0xC0, 0x14, 0x15, 0x30, 0x00,
+    0x54, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8,
+    0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x00, 0x11,
+    0xCC, 0x4C, 0x36, 0x29, 0x40, 0x01, 0x10, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C,
+    0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0xAA, 0xA8, 0x44, 0x43, 0x05, 0x40, 0x0B,
+    0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B, 0x80, 0x0B,
+    0x80, 0x1A, 0xAA, 0x70, 0x44, 0x43, 0x00, 0x11, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60,
+    0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x01, 0x00, 0x10, 0xC6, 0x7B, 0x02,
+    0x66, 0x10, 0x05, 0x40, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8,
+    0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x02, 0x00, 0x21, 0xC5, 0x79,
+    0x03, 0x98, 0x10, 0x01, 0x10, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00,
+    0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x54, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80,
+    0xB8, 0x0B, 0x80, 0xB8, 0x0B, 0x80, 0x84, 0x0D, 0x53, 0x37, 0x30, 0x0A, 0x50, 0x07, 0x40, 0x01,
+    0x10, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0C, 0x60, 0x0A,
+    0x20, 0x0E, 0x52, 0x04, 0x72, 0xA8, 0x54, 0x54, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8, 0xB8,
+    0xB8, 0x11, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0x54, 0x00, 0x63, 0xB8, 0x00, 0xD6, 0xB8,
+    0x00, 0xD6, 0xB8, 0x00, 0xD6, 0xB8, 0x00, 0xD6, 0xB8, 0x00, 0xD6, 0xB8, 0x00, 0xD6, 0xB8, 0x00,
+    0xD6, 0xB8, 0x00, 0xD6, 0xB8, 0x00, 0xE6, 0x00, 0x01, 0xF4, 0x00, 0x6B, 0xD1, 0x00, 0x87, 0x20,
+    0xA5, 0x02, 0xD7, 0x40, 0x29, 0x11, 0x00, 0x2C, 0x60, 0x3F, 0xC6, 0x03, 0xFC, 0x60, 0x3F, 0xC6,
+    0x03, 0xFC, 0x60, 0x3F, 0xC6, 0x03, 0xFC, 0x60, 0x3F, 0x00, 0x03, 0xF0, 0x03, 0x9C, 0x00, 0x79,
+    0x20, 0x00, 0x11, 0x00, 0x3C, 0xC1, 0x07, 0x23, 0x60, 0x05, 0x40, 0x00, 0xB8, 0x00, 0x0B, 0x80,
+    0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00, 0xB8, 0x00, 0x0B, 0x80, 0x00,
+    0xB8, 0x00, 0x0D, 0x70, 0x5A, 0xE2, 0x06, 0x83, 0x00, 0x00, 0x52, 0x00, 0x4C, 0xC0, 0x1C, 0x26,
+    0x80, 0x01, 0x10, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00,
+    0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x15, 0xE3, 0x02, 0xA5, 0x00, 0x54, 0x00, 0x03, 0x71,
+    0xB8, 0x00, 0x4E, 0x60, 0xB8, 0x05, 0xE6, 0x00, 0xB8, 0x5E, 0x50, 0x00, 0xBD, 0xE4, 0x00, 0x00,
+    0xBD, 0xF5, 0x00, 0x00, 0xB8, 0x6F, 0x50, 0x00, 0xB8, 0x06, 0xF5, 0x00, 0xB8, 0x00, 0x6F, 0x50,
+    0xB8, 0x00, 0x06, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xA0, 0x00, 0x00, 0x0B, 0x30, 0x00,
+    0x00, 0x01, 0x00, 0x00, 0xB5, 0x00, 0x00, 0x0C, 0x50, 0x00, 0x00, 0xC5, 0x00, 0x02, 0x0C, 0x50,
+    0x1C, 0x90, 0xC5, 0x2D, 0x80, 0x0C, 0x8D, 0x60, 0x00, 0xCD, 0xD1, 0x00, 0x0C, 0x59, 0xC1, 0x00,
+    0xC5, 0x09, 0xD1, 0x0C, 0x50, 0x08, 0xD2, 0x00, 0x00, 0x00, 0x00, 0x03, 0xE1, 0x00, 0x00, 0x77,
+    0x00, 0x00, 0x01, 0x00, 0x00, 0x11, 0x00, 0x02, 0x0C, 0x50, 0x1C, 0x90, 0xC5, 0x2D, 0x80, 0x0C,
+    0x8D, 0x60, 0x00, 0xCD, 0xD1, 0x00, 0x0C, 0x69, 0xC1, 0x00, 0xC5, 0x09, 0xC1, 0x0C, 0x50, 0x08,
+    0xD2, 0x00, 0x10, 0x00, 0x00, 0xB5, 0x00, 0x00, 0x36, 0x00, 0x00, 0x05, 0x40, 0x00, 0x00, 0xB8,
+    0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00,
+    0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x91, 0x11, 0x10, 0xBF, 0xFF, 0xFF, 0x30, 0x00,
+    0x11, 0xC4, 0x45, 0x0A, 0x50, 0xC6, 0x0C, 0x60, 0xC6, 0x0C, 0x60, 0xC6, 0x0C, 0x60, 0xC6, 0x0C,
+    0x60, 0xC6, 0x00, 0x54, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00,
+    0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9,
+    0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x05, 0xD0, 0x00, 0x00, 0x96, 0x00,
+    0x00, 0x01, 0x00, 0x00, 0x0A, 0x50, 0xC6, 0x0C, 0x60, 0xC6, 0x0C, 0x60, 0xC6, 0x0C, 0x60, 0xC6,
+    0x0C, 0x60, 0xC6, 0x00, 0x00, 0xB7, 0x0D, 0x10, 0x10, 0x54, 0x06, 0x30, 0x0B, 0x80, 0xE3, 0x00,
+    0xB8, 0x1C, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00,
+    0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B, 0xFF, 0xFF, 0xF3, 0xA5, 0x6A, 0xC6, 0xA7,
+    0xC6, 0x62, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00,
+    0x54, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x01, 0x00, 0xB8, 0x01,
+    0xF4, 0x0B, 0x80, 0x08, 0x20, 0xB8, 0x00, 0x00, 0x0B, 0x80, 0x00, 0x00, 0xB9, 0x11, 0x11, 0x0B,
+    0xFF, 0xFF, 0xF3, 0xA5, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x10, 0xCA, 0xF1, 0xC8, 0x81, 0xC6,
+    0x00, 0xC6, 0x00, 0xC6, 0x00, 0xC6, 0x00, 0x00, 0x54, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00,
+    0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x93, 0xB0, 0x00, 0x00, 0xAD, 0xC3, 0x00, 0x00, 0x2D, 0xB1,
+    0x00, 0x00, 0x0B, 0xC9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xAA, 0x11, 0x11, 0x00,
+    0x0A, 0xFF, 0xFF, 0xF4, 0x0A, 0x60, 0x0B, 0x60, 0x0B, 0x60, 0x0B, 0x94, 0x0C, 0xD3, 0xAE, 0x60,
+    0x2B, 0x60, 0x0B, 0x60, 0x0B, 0x60, 0x0B, 0x60, 0x00, 0x00, 0x10, 0x00, 0x00, 0x08, 0x80, 0x00,
+    0x00, 0x28, 0x00, 0x00, 0x57, 0x10, 0x00, 0x63, 0xBF, 0x80, 0x00, 0xC7, 0xBC, 0xE1, 0x00, 0xC7,
+    0xB8, 0xB9, 0x00, 0xC7, 0xB8, 0x3F, 0x20, 0xC7, 0xB8, 0x0A, 0xA0, 0xC7, 0xB8, 0x02, 0xF3, 0xC7,
+    0xB8, 0x00, 0x9B, 0xC7, 0xB8, 0x00, 0x2E, 0xE7, 0xB8, 0x00, 0x08, 0xF7, 0x00, 0x04, 0x40, 0x00,
+    0x03, 0xD2, 0x00, 0x00, 0xB3, 0x00, 0x01, 0x11, 0x42, 0x00, 0xC9, 0xDB, 0xF7, 0x0C, 0xC1, 0x05,
+    0xE0, 0xC6, 0x00, 0x1F, 0x2C, 0x50, 0x00, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0xC5,
+    0x00, 0x0F, 0x20, 0x57, 0x10, 0x00, 0x63, 0xBF, 0x80, 0x00, 0xC7, 0xBC, 0xE1, 0x00, 0xC7, 0xB8,
+    0xB9, 0x00, 0xC7, 0xB8, 0x3F, 0x20, 0xC7, 0xB8, 0x0A, 0xA0, 0xC7, 0xB8, 0x02, 0xF3, 0xC7, 0xB8,
+    0x00, 0x9B, 0xC7, 0xB8, 0x00, 0x2E, 0xE7, 0xB8, 0x00, 0x08, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0A, 0x80, 0x00, 0x00, 0x0D, 0x10, 0x00, 0x00, 0x01, 0x00, 0x00, 0x11, 0x14, 0x20, 0x0C, 0x9D,
+    0xBF, 0x70, 0xCC, 0x10, 0x5E, 0x0C, 0x60, 0x01, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2,
+    0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x06, 0xC0, 0x00, 0x00, 0xA5,
+    0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xA4, 0x95, 0x00, 0x00, 0x1A, 0x70, 0x00, 0x57, 0x10, 0x00,
+    0x63, 0xBF, 0x80, 0x00, 0xC7, 0xBC, 0xE1, 0x00, 0xC7, 0xB8, 0xB9, 0x00, 0xC7, 0xB8, 0x3F, 0x20,
+    0xC7, 0xB8, 0x0A, 0xA0, 0xC7, 0xB8, 0x02, 0xF3, 0xC7, 0xB8, 0x00, 0x9B, 0xC7, 0xB8, 0x00, 0x2E,
+    0xE7, 0xB8, 0x00, 0x08, 0xF7, 0x04, 0x10, 0x50, 0x00, 0x4A, 0x69, 0x00, 0x00, 0xAD, 0x10, 0x01,
+    0x11, 0x42, 0x00, 0xC9, 0xDB, 0xF7, 0x0C, 0xC1, 0x05, 0xE0, 0xC6, 0x00, 0x1F, 0x2C, 0x50, 0x00,
+    0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0xC5, 0x00, 0x0F, 0x20, 0x17, 0x10, 0x00, 0x00,
+    0x03, 0xF2, 0x00, 0x00, 0x00, 0x7A, 0x11, 0x14, 0x30, 0x05, 0x2A, 0xAD, 0xBE, 0x90, 0x00, 0xAD,
+    0x10, 0x2F, 0x20, 0x0A, 0x90, 0x00, 0xD4, 0x00, 0xA8, 0x00, 0x0D, 0x40, 0x0A, 0x80, 0x00, 0xD4,
+    0x00, 0xA8, 0x00, 0x0D, 0x40, 0x0A, 0x80, 0x00, 0xD4, 0x54, 0x28, 0x96, 0x00, 0xBA, 0xDA, 0xAF,
+    0x90, 0xBF, 0x30, 0x06, 0xF1, 0xBB, 0x00, 0x01, 0xF3, 0xB9, 0x00, 0x00, 0xF4, 0xB8, 0x00, 0x00,
+    0xF4, 0xB8, 0x00, 0x00, 0xF4, 0xB8, 0x00, 0x00, 0xF4, 0xB8, 0x00, 0x00, 0xF4, 0xB8, 0x00, 0x00,
+    0xF4, 0x00, 0x00, 0x00, 0xF4, 0x00, 0x00, 0x27, 0xF1, 0x00, 0x00, 0x5A, 0x40, 0x11, 0x14, 0x20,
+    0x0C, 0x9D, 0xBF, 0x70, 0xCC, 0x10, 0x5E, 0x0C, 0x60, 0x01, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50,
+    0x00, 0xF2, 0xC5, 0x00, 0x0F, 0x2C, 0x50, 0x00, 0xF2, 0x00, 0x00, 0x1F, 0x20, 0x03, 0x48, 0xE0,
+    0x00, 0x7B, 0xA3, 0x00, 0x00, 0x08, 0xAA, 0xA0, 0x00, 0x00, 0x03, 0x44, 0x40, 0x00, 0x00, 0x05,
+    0x99, 0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00,
+    0x02, 0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00,
+    0xE6, 0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00,
+    0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x9A, 0xA9, 0x00, 0x00, 0x44, 0x43, 0x00, 0x00, 0x03, 0x30,
+    0x00, 0x03, 0xEC, 0xCD, 0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00,
+    0xF3, 0x3F, 0x10, 0x02, 0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20,
+    0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x0A, 0x76, 0xC0, 0x00, 0x00, 0x01, 0x66, 0x20, 0x00,
+    0x00, 0x05, 0x99, 0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE, 0x30, 0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F,
+    0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7, 0x4F, 0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30,
+    0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05, 0xF6, 0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE,
+    0xE8, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0xC5, 0x6C, 0x00, 0x00,
+    0x28, 0x82, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD, 0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F,
+    0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02, 0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03,
+    0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0xC4, 0xB6,
+    0x00, 0x00, 0x04, 0x53, 0x70, 0x00, 0x00, 0x05, 0x99, 0x71, 0x00, 0x01, 0xCD, 0x77, 0xBE, 0x30,
+    0x09, 0xD1, 0x00, 0x0A, 0xD0, 0x1F, 0x50, 0x00, 0x02, 0xF4, 0x3F, 0x20, 0x00, 0x00, 0xE7, 0x4F,
+    0x10, 0x00, 0x00, 0xD7, 0x2F, 0x30, 0x00, 0x00, 0xE6, 0x0D, 0x80, 0x00, 0x05, 0xF2, 0x05, 0xF6,
+    0x00, 0x3E, 0x80, 0x00, 0x5D, 0xEE, 0xE8, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x02, 0x41,
+    0x50, 0x00, 0x0B, 0x49, 0x60, 0x00, 0x3A, 0x3B, 0x00, 0x00, 0x03, 0x30, 0x00, 0x03, 0xEC, 0xCD,
+    0x30, 0x0D, 0x70, 0x08, 0xD0, 0x3F, 0x10, 0x02, 0xF2, 0x4E, 0x00, 0x00, 0xF3, 0x3F, 0x10, 0x02,
+    0xF2, 0x0D, 0x90, 0x09, 0xC0, 0x03, 0xDD, 0xDC, 0x20, 0x00, 0x02, 0x20, 0x00, 0x00, 0x01, 0x57,
+    0x77, 0x77, 0x77, 0x70, 0x00, 0x8E, 0xCA, 0x9F, 0xB9, 0x99, 0x90, 0x08, 0xE4, 0x00, 0x0F, 0x40,
+    0x00, 0x00, 0x1E, 0x60, 0x00, 0x0F, 0x40, 0x00, 0x00, 0x3F, 0x20, 0x00, 0x0F, 0xA9, 0x99, 0x70,
+    0x4F, 0x10, 0x00, 0x0F, 0x97, 0x77, 0x60, 0x2F, 0x40, 0x00, 0x0F, 0x40, 0x00, 0x00, 0x0C, 0xA0,
+    0x00, 0x0F, 0x40, 0x00, 0x00, 0x03, 0xEB, 0x42, 0x2F, 0x51, 0x11, 0x10, 0x00, 0x28, 0xCE, 0xFF,
+    0xFF, 0xFF, 0xF1, 0x00, 0x03, 0x30, 0x00, 0x24, 0x20, 0x00, 0x3E, 0xCC, 0xD3, 0xAE, 0xBE, 0x90,
+    0x0D, 0x70, 0x08, 0xFB, 0x00, 0x1D, 0x43, 0xF1, 0x00, 0x2F, 0x73, 0x33, 0xA8, 0x4E, 0x00, 0x00,
+    0xFC, 0xBB, 0xBB, 0x73, 0xF1, 0x00, 0x2F, 0x60, 0x00, 0x00, 0x0D, 0x90, 0x09, 0xFD, 0x20, 0x02,
+    0x20, 0x3D, 0xDD, 0xC2, 0x9E, 0xDD, 0xE4, 0x00, 0x02, 0x20, 0x00, 0x13, 0x20, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x1C, 0x40, 0x00, 0x00, 0x45, 0x00, 0x00, 0x57, 0x77, 0x50, 0x00, 0xBC, 0x99,
+    0xEC, 0x00, 0xB8, 0x00, 0x2F, 0x40, 0xB8, 0x00, 0x0F, 0x60, 0xB9, 0x11, 0x8E, 0x20, 0xBF, 0xFF,
+    0xF4, 0x00, 0xB8, 0x01, 0xAC, 0x00, 0xB8, 0x00, 0x1E, 0x60, 0xB8, 0x00, 0x07, 0xD0, 0xB8, 0x00,
+    0x01, 0xE6, 0x00, 0x04, 0x40, 0x03, 0xD2, 0x00, 0xB3, 0x01, 0x11, 0x41, 0xC9, 0xDC, 0x5C, 0xC1,
+    0x00, 0xC7, 0x00, 0x0C, 0x50, 0x00, 0xC5, 0x00, 0x0C, 0x50, 0x00, 0xC5, 0x00, 0x00, 0x57, 0x77,
+    0x50, 0x00, 0xBC, 0x99, 0xEC, 0x00, 0xB8, 0x00, 0x2F, 0x40, 0xB8, 0x00, 0x0F, 0x60, 0xB9, 0x11,
+    0x8E, 0x20, 0xBF, 0xFF, 0xF4, 0x00, 0xB8, 0x01, 0xAC, 0x00, 0xB8, 0x00, 0x1E, 0x60, 0xB8, 0x00,
+    0x07, 0xD0, 0xB8, 0x00, 0x01, 0xE6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x90, 0x00, 0x00, 0x0C,
+    0x20, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x11, 0x41, 0x0C, 0x9D, 0xC5, 0x0C, 0xC1, 0x00, 0x0C,
+    0x70, 0x00, 0x0C, 0x50, 0x00, 0x0C, 0x50, 0x00, 0x0C, 0x50, 0x00, 0x0C, 0x50, 0x00, 0x00, 0x00,
+    0x00, 0x0B, 0x60, 0x00, 0x1D, 0x00, 0x00, 0x01, 0x00, 0x00, 0x07, 0x75, 0x90, 0x00, 0x00, 0x99,
+    0x00, 0x00, 0x57, 0x77, 0x50, 0x00, 0xBC, 0x99, 0xEC, 0x00, 0xB8, 0x00, 0x2F, 0x40, 0xB8, 0x00,
+    0x0F, 0x60, 0xB9, 0x11, 0x8E, 0x20, 0xBF, 0xFF, 0xF4, 0x00, 0xB8, 0x01, 0xAC, 0x00, 0xB8, 0x00,
+    0x1E, 0x60, 0xB8, 0x00, 0x07, 0xD0, 0xB8, 0x00, 0x01, 0xE6, 0x33, 0x03, 0x21, 0xC3, 0xC0, 0x05,
+    0xE3, 0x01, 0x11, 0x41, 0xC9, 0xDC, 0x5C, 0xC1, 0x00, 0xC7, 0x00, 0x0C, 0x50, 0x00, 0xC5, 0x00,
+    0x0C, 0x50, 0x00, 0xC5, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0xC4, 0x00, 0x00, 0x04,
+    0x50, 0x00, 0x00, 0x59, 0x98, 0x40, 0x09, 0xD7, 0x68, 0xD0, 0x1F, 0x40, 0x00, 0x00, 0x1F, 0x40,
+    0x00, 0x00, 0x0A, 0xFA, 0x73, 0x00, 0x00, 0x59, 0xDF, 0xB0, 0x00, 0x00, 0x04, 0xF6, 0x00, 0x00,
+    0x00, 0xD8, 0x17, 0x10, 0x04, 0xF5, 0x1C, 0xFD, 0xEE, 0x80, 0x00, 0x12, 0x20, 0x00, 0x00, 0x00,
+    0x44, 0x00, 0x00, 0x3D, 0x20, 0x00, 0x0B, 0x30, 0x00, 0x02, 0x43, 0x00, 0x0B, 0xDB, 0xCB, 0x03,
+    0xE0, 0x00, 0x10, 0x2F, 0x72, 0x00, 0x00, 0x4B, 0xED, 0x50, 0x00, 0x00, 0x6F, 0x12, 0x20, 0x03,
+    0xF1, 0x3E, 0xDC, 0xE7, 0x00, 0x02, 0x21, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x1C, 0xC4, 0x00,
+    0x00, 0x54, 0x18, 0x00, 0x00, 0x59, 0x98, 0x40, 0x09, 0xD7, 0x68, 0xD0, 0x1F, 0x40, 0x00, 0x00,
+    0x1F, 0x40, 0x00, 0x00, 0x0A, 0xFA, 0x73, 0x00, 0x00, 0x59, 0xDF, 0xB0, 0x00, 0x00, 0x04, 0xF6,
+    0x00, 0x00, 0x00, 0xD8, 0x17, 0x10, 0x04, 0xF5, 0x1C, 0xFD, 0xEE, 0x80, 0x00, 0x12, 0x20, 0x00,
+    0x00, 0x16, 0x00, 0x00, 0x09, 0xB8, 0x00, 0x03, 0xA0, 0xB3, 0x00, 0x02, 0x43, 0x00, 0x0B, 0xDB,
+    0xCB, 0x03, 0xE0, 0x00, 0x10, 0x2F, 0x72, 0x00, 0x00, 0x4B, 0xED, 0x50, 0x00, 0x00, 0x6F, 0x12,
+    0x20, 0x03, 0xF1, 0x3E, 0xDC, 0xE7, 0x00, 0x02, 0x21, 0x00, 0x00, 0x59, 0x98, 0x40, 0x09, 0xD7,
+    0x68, 0xD0, 0x1F, 0x40, 0x00, 0x00, 0x1F, 0x40, 0x00, 0x00, 0x0A, 0xFA, 0x73, 0x00, 0x00, 0x59,
+    0xDF, 0xB0, 0x00, 0x00, 0x04, 0xF6, 0x00, 0x00, 0x00, 0xD8, 0x17, 0x10, 0x04, 0xF5, 0x1C, 0xFD,
+    0xEE, 0x80, 0x00, 0x12, 0xD2, 0x00, 0x00, 0x25, 0xB5, 0x00, 0x00, 0x27, 0x60, 0x00, 0x00, 0x24,
+    0x30, 0x00, 0xBD, 0xBC, 0xB0, 0x3E, 0x00, 0x01, 0x02, 0xF7, 0x20, 0x00, 0x04, 0xBE, 0xD5, 0x00,
+    0x00, 0x06, 0xF1, 0x22, 0x00, 0x3F, 0x13, 0xED, 0xCE, 0x70, 0x00, 0x2A, 0x50, 0x00, 0x05, 0x89,
+    0x00, 0x00, 0x77, 0x10, 0x00, 0x00, 0x10, 0x01, 0x00, 0x00, 0x59, 0x59, 0x00, 0x00, 0x07, 0x90,
+    0x00, 0x00, 0x59, 0x98, 0x40, 0x09, 0xD7, 0x68, 0xD0, 0x1F, 0x40, 0x00, 0x00, 0x1F, 0x40, 0x00,
+    0x00, 0x0A, 0xFA, 0x73, 0x00, 0x00, 0x59, 0xDF, 0xB0, 0x00, 0x00, 0x04, 0xF6, 0x00, 0x00, 0x00,
+    0xD8, 0x17, 0x10, 0x04, 0xF5, 0x1C, 0xFD, 0xEE, 0x80, 0x00, 0x12, 0x20, 0x00, 0x02, 0x30, 0x32,
+    0x00, 0x1C, 0x3C, 0x10, 0x00, 0x4F, 0x50, 0x00, 0x02, 0x43, 0x00, 0x0B, 0xDB, 0xCB, 0x03, 0xE0,
+    0x00, 0x10, 0x2F, 0x72, 0x00, 0x00, 0x4B, 0xED, 0x50, 0x00, 0x00, 0x6F, 0x12, 0x20, 0x03, 0xF1,
+    0x3E, 0xDC, 0xE7, 0x00, 0x02, 0x21, 0x00, 0x07, 0x77, 0x77, 0x77, 0x70, 0x99, 0x9D, 0xD9, 0x99,
+    0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A,
+    0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00,
+    0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0x2A, 0x00, 0x00, 0x00, 0x45, 0xE0, 0x00, 0x00, 0x04, 0x74,
+    0x00, 0x00, 0x01, 0x10, 0x00, 0xC6, 0x00, 0x1C, 0x72, 0x18, 0xED, 0xC9, 0x0C, 0x60, 0x00, 0xC6,
+    0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0B, 0x80, 0x00, 0x4D, 0xFC, 0x00, 0x0B, 0x10, 0x24, 0xC3,
+    0x02, 0x76, 0x00, 0x00, 0x08, 0x66, 0x80, 0x00, 0x00, 0x09, 0x90, 0x00, 0x07, 0x77, 0x77, 0x77,
+    0x70, 0x99, 0x9D, 0xD9, 0x99, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00,
+    0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00,
+    0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x04, 0x70, 0x11, 0x98, 0x0C, 0x69,
+    0x31, 0xC7, 0x21, 0x8E, 0xDC, 0x90, 0xC6, 0x00, 0x0C, 0x60, 0x00, 0xC6, 0x00, 0x0C, 0x60, 0x00,
+    0xB8, 0x00, 0x04, 0xDF, 0xC0, 0x07, 0x77, 0x77, 0x77, 0x70, 0x99, 0x9D, 0xD9, 0x99, 0x00, 0x00,
+    0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x04, 0xBE, 0xEB, 0x30,
+    0x00, 0x25, 0xCB, 0x51, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A,
+    0x90, 0x00, 0x01, 0x10, 0x00, 0xC6, 0x00, 0x1C, 0x72, 0x18, 0xED, 0xC9, 0x0C, 0x60, 0x07, 0xEC,
+    0xB9, 0x2C, 0x73, 0x20, 0xC6, 0x00, 0x0B, 0x80, 0x00, 0x4D, 0xFC, 0x02, 0xCB, 0x7B, 0x00, 0x02,
+    0x31, 0x62, 0x00, 0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6,
+    0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8,
+    0x00, 0x01, 0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x00,
+    0x10, 0x10, 0x00, 0xAC, 0x6B, 0x20, 0x08, 0x08, 0x70, 0x01, 0x00, 0x00, 0x20, 0xD4, 0x00, 0x2F,
+    0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x3F, 0x1B, 0x90,
+    0x0A, 0xF1, 0x3E, 0xDD, 0x7F, 0x10, 0x02, 0x10, 0x00, 0x01, 0xAA, 0xA7, 0x00, 0x00, 0x44, 0x42,
+    0x00, 0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00,
+    0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01,
+    0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x08, 0xAA, 0xA0,
+    0x00, 0x34, 0x44, 0x00, 0x10, 0x00, 0x02, 0x0D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40,
+    0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x03, 0xF1, 0xB9, 0x00, 0xAF, 0x13, 0xED, 0xD7, 0xF1,
+    0x00, 0x21, 0x00, 0x00, 0x00, 0x10, 0x01, 0x00, 0x01, 0xC6, 0x89, 0x00, 0x00, 0x37, 0x50, 0x00,
+    0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6,
+    0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4,
+    0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x02, 0x00, 0x20, 0x00,
+    0xB6, 0x5C, 0x00, 0x02, 0x89, 0x30, 0x01, 0x00, 0x00, 0x20, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02,
+    0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x3F, 0x1B, 0x90, 0x0A, 0xF1, 0x3E,
+    0xDD, 0x7F, 0x10, 0x02, 0x10, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x7C, 0xC2, 0x00, 0x00, 0xC0,
+    0x57, 0x00, 0x63, 0xB7, 0xB4, 0x63, 0xD6, 0x15, 0x30, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00,
+    0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00,
+    0x01, 0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x13, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x3C, 0xC4, 0x00, 0x0A, 0x21, 0xB0, 0x00, 0x79, 0x88, 0x00, 0x11, 0x55, 0x02, 0x0D,
+    0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x03,
+    0xF1, 0xB9, 0x00, 0xAF, 0x13, 0xED, 0xD7, 0xF1, 0x00, 0x21, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00,
+    0x00, 0x3C, 0x3D, 0x20, 0x00, 0x72, 0x63, 0x00, 0x63, 0x00, 0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6,
+    0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6,
+    0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4, 0x7E, 0x20, 0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30,
+    0x00, 0x13, 0x20, 0x00, 0x00, 0x15, 0x16, 0x00, 0x09, 0x68, 0x80, 0x02, 0xC2, 0xC0, 0x01, 0x00,
+    0x00, 0x20, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1,
+    0xD4, 0x00, 0x3F, 0x1B, 0x90, 0x0A, 0xF1, 0x3E, 0xDD, 0x7F, 0x10, 0x02, 0x10, 0x00, 0x63, 0x00,
+    0x00, 0x63, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00,
+    0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xD6, 0x00, 0x00, 0xE6, 0xC8, 0x00, 0x01, 0xF4, 0x7E, 0x20,
+    0x08, 0xE1, 0x09, 0xFD, 0xED, 0x30, 0x00, 0x2D, 0x20, 0x00, 0x00, 0x4B, 0x50, 0x00, 0x00, 0x06,
+    0x70, 0x00, 0x10, 0x00, 0x02, 0x0D, 0x40, 0x02, 0xF1, 0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x02, 0xF1,
+    0xD4, 0x00, 0x2F, 0x1D, 0x40, 0x03, 0xF1, 0xB9, 0x00, 0xAF, 0x13, 0xED, 0xD7, 0xF1, 0x00, 0x21,
+    0x2A, 0x00, 0x00, 0x05, 0xB5, 0x00, 0x00, 0x06, 0x70, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xAB, 0x80, 0x00, 0x00, 0x00, 0x00, 0x26, 0x07, 0x10, 0x00, 0x04, 0x60, 0x00, 0x37,
+    0x10, 0x00, 0x73, 0x5E, 0x00, 0x08, 0xF6, 0x00, 0x2F, 0x31, 0xF3, 0x00, 0xCA, 0xA0, 0x05, 0xE0,
+    0x0C, 0x70, 0x1E, 0x3D, 0x00, 0x9A, 0x00, 0x9B, 0x04, 0xC0, 0xE2, 0x0D, 0x60, 0x05, 0xE0, 0x88,
+    0x0A, 0x62, 0xF3, 0x00, 0x1F, 0x3C, 0x40, 0x6A, 0x5E, 0x00, 0x00, 0xC8, 0xE1, 0x03, 0xD9, 0xA0,
+    0x00, 0x09, 0xEC, 0x00, 0x0E, 0xE6, 0x00, 0x00, 0x5F, 0x80, 0x00, 0xAF, 0x30, 0x00, 0x00, 0x00,
+    0x26, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xB6, 0x00, 0x00, 0x00, 0x06, 0x71, 0xB1, 0x00, 0x01, 0x10,
+    0x01, 0x20, 0x00, 0x20, 0x4D, 0x00, 0x8F, 0x20, 0x4E, 0x01, 0xF2, 0x0C, 0xC6, 0x08, 0xA0, 0x0C,
+    0x61, 0xE5, 0xA0, 0xB6, 0x00, 0x8A, 0x5B, 0x1E, 0x1F, 0x20, 0x04, 0xE9, 0x70, 0xC8, 0xD0, 0x00,
+    0x0E, 0xE3, 0x08, 0xE9, 0x00, 0x00, 0xBE, 0x00, 0x4F, 0x50, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00,
+    0x00, 0x2C, 0xC2, 0x00, 0x00, 0x06, 0x22, 0x60, 0x00, 0x64, 0x00, 0x00, 0x46, 0x05, 0xE1, 0x00,
+    0x2E, 0x50, 0x0A, 0xA0, 0x0B, 0x90, 0x00, 0x1E, 0x56, 0xD1, 0x00, 0x00, 0x5E, 0xE4, 0x00, 0x00,
+    0x00, 0xBA, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90,
+    0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x05, 0x30, 0x00, 0x00, 0x4C, 0xC1, 0x00, 0x00, 0xB2,
+    0x58, 0x00, 0x11, 0x00, 0x00, 0x20, 0x5D, 0x00, 0x03, 0xF1, 0x0E, 0x40, 0x09, 0xA0, 0x08, 0xA0,
+    0x1E, 0x40, 0x02, 0xF1, 0x6D, 0x00, 0x00, 0xB7, 0xC7, 0x00, 0x00, 0x5E, 0xF1, 0x00, 0x00, 0x0D,
+    0xA0, 0x00, 0x00, 0x1E, 0x40, 0x00, 0x04, 0x9C, 0x00, 0x00, 0x0A, 0x92, 0x00, 0x00, 0x00, 0x0C,
+    0x56, 0xB0, 0x00, 0x00, 0x62, 0x35, 0x00, 0x06, 0x40, 0x00, 0x04, 0x60, 0x5E, 0x10, 0x02, 0xE5,
+    0x00, 0xAA, 0x00, 0xB9, 0x00, 0x01, 0xE5, 0x6D, 0x10, 0x00, 0x05, 0xEE, 0x40, 0x00, 0x00, 0x0B,
+    0xA0, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0xA9, 0x00, 0x00,
+    0x00, 0x0A, 0x90, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1C, 0x40, 0x00, 0x00, 0x04, 0x50,
+    0x00, 0x02, 0x77, 0x77, 0x77, 0x71, 0x29, 0x99, 0x99, 0xDF, 0x20, 0x00, 0x00, 0x3E, 0x60, 0x00,
+    0x00, 0x1D, 0x80, 0x00, 0x00, 0x0B, 0xB0, 0x00, 0x00, 0x09, 0xD1, 0x00, 0x00, 0x06, 0xE3, 0x00,
+    0x00, 0x03, 0xF5, 0x00, 0x00, 0x01, 0xD9, 0x11, 0x11, 0x10, 0x6F, 0xFF, 0xFF, 0xFF, 0x50, 0x00,
+    0x00, 0x44, 0x00, 0x00, 0x3D, 0x20, 0x00, 0x0B, 0x30, 0x00, 0x22, 0x22, 0x20, 0x3C, 0xCC, 0xDF,
+    0x40, 0x00, 0x09, 0xC0, 0x00, 0x07, 0xD1, 0x00, 0x05, 0xE2, 0x00, 0x04, 0xE4, 0x00, 0x02, 0xE5,
+    0x00, 0x00, 0x7F, 0xEE, 0xEE, 0x40, 0x00, 0x03, 0xD1, 0x00, 0x00, 0x00, 0x16, 0x10, 0x00, 0x27,
+    0x77, 0x77, 0x77, 0x12, 0x99, 0x99, 0x9D, 0xF2, 0x00, 0x00, 0x03, 0xE6, 0x00, 0x00, 0x01, 0xD8,
+    0x00, 0x00, 0x00, 0xBB, 0x00, 0x00, 0x00, 0x9D, 0x10, 0x00, 0x00, 0x6E, 0x30, 0x00, 0x00, 0x3F,
+    0x50, 0x00, 0x00, 0x1D, 0x91, 0x11, 0x11, 0x06, 0xFF, 0xFF, 0xFF, 0xF5, 0x00, 0x3D, 0x00, 0x00,
+    0x02, 0x90, 0x00, 0x02, 0x22, 0x22, 0x03, 0xCC, 0xCD, 0xF4, 0x00, 0x00, 0x9C, 0x00, 0x00, 0x7D,
+    0x10, 0x00, 0x5E, 0x20, 0x00, 0x4E, 0x40, 0x00, 0x2E, 0x50, 0x00, 0x07, 0xFE, 0xEE, 0xE4, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0xB3, 0xB1, 0x00, 0x00, 0x04, 0x93, 0x00, 0x02, 0x77, 0x77, 0x77,
+    0x71, 0x29, 0x99, 0x99, 0xDF, 0x20, 0x00, 0x00, 0x3E, 0x60, 0x00, 0x00, 0x1D, 0x80, 0x00, 0x00,
+    0x0B, 0xB0, 0x00, 0x00, 0x09, 0xD1, 0x00, 0x00, 0x06, 0xE3, 0x00, 0x00, 0x03, 0xF5, 0x00, 0x00,
+    0x01, 0xD9, 0x11, 0x11, 0x10, 0x6F, 0xFF, 0xFF, 0xFF, 0x50, 0x03, 0x30, 0x32, 0x00, 0x1C, 0x3C,
+    0x00, 0x00, 0x5E, 0x30, 0x00, 0x22, 0x22, 0x20, 0x3C, 0xCC, 0xDF, 0x40, 0x00, 0x09, 0xC0, 0x00,
+    0x07, 0xD1, 0x00, 0x05, 0xE2, 0x00, 0x04, 0xE4, 0x00, 0x02, 0xE5, 0x00, 0x00, 0x7F, 0xEE, 0xEE,
+    0x40, 0x01, 0xAD, 0xB0, 0x7C, 0x21, 0x19, 0x90, 0x08, 0xE9, 0x00, 0x09, 0x90, 0x00, 0x99, 0x00,
+    0x09, 0x90, 0x00, 0x99, 0x00, 0x09, 0x90, 0x00, 0x99, 0x00, 0x00, 0x00, 0x00, 0x5D, 0xDD, 0xDC,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xDD, 0xDD, 0xDD, 0xDD, 0xDD, 0xD5, 0x06, 0x5B, 0xC7,
+    0x94, 0x27, 0x6E, 0x97, 0x61, 0x06, 0x03, 0x45, 0xB0, 0xB5, 0xC7, 0x3F, 0x19, 0x43, 0xA0, 0x37,
+    0x06, 0x46, 0xE0, 0xC8, 0x98, 0x0E, 0x28, 0x12, 0x70, 0x03, 0xA8, 0x10, 0xDF, 0xF9, 0x0F, 0xFF,
+    0xA0, 0x7F, 0xD3, 0x00, 0x00, 0x00, 0x58, 0x00, 0x29, 0x20, 0x08, 0x58, 0xD0, 0x03, 0xF3, 0x00,
+    0xD8, 0x00, 0x05, 0x99, 0x40, 0x00, 0x9D, 0x76, 0xC5, 0x04, 0xF2, 0x00, 0x02, 0x2A, 0xC3, 0x33,
+    0x10, 0x8E, 0xC9, 0x99, 0x10, 0x1C, 0x81, 0x10, 0x00, 0x9D, 0xDA, 0xA2, 0x00, 0x07, 0xD0, 0x00,
+    0x00, 0x01, 0xE8, 0x00, 0x45, 0x00, 0x3D, 0xED, 0xC3, 0x00, 0x00, 0x22, 0x00, 0x00, 0x47, 0x00,
+    0x00, 0x00, 0x00, 0x4E, 0x50, 0x00, 0x00, 0x00, 0x3E, 0xD9, 0x99, 0x99, 0x99, 0x12, 0xEC, 0x77,
+    0x77, 0x77, 0x71, 0x02, 0xE7, 0x00, 0x00, 0x00, 0x00, 0x02, 0x50, 0x00, 0x00, 0x00, 0x00, 0x17,
+    0x00, 0x00, 0x1C, 0xFB, 0x00, 0x1C, 0xBF, 0xBB, 0x01, 0x72, 0xF1, 0x70, 0x00, 0x1F, 0x00, 0x00,
+    0x01, 0xF0, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x01, 0xF0, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x01, 0xF0,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x30, 0x00, 0x00, 0x00, 0x00, 0x6E, 0x30, 0x29, 0x99, 0x99, 0x99,
+    0xDE, 0x22, 0x77, 0x77, 0x77, 0x7D, 0xD1, 0x00, 0x00, 0x00, 0x08, 0xD2, 0x00, 0x00, 0x00, 0x00,
+    0x62, 0x00, 0x00, 0x17, 0x00, 0x00, 0x01, 0xF0, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x01, 0xF0, 0x00,
+    0x00, 0x1F, 0x00, 0x00, 0x01, 0xF0, 0x00, 0x01, 0x1F, 0x01, 0x02, 0xE4, 0xF4, 0xD1, 0x06, 0xEF,
+    0xE4, 0x00, 0x06, 0xF4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x72, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x70, 0x00, 0x00, 0x00, 0x02, 0xFC, 0x00, 0x00, 0x07,
+    0xCC, 0xDF, 0xFC, 0xCB, 0x40, 0x06, 0xFF, 0xFF, 0xFD, 0x30, 0x00, 0x04, 0xFF, 0xFD, 0x10, 0x00,
+    0x00, 0x6F, 0xEF, 0xF1, 0x00, 0x00, 0x0B, 0xC2, 0x5E, 0x60, 0x00, 0x01, 0x70, 0x00, 0x27, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x72, 0x00,
+    0x00, 0x00, 0x00, 0x06, 0x50, 0x00, 0x00, 0x00, 0x02, 0x46, 0x00, 0x00, 0x07, 0x65, 0x60, 0x35,
+    0x58, 0x40, 0x05, 0x40, 0x00, 0x06, 0x30, 0x00, 0x04, 0x30, 0x06, 0x10, 0x00, 0x00, 0x50, 0x53,
+    0x41, 0x00, 0x00, 0x07, 0x72, 0x45, 0x50, 0x00, 0x01, 0x70, 0x00, 0x27, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x56, 0x00, 0x00, 0x00, 0x5F, 0xFB, 0x40, 0x00, 0x5F, 0xFF, 0xFD, 0xB5, 0x5F,
+    0xFF, 0xFF, 0xF7, 0x5F, 0xFF, 0xFF, 0xF7, 0x5F, 0xFF, 0xFF, 0xF7, 0x57, 0x36, 0xCF, 0xF7, 0x54,
+    0x00, 0x01, 0x20, 0x54, 0x00, 0x00, 0x00, 0x54, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x00,
+    0x00, 0x02, 0xE4, 0x00, 0x00, 0x1D, 0x70, 0x00, 0x00, 0x9B, 0x00, 0x01, 0x05, 0xD1, 0x00, 0x0E,
+    0x6D, 0x40, 0x00, 0x0C, 0xF9, 0x00, 0x00, 0x05, 0xA1, 0x00, 0x00, 0x04, 0x00, 0x00, 0x04, 0x02,
+    0xE9, 0x00, 0x0A, 0xE1, 0x04, 0xE9, 0x0A, 0xE3, 0x00, 0x04, 0xED, 0xE3, 0x00, 0x00, 0x0B, 0xFA,
+    0x00, 0x00, 0x0A, 0xE6, 0xE9, 0x00, 0x0A, 0xE3, 0x04, 0xE9, 0x02, 0xC3, 0x00, 0x04, 0xC1, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x35, 0x40, 0x00, 0x00, 0x03, 0xCF, 0xFF, 0xE6, 0x00, 0x03,
+    0xEF, 0xFF, 0xFF, 0xF7, 0x00, 0xCF, 0xFF, 0xFF, 0xFF, 0xF2, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0x74,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0x80, 0xDF, 0xFF, 0xFF, 0xFF, 0xF4,
+    0x05, 0xFF, 0xFF, 0xFF, 0xFA, 0x00, 0x06, 0xFF, 0xFF, 0xFA, 0x10, 0x00, 0x02, 0x79, 0x83, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x92, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFD, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x99, 0x66, 0xA2, 0x00, 0x00, 0x00, 0x0A, 0x75, 0x96, 0x0A, 0x20, 0x00, 0x00, 0xAF, 0xEF,
+    0xFF, 0x37, 0xD2, 0x00, 0x0A, 0xFF, 0xFF, 0xFD, 0x1B, 0xFD, 0x20, 0x8F, 0xFF, 0xFF, 0xD1, 0x8F,
+    0xFF, 0xD0, 0x1D, 0xFF, 0xFF, 0x46, 0xFF, 0xFF, 0x40, 0x01, 0xDF, 0xFF, 0x1B, 0xFF, 0xF4, 0x00,
+    0x00, 0x1D, 0xFF, 0x9D, 0xFF, 0x40, 0x00, 0x00, 0x01, 0xDF, 0x6C, 0xF4, 0x00, 0x00, 0x00, 0x00,
+    0x1D, 0x0A, 0x40, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00,
+];
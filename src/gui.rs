@@ -1,21 +1,152 @@
+pub use crate::gui::prompt::{Action, Prompt};
+use crate::gui::duplicates::Duplicates;
+use crate::gui::settings::Settings;
+use crate::gui::viewer::Viewer;
+use crate::buffer::{Buffer, BufferView};
+use crate::config::Layout;
+use crate::fileops;
+use crate::filter::Filter;
+use crate::input::Mouse;
+use crate::animation::Animation;
+use crate::library::{self, Change, Library};
+use crate::pool::Token;
+use crate::text::{Align, LINE_HEIGHT};
+use crate::xmp::{Flag, Label, Xmp};
 use crate::{State, Vec2};
+use minifb::{Key, MouseButton};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+mod duplicates;
+mod prompt;
+mod settings;
+mod viewer;
+
+pub const STATUS_HEIGHT: u32 = LINE_HEIGHT + 8;
+const CAPTION_HEIGHT: u32 = LINE_HEIGHT + 4;
+/// The height justified rows aim for before they get scaled to fit the window, relative to the thumbnail size.
+const ROW_HEIGHT: f32 = 0.6;
 
 pub struct View {
     widgets: Vec<Box<dyn Widget + Send + Sync>>,
     pub height: u32,
     pub scroll: u32,
+    /// Shown on the left of the status bar.
+    pub status: String,
+    /// Takes all keys while it is open.
+    pub prompt: Option<Prompt>,
+    /// Which images the gallery shows.
+    pub filter: Filter,
+    /// How many images the gallery shows.
+    pub matches: usize,
+    previous: Option<Box<View>>,
+    navigation: Option<Navigation>,
+    /// Work that needs every widget of the view, which a widget can't do while it is being called.
+    deferred: Vec<Deferred>,
+}
+
+type Deferred = Box<dyn FnOnce(&mut State) + Send + Sync>;
+
+/// Switching views from inside a widget has to wait until the current view got its widgets back.
+enum Navigation {
+    Open(Box<View>),
+    Back,
 }
 
 impl View {
-    pub fn gallery() -> Self {
-        let mut widgets: Vec<Box<dyn Widget + Send + Sync>> = Vec::new();
-        widgets.push(Box::new(Scrollbar::default()));
-        widgets.push(Box::new(Gallery::default()));
+    fn new(widgets: Vec<Box<dyn Widget + Send + Sync>>) -> Self {
         View {
             widgets,
             height: 0,
             scroll: 0,
+            status: String::new(),
+            prompt: None,
+            filter: Filter::default(),
+            matches: 0,
+            previous: None,
+            navigation: None,
+            deferred: Vec::new(),
+        }
+    }
+
+    pub fn gallery() -> Self {
+        View::new(vec![
+            Box::new(Scrollbar::default()),
+            Box::new(Gallery::default()),
+            Box::new(StatusBar::default()),
+        ])
+    }
+
+    /// Shows `index`, stepping only through the images that match `filter`.
+    pub fn viewer(index: usize, filter: Filter) -> Self {
+        View::new(vec![Box::new(Viewer::new(index, filter)), Box::new(StatusBar::default())])
+    }
+
+    pub fn slideshow(index: usize, filter: Filter) -> Self {
+        View::new(vec![Box::new(Viewer::slideshow(index, filter)), Box::new(StatusBar::default())])
+    }
+
+    pub fn duplicates() -> Self {
+        View::new(vec![
+            Box::new(Scrollbar::default()),
+            Box::new(Duplicates::default()),
+            Box::new(StatusBar::default()),
+        ])
+    }
+
+    pub fn settings() -> Self {
+        View::new(vec![
+            Box::new(Scrollbar::default()),
+            Box::new(Settings::default()),
+            Box::new(StatusBar::default()),
+        ])
+    }
+
+    /// Opens `view` on top of the current one, which comes back as it was on [`View::back`].
+    pub fn open(&mut self, view: View) {
+        self.navigation = Some(Navigation::Open(Box::new(view)));
+    }
+
+    pub fn back(&mut self) {
+        self.navigation = Some(Navigation::Back);
+    }
+
+    /// Shows only the images matching `query` in the gallery, see [`Filter`].
+    pub fn filter(state: &mut State, query: &str) {
+        if state.view.filter.query == query {
+            return;
         }
+        state.view.filter = Filter::parse(query);
+        state.view.scroll = 0;
+        View::resize(state);
+        state.update();
+    }
+
+    /// Runs `f` once the current event has been handled by every widget.
+    pub fn defer(&mut self, f: impl FnOnce(&mut State) + Send + Sync + 'static) {
+        self.deferred.push(Box::new(f));
+    }
+
+    /// Runs deferred work and switches to the view that was opened or goes back.
+    pub fn navigate(state: &mut State) {
+        for f in std::mem::take(&mut state.view.deferred) {
+            f(state);
+        }
+        match state.view.navigation.take() {
+            Some(Navigation::Open(view)) => {
+                let previous = std::mem::replace(&mut state.view, *view);
+                state.view.previous = Some(Box::new(previous));
+            }
+            Some(Navigation::Back) => match state.view.previous.take() {
+                Some(previous) => state.view = *previous,
+                None => return,
+            },
+            None => return,
+        }
+        View::resize(state);
+        state.view.scroll = state.view.scroll.min(state.view.height.saturating_sub(state.buffer.size.y));
+        state.update();
     }
 
     pub fn resize(state: &mut State) {
@@ -26,6 +157,111 @@ impl View {
         state.view.widgets = widgets;
     }
 
+    pub fn changed(state: &mut State, changes: &[Change]) {
+        if changes.is_empty() {
+            return;
+        }
+        // Views further down the stack have to stay in sync as well.
+        if let Some(mut previous) = state.view.previous.take() {
+            std::mem::swap(&mut state.view, &mut previous);
+            View::changed(state, changes);
+            std::mem::swap(&mut state.view, &mut previous);
+            state.view.previous = Some(previous);
+        }
+
+        let mut widgets = std::mem::take(&mut state.view.widgets);
+        for change in changes.iter() {
+            for widget in widgets.iter_mut() {
+                widget.changed(state, change);
+            }
+        }
+        state.view.widgets = widgets;
+        View::resize(state);
+        state.update();
+    }
+
+    /// Keeps the views on the same images after [`Library::sort`] moved them, `moved` is what it returned.
+    pub fn sorted(state: &mut State, moved: &[usize]) {
+        if let Some(mut previous) = state.view.previous.take() {
+            std::mem::swap(&mut state.view, &mut previous);
+            View::sorted(state, moved);
+            std::mem::swap(&mut state.view, &mut previous);
+            state.view.previous = Some(previous);
+        }
+        let mut widgets = std::mem::take(&mut state.view.widgets);
+        for widget in widgets.iter_mut() {
+            widget.sorted(state, moved);
+        }
+        state.view.widgets = widgets;
+    }
+
+    /// Gives every widget a chance to handle `key`, returns whether one did.
+    pub fn key(state: &mut State, key: Key) -> bool {
+        if let Some(prompt) = state.view.prompt.take() {
+            state.view.prompt = prompt.key(state, key);
+            return true;
+        }
+        let mut widgets = std::mem::take(&mut state.view.widgets);
+        let handled = widgets.iter_mut().any(|widget| widget.key(state, key));
+        state.view.widgets = widgets;
+        View::navigate(state);
+        handled
+    }
+
+    /// Gives every widget a chance to handle the scroll wheel, returns whether one did.
+    pub fn scroll(state: &mut State, delta: f32) -> bool {
+        let mut widgets = std::mem::take(&mut state.view.widgets);
+        let handled = widgets.iter_mut().any(|widget| widget.scroll(state, delta));
+        state.view.widgets = widgets;
+        View::navigate(state);
+        handled
+    }
+
+    /// Buttons only go to the topmost widget under the mouse, everything else goes to all of them.
+    pub fn mouse(state: &mut State, event: Mouse) -> bool {
+        let mut widgets = std::mem::take(&mut state.view.widgets);
+        let scroll = state.view.scroll;
+        let handled = match event {
+            Mouse::Down(pos, _) | Mouse::DoubleClick(pos) => widgets
+                .iter_mut()
+                .rev()
+                .find(|widget| contains(widget.pos(scroll), widget.size(), pos))
+                .is_some_and(|widget| widget.mouse(state, &event)),
+            _ => {
+                let mut handled = false;
+                for widget in widgets.iter_mut() {
+                    handled |= widget.mouse(state, &event);
+                }
+                handled
+            }
+        };
+        state.view.widgets = widgets;
+        if handled {
+            state.update();
+        }
+        View::navigate(state);
+        handled
+    }
+
+    /// Typed text only goes to the prompt.
+    pub fn char(state: &mut State, c: char) -> bool {
+        let Some(mut prompt) = state.view.prompt.take() else {
+            return false;
+        };
+        prompt.char(state, c);
+        state.view.prompt = Some(prompt);
+        true
+    }
+
+    /// Lets widgets advance animations by the time since the last frame.
+    pub fn tick(state: &mut State, elapsed: Duration) {
+        let mut widgets = std::mem::take(&mut state.view.widgets);
+        for widget in widgets.iter_mut() {
+            widget.tick(state, elapsed);
+        }
+        state.view.widgets = widgets;
+    }
+
     pub fn draw(state: &mut State) {
         state.buffer.clear(state.config.background_color);
         let mut widgets = std::mem::take(&mut state.view.widgets);
@@ -33,6 +269,10 @@ impl View {
             widget.draw(state);
         }
         state.view.widgets = widgets;
+        if let Some(prompt) = state.view.prompt.take() {
+            prompt.draw(state);
+            state.view.prompt = Some(prompt);
+        }
     }
 }
 
@@ -40,13 +280,378 @@ pub trait Widget {
     fn pos(&self, scroll: u32) -> Vec2;
     fn size(&self) -> Vec2;
     fn resize(&mut self, _state: &mut State) {}
+    fn changed(&mut self, _state: &mut State, _change: &Change) {}
+    fn sorted(&mut self, _state: &mut State, _moved: &[usize]) {}
+    fn key(&mut self, _state: &mut State, _key: Key) -> bool {
+        false
+    }
+    fn scroll(&mut self, _state: &mut State, _delta: f32) -> bool {
+        false
+    }
+    fn mouse(&mut self, _state: &mut State, _event: &Mouse) -> bool {
+        false
+    }
+    fn tick(&mut self, _state: &mut State, _elapsed: Duration) {}
     fn draw(&mut self, state: &mut State);
 }
 
+fn contains(pos: Vec2, size: Vec2, point: Vec2) -> bool {
+    point.x >= pos.x && point.y >= pos.y && point.x < pos.x + size.x && point.y < pos.y + size.y
+}
+
 #[derive(Default)]
 struct Gallery {
     viewport: Vec2,
     children: Vec<Image>,
+    hovered: Option<usize>,
+    mouse: Option<Vec2>,
+    /// The scroll position of the last frame, to know which way to prefetch.
+    last_scroll: u32,
+    scrolling_up: bool,
+    /// The library index of the image that the arrow keys move from.
+    focus: Option<usize>,
+    /// The library index that Shift selects a range from.
+    anchor: Option<usize>,
+    /// The corners of the rubber band in content coordinates, while dragging one.
+    band: Option<(Vec2, Vec2)>,
+    /// What was selected before a Ctrl rubber band started, so it can be added to.
+    band_base: Vec<usize>,
+    /// The query `children` were last filtered with.
+    query: String,
+}
+
+impl Gallery {
+    /// Finds the child under `pos`, which is in window coordinates.
+    fn child_at(&self, scroll: u32, pos: Vec2) -> Option<usize> {
+        let pos = Vec2::from(pos.x, pos.y + scroll);
+        self.children.iter().position(|c| contains(c.pos, c.size, pos))
+    }
+
+    /// Places thumbnails at their own size from left to right, returns the height of the content.
+    fn flow(&mut self, state: &mut State) -> u32 {
+        let caption = if state.config.captions { CAPTION_HEIGHT } else { 0 };
+        let mut left = 20;
+        let mut top = 20i32;
+        let mut row_height = 0;
+        for image in &mut self.children {
+            image.resize(state);
+            let size = image.size().add2(0, caption);
+            if left + size.x > self.viewport.x - 20 {
+                left = 20;
+                top += (row_height + 20) as i32;
+                row_height = 0;
+            }
+            if size.y > row_height {
+                row_height = size.y;
+            }
+
+            image.pos = Vec2::from(left, top as u32);
+            left += size.x + 20;
+        }
+        top as u32 + row_height + 20
+    }
+
+    /// Fills rows until they are wider than the window at [`ROW_HEIGHT`], then scales each one
+    /// down so it exactly fits. The last row keeps its height, returns the height of the content.
+    fn justify(&mut self, state: &mut State) -> u32 {
+        let caption = if state.config.captions { CAPTION_HEIGHT } else { 0 };
+        let row_height = (state.library.thumbnail_size as f32 * ROW_HEIGHT).round();
+        let width = self.viewport.x.saturating_sub(40) as f32;
+        let mut top = 20;
+        let mut start = 0;
+        let mut aspects = 0.0;
+        for end in 0..self.children.len() {
+            let size = state.library.images[self.children[end].index].size;
+            aspects += size.x as f32 / size.y.max(1) as f32;
+            let gaps = (20 * (end - start)) as f32;
+            if aspects * row_height + gaps >= width {
+                let height = ((width - gaps) / aspects).max(1.0);
+                self.place_row(state, start..end + 1, top, height, true);
+                top += height.round() as u32 + caption + 20;
+                start = end + 1;
+                aspects = 0.0;
+            }
+        }
+        if start < self.children.len() {
+            self.place_row(state, start..self.children.len(), top, row_height, false);
+            top += row_height as u32 + caption + 20;
+        }
+        top
+    }
+
+    fn place_row(&mut self, state: &State, row: std::ops::Range<usize>, top: u32, height: f32, fill: bool) {
+        let right = self.viewport.x.saturating_sub(20);
+        let last = row.end - 1;
+        let mut left = 20;
+        for i in row {
+            let image = &mut self.children[i];
+            let size = state.library.images[image.index].size;
+            let mut width = (size.x as f32 * height / size.y.max(1) as f32).round().max(1.0) as u32;
+            if fill && i == last {
+                // Rounding shouldn't leave a ragged edge.
+                width = right.saturating_sub(left).max(1);
+            }
+            image.pos = Vec2::from(left, top);
+            image.size = Vec2::from(width, height.round() as u32);
+            left += width + 20;
+        }
+    }
+
+    /// Scales thumbnails by `factor`, keeping the image under the mouse, or in the middle of the
+    /// window, at the same place on screen.
+    fn zoom(&mut self, state: &mut State, factor: f32) {
+        let size = ((state.library.thumbnail_size as f32 * factor).round() as u32).clamp(50, 2000);
+        if size == state.library.thumbnail_size {
+            return;
+        }
+
+        let anchor = self.mouse.unwrap_or(Vec2::from(self.viewport.x / 2, self.viewport.y / 2));
+        let y = anchor.y + state.view.scroll;
+        let child = self
+            .child_at(state.view.scroll, anchor)
+            .or_else(|| self.children.iter().position(|c| c.pos.y + c.size.y > y));
+        let offset = child.map(|i| {
+            let c = &self.children[i];
+            y.saturating_sub(c.pos.y) as f32 / c.size.y.max(1) as f32
+        });
+
+        Library::set_thumbnail_size(state, size);
+        self.resize(state);
+
+        if let (Some(i), Some(offset)) = (child, offset) {
+            let c = &self.children[i];
+            let y = c.pos.y as f32 + offset * c.size.y as f32;
+            let max = state.view.height.saturating_sub(self.viewport.y);
+            state.view.scroll = (y as u32).saturating_sub(anchor.y).min(max);
+        }
+        self.hover(state, self.mouse.and_then(|pos| self.child_at(state.view.scroll, pos)));
+        state.update();
+    }
+
+    fn hover(&mut self, state: &mut State, hovered: Option<usize>) {
+        if hovered == self.hovered {
+            return;
+        }
+        if let Some(child) = self.hovered.and_then(|i| self.children.get_mut(i)) {
+            child.hovered = false;
+            child.stop();
+        }
+        state.view.status.clear();
+        if let Some(child) = hovered.and_then(|i| self.children.get_mut(i)) {
+            child.hovered = true;
+            let image = &state.library.images[child.index];
+            state.view.status = format!("{} — {}×{}", image.name, image.dimensions.x, image.dimensions.y);
+            if image.animated && state.config.animate_on_hover {
+                let (path, orientation) = (image.path.clone(), image.exif.orientation);
+                let token = Token::default();
+                child.animation = Some((library::Image::animation(path, orientation, token.clone(), state), token));
+            }
+        }
+        self.hovered = hovered;
+        state.update();
+    }
+
+    /// Queues the thumbnails in the next few screens in the direction the gallery is scrolling,
+    /// the closest ones first.
+    fn prefetch(&mut self, state: &mut State) {
+        let scroll = state.view.scroll;
+        if scroll != self.last_scroll {
+            self.scrolling_up = scroll < self.last_scroll;
+            self.last_scroll = scroll;
+        }
+        let ahead = state.config.prefetch_screens * self.viewport.y;
+        let bottom = scroll + self.viewport.y;
+
+        let mut images = std::mem::take(&mut state.library.images);
+        for child in self.children.iter() {
+            let distance = if self.scrolling_up {
+                scroll.checked_sub(child.pos.y + child.size.y)
+            } else {
+                child.pos.y.checked_sub(bottom)
+            };
+            if let Some(distance) = distance.filter(|&d| d < ahead)
+                && let Some(image) = images.get_mut(child.index)
+            {
+                image.get(state, distance + 1);
+            }
+        }
+        state.library.images = images;
+    }
+
+    /// The paths batch operations work on, the selection or else the focused image.
+    fn targets(&self, state: &State) -> Vec<PathBuf> {
+        self.target_indices(state).into_iter().map(|i| state.library.images[i].path.clone()).collect()
+    }
+
+    fn target_indices(&self, state: &State) -> Vec<usize> {
+        let mut indices = state.library.selection();
+        if indices.is_empty() {
+            indices.extend(self.focus);
+        }
+        indices
+    }
+
+    /// Changes the rating, label or flag of the targets and writes their sidecars.
+    fn tag(&self, state: &mut State, change: impl Fn(&mut Xmp)) {
+        let mut failed = None;
+        let indices = self.target_indices(state);
+        for &i in &indices {
+            let image = &mut state.library.images[i];
+            let mut xmp = image.xmp.clone();
+            change(&mut xmp);
+            if xmp == image.xmp {
+                continue;
+            }
+            match xmp.write(&image.path) {
+                Ok(()) => image.xmp = xmp,
+                Err(err) => {
+                    eprintln!("Failed to write sidecar for {}: {}", image.path.display(), err);
+                    failed = Some(format!("Failed to write sidecar for {}: {err}", image.name));
+                }
+            }
+        }
+        if let Some(failed) = failed {
+            state.view.status = failed;
+        }
+    }
+
+    fn child_of(&self, index: usize) -> Option<usize> {
+        self.children.iter().position(|c| c.index == index)
+    }
+
+    /// Selects `index` the way a click does: alone, toggled with Ctrl, or a range from the anchor with Shift.
+    fn select(&mut self, state: &mut State, index: usize) {
+        let images = &mut state.library.images;
+        if state.modifiers.shift {
+            // The range only covers what the filter shows, hidden images in between stay unselected.
+            let Some(to) = self.child_of(index) else {
+                return;
+            };
+            let from = self.anchor.and_then(|anchor| self.child_of(anchor)).unwrap_or(to);
+            if !state.modifiers.ctrl {
+                images.iter_mut().for_each(|image| image.selected = false);
+            }
+            for child in &self.children[from.min(to)..=from.max(to)] {
+                images[child.index].selected = true;
+            }
+        } else {
+            if state.modifiers.ctrl {
+                images[index].selected = !images[index].selected;
+            } else {
+                images.iter_mut().for_each(|image| image.selected = false);
+                images[index].selected = true;
+            }
+            self.anchor = Some(index);
+        }
+        self.focus = Some(index);
+        state.update();
+    }
+
+    /// Moves the focus to `child` with the arrow keys. Ctrl only moves the focus, everything else selects.
+    fn move_focus(&mut self, state: &mut State, child: usize) {
+        let index = self.children[child].index;
+        if state.modifiers.ctrl && !state.modifiers.shift {
+            self.focus = Some(index);
+            state.update();
+        } else {
+            self.select(state, index);
+        }
+
+        // Keep the focused image on screen.
+        let caption = if state.config.captions { CAPTION_HEIGHT } else { 0 };
+        let c = &self.children[child];
+        let visible = self.viewport.y.saturating_sub(STATUS_HEIGHT);
+        let max = state.view.height.saturating_sub(self.viewport.y);
+        if c.pos.y < state.view.scroll + 20 {
+            state.view.scroll = c.pos.y.saturating_sub(20);
+        } else if c.pos.y + c.size.y + caption + 20 > state.view.scroll + visible {
+            state.view.scroll = (c.pos.y + c.size.y + caption + 20).saturating_sub(visible).min(max);
+        }
+    }
+
+    /// Finds the child to focus next for an arrow key, `None` at the edges.
+    fn neighbour(&self, state: &State, key: Key) -> Option<usize> {
+        let Some(current) = self.focus.and_then(|index| self.child_of(index)) else {
+            // Start at the first image on screen.
+            let scroll = state.view.scroll;
+            return self.children.iter().position(|c| c.pos.y + c.size.y > scroll);
+        };
+        let c = &self.children[current];
+        let center = c.pos.x as i32 + c.size.x as i32 / 2;
+        let closest_in_row = |y: u32| {
+            (0..self.children.len())
+                .filter(|&i| self.children[i].pos.y == y)
+                .min_by_key(|&i| (self.children[i].pos.x as i32 + self.children[i].size.x as i32 / 2 - center).abs())
+        };
+        match key {
+            Key::Left => current.checked_sub(1),
+            Key::Right => Some(current + 1).filter(|&i| i < self.children.len()),
+            Key::Up => {
+                let row = self.children.iter().map(|c| c.pos.y).filter(|&y| y < c.pos.y).max()?;
+                closest_in_row(row)
+            }
+            Key::Down => {
+                let row = self.children.iter().map(|c| c.pos.y).filter(|&y| y > c.pos.y).min()?;
+                closest_in_row(row)
+            }
+            _ => None,
+        }
+    }
+
+    /// Selects every image that intersects the rubber band, on top of what was selected before it.
+    fn select_band(&mut self, state: &mut State) {
+        let Some((a, b)) = self.band else {
+            return;
+        };
+        let (min, max) = (Vec2::from(a.x.min(b.x), a.y.min(b.y)), Vec2::from(a.x.max(b.x), a.y.max(b.y)));
+        let images = &mut state.library.images;
+        images.iter_mut().for_each(|image| image.selected = false);
+        for &index in &self.band_base {
+            if let Some(image) = images.get_mut(index) {
+                image.selected = true;
+            }
+        }
+        for c in &self.children {
+            if c.pos.x <= max.x && c.pos.y <= max.y && c.pos.x + c.size.x >= min.x && c.pos.y + c.size.y >= min.y {
+                images[c.index].selected = true;
+            }
+        }
+        state.update();
+    }
+
+    /// Shows the images matching the filter of the view. Hidden images are deselected, so batch operations
+    /// only ever work on what can be seen.
+    fn refilter(&mut self, state: &mut State) {
+        self.hover(state, None);
+        self.query = state.view.filter.query.clone();
+        self.children.clear();
+        for index in 0..state.library.images.len() {
+            let image = &mut state.library.images[index];
+            if !state.view.filter.matches(image) {
+                image.selected = false;
+                continue;
+            }
+            let mut img = Image::new(index);
+            img.resize(state);
+            self.children.push(img);
+        }
+        for tracked in [&mut self.focus, &mut self.anchor] {
+            *tracked = tracked.filter(|&index| self.children.iter().any(|c| c.index == index));
+        }
+    }
+
+    /// Puts the library in its new order and lays the gallery out again from the top.
+    fn sort(&mut self, state: &mut State) {
+        state.library.sort();
+        self.children.clear();
+        self.hovered = None;
+        self.focus = None;
+        self.anchor = None;
+        state.view.scroll = 0;
+        let direction = if state.library.reverse { ", reversed" } else { "" };
+        state.view.status = format!("Sorted by {}{direction}", state.library.sort.name());
+        self.resize(state);
+    }
 }
 
 impl Widget for Gallery {
@@ -61,45 +666,283 @@ impl Widget for Gallery {
     fn resize(&mut self, state: &mut State) {
         self.viewport = state.buffer.size;
 
-        if self.children.is_empty() {
-            for index in 0..state.library.images.len() {
-                let mut img = Image {
-                    pos: Vec2::zero(),
-                    size: Vec2::zero(),
-                    index,
+        if self.children.is_empty() || self.query != state.view.filter.query {
+            self.refilter(state);
+        }
+        state.view.matches = self.children.len();
+
+        let bottom = match state.config.layout {
+            Layout::Flow => self.flow(state),
+            Layout::Justified => self.justify(state),
+        };
+        state.view.height = bottom + STATUS_HEIGHT;
+    }
+
+    fn changed(&mut self, state: &mut State, change: &Change) {
+        if self.viewport == Vec2::zero() {
+            // Not laid out yet, the first resize picks up the whole library.
+            return;
+        }
+        self.hover(state, None);
+        for tracked in [&mut self.focus, &mut self.anchor] {
+            *tracked = match (*tracked, *change) {
+                (Some(i), Change::Added(index)) if index <= i => Some(i + 1),
+                (Some(i), Change::Removed(index)) if index == i => None,
+                (Some(i), Change::Removed(index)) if index < i => Some(i - 1),
+                (tracked, _) => tracked,
+            };
+        }
+        self.band = None;
+        self.band_base.clear();
+        match *change {
+            Change::Added(index) => {
+                for child in self.children.iter_mut().filter(|c| c.index >= index) {
+                    child.index += 1;
+                }
+                if state.view.filter.matches(&state.library.images[index]) {
+                    let mut img = Image::new(index);
+                    img.resize(state);
+                    let at = self.children.partition_point(|c| c.index < index);
+                    self.children.insert(at, img);
+                }
+            }
+            Change::Removed(index) => {
+                self.children.retain(|c| c.index != index);
+                for child in self.children.iter_mut().filter(|c| c.index > index) {
+                    child.index -= 1;
+                }
+            }
+            Change::Modified(index) => {
+                let at = self.children.partition_point(|c| c.index < index);
+                let shown = self.children.get(at).is_some_and(|c| c.index == index);
+                match (shown, state.view.filter.matches(&state.library.images[index])) {
+                    (true, true) => self.children[at].resize(state),
+                    (true, false) => {
+                        self.children.remove(at);
+                        state.library.images[index].selected = false;
+                    }
+                    (false, true) => {
+                        let mut img = Image::new(index);
+                        img.resize(state);
+                        self.children.insert(at, img);
+                    }
+                    (false, false) => {}
+                }
+            }
+        }
+    }
+
+    fn sorted(&mut self, state: &mut State, moved: &[usize]) {
+        // The next resize lays the children out again in the new order.
+        self.hover(state, None);
+        self.children.clear();
+        for tracked in [&mut self.focus, &mut self.anchor] {
+            *tracked = tracked.map(|index| moved[index]);
+        }
+        self.band = None;
+        self.band_base.clear();
+    }
+
+    fn key(&mut self, state: &mut State, key: Key) -> bool {
+        match key {
+            Key::Enter => {
+                let scroll = state.view.scroll;
+                let first = self.children.iter().find(|c| c.pos.y + c.size.y > scroll).map(|c| c.index);
+                if let Some(index) = self.focus.or(first) {
+                    let filter = state.view.filter.clone();
+                    state.view.open(View::viewer(index, filter));
+                }
+            }
+            Key::Left | Key::Right | Key::Up | Key::Down => {
+                if let Some(child) = self.neighbour(state, key) {
+                    self.move_focus(state, child);
+                }
+            }
+            Key::Space => {
+                if let Some(index) = self.focus {
+                    state.library.images[index].selected = !state.library.images[index].selected;
+                    self.anchor = Some(index);
+                }
+            }
+            Key::A if state.modifiers.ctrl => {
+                self.children.iter().for_each(|c| state.library.images[c.index].selected = true);
+            }
+            Key::I if state.modifiers.ctrl => {
+                self.children.iter().for_each(|c| state.library.images[c.index].selected ^= true);
+            }
+            Key::F if state.modifiers.ctrl => {
+                let query = state.view.filter.query.clone();
+                state.view.prompt = Some(Prompt::new("Filter:", query, Vec::new(), Action::Filter));
+            }
+            // Clears the selection first, the filter once nothing is selected.
+            Key::Escape => {
+                if state.library.images.iter().any(|image| image.selected) {
+                    state.library.images.iter_mut().for_each(|image| image.selected = false);
+                } else if !state.view.filter.query.is_empty() {
+                    state.view.defer(|state| View::filter(state, ""));
+                }
+            }
+            Key::Key0 | Key::NumPad0 => self.tag(state, |xmp| xmp.rating = 0),
+            Key::Key1 | Key::NumPad1 => self.tag(state, |xmp| xmp.rating = 1),
+            Key::Key2 | Key::NumPad2 => self.tag(state, |xmp| xmp.rating = 2),
+            Key::Key3 | Key::NumPad3 => self.tag(state, |xmp| xmp.rating = 3),
+            Key::Key4 | Key::NumPad4 => self.tag(state, |xmp| xmp.rating = 4),
+            Key::Key5 | Key::NumPad5 => self.tag(state, |xmp| xmp.rating = 5),
+            // Same keys as Lightroom, pressing the label an image already has removes it.
+            Key::Key6 | Key::Key7 | Key::Key8 | Key::Key9 => {
+                let label = match key {
+                    Key::Key6 => Label::Red,
+                    Key::Key7 => Label::Yellow,
+                    Key::Key8 => Label::Green,
+                    _ => Label::Blue,
                 };
-                img.resize(state);
-                self.children.push(img);
+                self.tag(state, |xmp| xmp.label = if xmp.label == Some(label) { None } else { Some(label) });
+            }
+            Key::P => self.tag(state, |xmp| xmp.flag = Flag::Pick),
+            Key::X => self.tag(state, |xmp| xmp.flag = Flag::Reject),
+            Key::U => self.tag(state, |xmp| xmp.flag = Flag::None),
+            Key::Delete => {
+                let paths = self.targets(state);
+                state.view.defer(move |state| fileops::apply(state, paths, ("trash", "Trashed"), fileops::trash));
             }
+            Key::Z if state.modifiers.ctrl => state.view.defer(fileops::undo),
+            Key::F2 => {
+                let paths = self.targets(state);
+                if let [path] = &paths[..] {
+                    let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+                    state.view.prompt = Some(Prompt::new("Rename to:", name, Vec::new(), Action::Rename(path.clone())));
+                } else {
+                    state.view.status = "Select a single image to rename".to_string();
+                }
+            }
+            Key::F6 | Key::F7 => {
+                let paths = self.targets(state);
+                if paths.is_empty() {
+                    return true;
+                }
+                let libraries: Vec<String> = state
+                    .library
+                    .roots
+                    .iter()
+                    .filter(|root| root.path.is_dir())
+                    .map(|root| root.path.to_string_lossy().into_owned())
+                    .collect();
+                let first = libraries.first().cloned().unwrap_or_default();
+                state.view.prompt = Some(match key {
+                    Key::F6 => Prompt::new("Move to (Tab for libraries):", first, libraries, Action::Move(paths)),
+                    _ => Prompt::new("Copy to (Tab for libraries):", first, libraries, Action::Copy(paths)),
+                });
+            }
+            Key::Equal | Key::NumPadPlus => self.zoom(state, 1.25),
+            Key::Minus | Key::NumPadMinus => self.zoom(state, 0.8),
+            Key::D => state.view.open(View::duplicates()),
+            Key::Comma if state.modifiers.ctrl => state.view.open(View::settings()),
+            Key::S => {
+                state.library.sort = state.library.sort.next();
+                self.sort(state);
+            }
+            Key::R => {
+                state.library.reverse = !state.library.reverse;
+                self.sort(state);
+            }
+            _ => return false,
         }
+        true
+    }
 
-        let mut left = 20;
-        let mut top = 20i32;
-        let mut row_height = 0;
-        for image in &mut self.children {
-            let size = image.size();
-            if left + size.x > self.viewport.x - 20 {
-                left = 20;
-                top += (row_height + 20) as i32;
-                row_height = 0;
+    fn scroll(&mut self, state: &mut State, delta: f32) -> bool {
+        if !state.modifiers.ctrl {
+            return false;
+        }
+        self.zoom(state, 1.1f32.powf(delta.signum()));
+        true
+    }
+
+    fn mouse(&mut self, state: &mut State, event: &Mouse) -> bool {
+        let scroll = state.view.scroll;
+        match *event {
+            Mouse::Move(pos) => {
+                self.mouse = Some(pos);
+                self.hover(state, self.child_at(scroll, pos));
+                if let Some((_, end)) = &mut self.band {
+                    *end = pos.add2(0, scroll);
+                    self.select_band(state);
+                }
             }
-            if size.y > row_height {
-                row_height = size.y;
+            Mouse::Leave => {
+                self.mouse = None;
+                self.hover(state, None);
             }
+            Mouse::Down(pos, MouseButton::Left) => {
+                match self.child_at(scroll, pos) {
+                    Some(child) => self.select(state, self.children[child].index),
+                    None => {
+                        self.band_base = if state.modifiers.ctrl { state.library.selection() } else { Vec::new() };
+                        let start = pos.add2(0, scroll);
+                        self.band = Some((start, start));
+                        self.select_band(state);
+                    }
+                }
+                return true;
+            }
+            Mouse::Up(_, MouseButton::Left) if self.band.is_some() => {
+                self.band = None;
+                self.band_base.clear();
+                state.update();
+                return true;
+            }
+            Mouse::DoubleClick(pos) => {
+                if let Some(child) = self.child_at(scroll, pos) {
+                    let filter = state.view.filter.clone();
+                    state.view.open(View::viewer(self.children[child].index, filter));
+                    return true;
+                }
+            }
+            _ => {}
+        }
+        false
+    }
 
-            image.pos = Vec2::from(left, top as u32);
-            left += size.x + 20;
+    /// Plays the animation of the hovered thumbnail while it is on screen.
+    fn tick(&mut self, state: &mut State, elapsed: Duration) {
+        let Some(child) = self.hovered.and_then(|i| self.children.get(i)) else {
+            return;
+        };
+        let scroll = state.view.scroll;
+        if child.pos.y + child.size.y <= scroll || child.pos.y >= scroll + self.viewport.y {
+            return;
+        }
+        if let Some((animation, _)) = &child.animation
+            && let Ok(mut animation) = animation.try_write()
+            && let Some(animation) = animation.as_mut()
+            && animation.tick(elapsed)
+        {
+            state.update();
         }
-        state.view.height = top as u32 + row_height + 20;
     }
 
     fn draw(&mut self, state: &mut State) {
+        state.library.frame += 1;
+        let caption = if state.config.captions { CAPTION_HEIGHT } else { 0 };
         for widget in self.children.iter_mut() {
             let y = (widget.pos.y as i32) - state.view.scroll as i32;
-            if y > -(widget.size().y as i32) && y < self.viewport.y as i32 {
+            if y > -((widget.size().y + caption) as i32) && y < self.viewport.y as i32 {
+                widget.focused = self.focus == Some(widget.index);
                 widget.draw(state);
+            } else {
+                widget.scaled = None;
             }
         }
+        if let Some((a, b)) = self.band {
+            let scroll = state.view.scroll as i32;
+            let size = Vec2::from(a.x.abs_diff(b.x).max(1), a.y.abs_diff(b.y).max(1));
+            let (x, y) = (a.x.min(b.x) as i32, a.y.min(b.y) as i32 - scroll);
+            state.buffer.tint(x, y, size, state.config.primary_color, 0.15);
+            state.buffer.outline(x, y, size, 1, state.config.primary_color);
+        }
+        self.prefetch(state);
+        state.library.queue.cancel_stale(state.library.frame);
+        state.library.evict(state.config.memory_budget as usize * 1024 * 1024);
     }
 }
 
@@ -107,6 +950,60 @@ struct Image {
     index: usize,
     pos: Vec2,
     size: Vec2,
+    hovered: bool,
+    focused: bool,
+    /// The thumbnail rescaled to `size`, when the layout doesn't show it at its own size.
+    scaled: Option<Buffer>,
+    /// Plays while the thumbnail is hovered, if it is animated.
+    animation: Option<(Arc<RwLock<Option<Animation>>>, Token)>,
+    /// The frame of the animation that is in `scaled`.
+    frame: Option<usize>,
+}
+
+impl Image {
+    fn new(index: usize) -> Self {
+        Image {
+            index,
+            pos: Vec2::zero(),
+            size: Vec2::zero(),
+            hovered: false,
+            focused: false,
+            scaled: None,
+            animation: None,
+            frame: None,
+        }
+    }
+
+    /// Draws the label, flag and rating in the corners of the tile.
+    fn badges(state: &mut State, xmp: &Xmp, x: i32, y: i32, size: Vec2) {
+        let badge = Vec2::from(LINE_HEIGHT + 4, LINE_HEIGHT + 4);
+        let draw = |state: &mut State, text: &str, x: i32, y: i32, color: u32| {
+            let size = Vec2::from(crate::text::measure(text) + 4, badge.y);
+            state.buffer.tint(x, y, size, 0xFF000000, 0.5);
+            state.buffer.text(text, x + 2, y + 2, size.x, Align::Left, color);
+        };
+        if let Some(label) = xmp.label {
+            draw(state, "●", x + 4, y + 4, label.color());
+        }
+        match xmp.flag {
+            Flag::Pick => draw(state, "⚑", x + size.x as i32 - badge.x as i32 - 4, y + 4, 0xFFFFFFFF),
+            Flag::Reject => draw(state, "✕", x + size.x as i32 - badge.x as i32 - 4, y + 4, 0xFFE53935),
+            Flag::None => {}
+        }
+        if xmp.rating > 0 && size.x > badge.x * 5 {
+            let stars: String = (0..5).map(|i| if i < xmp.rating { '★' } else { '☆' }).collect();
+            draw(state, &stars, x + 4, y + size.y as i32 - badge.y as i32 - 4, 0xFFFFD700);
+        }
+    }
+
+    /// Goes back to the still thumbnail.
+    fn stop(&mut self) {
+        if let Some((_, token)) = self.animation.take() {
+            token.cancel();
+            self.scaled = None;
+            self.frame = None;
+        }
+    }
 }
 
 impl Widget for Image {
@@ -124,13 +1021,56 @@ impl Widget for Image {
 
     fn draw(&mut self, state: &mut State) {
         let mut image = state.library.images.remove(self.index);
-        let arc = image.get(state);
-        if let Ok(buf) = arc.try_read() {
-            if let Some(ref buf) = *buf {
-                state
-                    .buffer
-                    .copy_from(buf, self.pos.x, self.pos.y as i32 - state.view.scroll as i32);
+        let arc = image.get(state, 0);
+        let y = self.pos.y as i32 - state.view.scroll as i32;
+        let animation = self.animation.as_ref().map(|(animation, _)| animation.clone());
+        if let Some(animation) = animation
+            && let Ok(animation) = animation.try_read()
+            && let Some(ref animation) = *animation
+        {
+            if self.frame != Some(animation.current()) || self.scaled.as_ref().is_none_or(|s| s.size != self.size) {
+                self.scaled = Some(animation.frame().scale(self.size, state.config.thumbnail_filter));
+                self.frame = Some(animation.current());
+            }
+            if let Some(scaled) = &self.scaled {
+                state.buffer.composite(scaled, self.pos.x, y, state.config.backdrop());
             }
+        } else if let Ok(buf) = arc.try_read()
+            && let Some(ref buf) = *buf
+            && buf.size != Vec2::zero()
+        {
+            if buf.size == self.size {
+                self.scaled = None;
+                state.buffer.composite(buf, self.pos.x, y, state.config.backdrop());
+            } else {
+                let scaled = match self.scaled.take() {
+                    Some(scaled) if scaled.size == self.size => scaled,
+                    _ => buf.scale(self.size, state.config.thumbnail_filter),
+                };
+                state.buffer.composite(&scaled, self.pos.x, y, state.config.backdrop());
+                self.scaled = Some(scaled);
+            }
+        }
+        if image.selected {
+            state.buffer.tint(self.pos.x as i32, y, self.size, state.config.primary_color, 0.3);
+        }
+        if self.hovered || image.selected {
+            state.buffer.outline(self.pos.x as i32, y, self.size, 3, state.config.primary_color);
+        }
+        Image::badges(state, &image.xmp, self.pos.x as i32, y, self.size);
+        if self.focused {
+            let size = self.size.add2(10, 10);
+            state.buffer.outline(self.pos.x as i32 - 5, y - 5, size, 1, state.config.text_color);
+        }
+        if state.config.captions {
+            state.buffer.text(
+                &image.name,
+                self.pos.x as i32,
+                y + self.size.y as i32 + 2,
+                self.size.x,
+                Align::Center,
+                state.config.text_color,
+            );
         }
 
         state.library.images.insert(self.index, image);
@@ -140,6 +1080,38 @@ impl Widget for Image {
 #[derive(Default)]
 struct Scrollbar {
     viewport: Vec2,
+    /// Where the thumb was grabbed, relative to its top.
+    dragging: Option<f32>,
+}
+
+impl Scrollbar {
+    /// Returns the top and length of the thumb, if there is anything to scroll.
+    fn thumb(&self, state: &State) -> Option<(f32, f32)> {
+        let viewport = self.viewport.y as f32 - 1.0;
+        let height = state.view.height as f32;
+        if height <= viewport {
+            return None;
+        }
+
+        let length = (viewport / height * viewport).max(10.0);
+
+        let max_scroll = (height - viewport).max(1.0);
+        let max_y = (viewport - length).max(0.0);
+
+        let y = ((state.view.scroll as f32 / max_scroll) * max_y).clamp(0.0, max_y);
+        Some((y, length))
+    }
+
+    fn drag(&self, state: &mut State, y: u32, offset: f32) {
+        let Some((_, length)) = self.thumb(state) else {
+            return;
+        };
+        let max_y = (self.viewport.y as f32 - 1.0 - length).max(1.0);
+        let max_scroll = state.view.height.saturating_sub(self.viewport.y) as f32;
+        let t = ((y as f32 - offset) / max_y).clamp(0.0, 1.0);
+        state.view.scroll = (t * max_scroll) as u32;
+        state.update();
+    }
 }
 
 impl Widget for Scrollbar {
@@ -153,20 +1125,36 @@ impl Widget for Scrollbar {
         self.viewport = state.buffer.size;
     }
 
-    fn draw(&mut self, state: &mut State) {
-        let viewport = self.viewport.y as f32 - 1.0;
-        let height = state.view.height as f32;
-        if height <= viewport {
-            return;
+    fn mouse(&mut self, state: &mut State, event: &Mouse) -> bool {
+        match *event {
+            Mouse::Down(pos, MouseButton::Left) => {
+                let Some((y, length)) = self.thumb(state) else {
+                    return false;
+                };
+                // Grabbing the track instead of the thumb centers the thumb on the mouse.
+                let offset = pos.y as f32 - y;
+                let offset = if (0.0..=length).contains(&offset) { offset } else { length / 2.0 };
+                self.dragging = Some(offset);
+                self.drag(state, pos.y, offset);
+                true
+            }
+            Mouse::Move(pos) => match self.dragging {
+                Some(offset) => {
+                    self.drag(state, pos.y, offset);
+                    true
+                }
+                None => false,
+            },
+            Mouse::Up(_, MouseButton::Left) => self.dragging.take().is_some(),
+            _ => false,
         }
+    }
 
+    fn draw(&mut self, state: &mut State) {
+        let Some((y, length)) = self.thumb(state) else {
+            return;
+        };
         let x = self.viewport.x - 5;
-        let length = (viewport / height * viewport).max(10.0);
-
-        let max_scroll = (height - viewport).max(1.0);
-        let max_y = (viewport - length).max(0.0);
-
-        let y = ((state.view.scroll as f32 / max_scroll) * max_y).clamp(0.0, max_y);
         state.buffer.line(
             x,
             y as u32,
@@ -177,3 +1165,45 @@ impl Widget for Scrollbar {
         );
     }
 }
+
+#[derive(Default)]
+struct StatusBar {
+    viewport: Vec2,
+}
+
+impl Widget for StatusBar {
+    fn pos(&self, _scroll: u32) -> Vec2 {
+        Vec2::from(0, self.viewport.y - STATUS_HEIGHT)
+    }
+
+    fn size(&self) -> Vec2 {
+        Vec2::from(self.viewport.x, STATUS_HEIGHT)
+    }
+
+    fn resize(&mut self, state: &mut State) {
+        self.viewport = state.buffer.size;
+    }
+
+    fn draw(&mut self, state: &mut State) {
+        let top = (self.viewport.y - STATUS_HEIGHT) as i32;
+        state.buffer.fill(0, top, self.size(), state.config.secondary_color);
+
+        let total = state.library.images.len();
+        let mut count = if state.view.filter.is_empty() {
+            format!("{total} images")
+        } else {
+            format!("{} of {total} images match \"{}\"", state.view.matches, state.view.filter.query)
+        };
+        let selected = state.library.images.iter().filter(|image| image.selected).count();
+        if selected > 0 {
+            count.push_str(&format!(", {selected} selected"));
+        }
+        let width = self.viewport.x - 20;
+        let y = top + ((STATUS_HEIGHT - LINE_HEIGHT) / 2) as i32;
+        state.buffer.text(&count, 10, y, width, Align::Right, state.config.text_color);
+        let status = std::mem::take(&mut state.view.status);
+        let left = width.saturating_sub(crate::text::measure(&count) + 20);
+        state.buffer.text(&status, 10, y, left, Align::Left, state.config.text_color);
+        state.view.status = status;
+    }
+}
@@ -0,0 +1,382 @@
+use crate::config;
+use crate::fileops;
+use crate::filter::Filter;
+use crate::gui::{Image, View, Widget, CAPTION_HEIGHT, STATUS_HEIGHT};
+use crate::input::Mouse;
+use crate::library::{Change, Hashes, Library};
+use crate::pool::{Priority, Token};
+use crate::similar;
+use crate::text::{Align, LINE_HEIGHT};
+use crate::{lock_state, util, State, Vec2};
+use minifb::{Key, MouseButton};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Resolution, file size and date under every thumbnail.
+const INFO_LINES: u32 = 3;
+const GAP: u32 = 20;
+
+/// Where grouping on the pool puts the groups, by path since the library can change in the meantime, along with
+/// the generation they were asked for in.
+type Groups = Arc<Mutex<Option<(u64, Vec<Vec<PathBuf>>)>>>;
+
+/// Groups of images that look alike, side by side, to pick which copies to keep.
+#[derive(Default)]
+pub struct Duplicates {
+    viewport: Vec2,
+    /// The largest image of each group comes first.
+    groups: Vec<Vec<Image>>,
+    /// The group and the position in it of the image that keys act on.
+    focus: Option<(usize, usize)>,
+    /// Cancels hashing when the view is closed, `None` until hashing started.
+    token: Option<Token>,
+    results: Hashes,
+    /// How many images are still being hashed.
+    pending: usize,
+    grouped: Groups,
+    /// Counts the times grouping was started, so only the groups for the latest threshold are shown.
+    generation: u64,
+    grouping: bool,
+}
+
+/// `2.4 MiB` or `640 KiB`.
+fn file_size(len: u64) -> String {
+    let kib = len as f64 / 1024.0;
+    if kib < 1024.0 { format!("{kib:.0} KiB") } else { format!("{:.1} MiB", kib / 1024.0) }
+}
+
+/// `YYYYMMDDhhmmss` as `YYYY-MM-DD hh:mm`.
+fn date(digits: u64) -> String {
+    let d = format!("{digits:014}");
+    format!("{}-{}-{} {}:{}", &d[..4], &d[4..6], &d[6..8], &d[8..10], &d[10..12])
+}
+
+impl Duplicates {
+    /// Groups the hashed images again on the pool, comparing every pair takes too long for the UI thread once
+    /// there are thousands. [`Duplicates::grouped`] shows the result.
+    fn regroup(&mut self, state: &mut State) {
+        let (paths, hashes): (Vec<PathBuf>, Vec<(usize, u64)>) = state
+            .library
+            .images
+            .iter()
+            .filter_map(|image| Some((image.path.clone(), image.hash?)))
+            .enumerate()
+            .map(|(i, (path, hash))| (path, (i, hash)))
+            .unzip();
+        self.generation += 1;
+        self.grouping = true;
+        let (generation, grouped) = (self.generation, self.grouped.clone());
+        let threshold = state.config.duplicate_threshold;
+        state.thread_pool.submit(Priority::High, self.token.clone(), move || {
+            let groups = similar::groups(&hashes, threshold);
+            let groups = groups.into_iter().map(|group| group.into_iter().map(|i| paths[i].clone()).collect());
+            *grouped.lock().unwrap() = Some((generation, groups.collect()));
+            lock_state().update();
+        });
+        self.status(state);
+    }
+
+    /// Shows the groups from the last [`Duplicates::regroup`] once they are there, keeping the focus on the same
+    /// image if it is still in a group.
+    fn grouped(&mut self, state: &mut State) {
+        let Some((generation, groups)) = self.grouped.lock().unwrap().take() else {
+            return;
+        };
+        if generation != self.generation {
+            return;
+        }
+        let focused = self.focused();
+        let images = &state.library.images;
+        let indices: HashMap<&Path, usize> =
+            images.iter().enumerate().map(|(i, image)| (image.path.as_path(), i)).collect();
+        let mut groups: Vec<Vec<usize>> = groups
+            .iter()
+            .map(|group| group.iter().filter_map(|path| indices.get(path.as_path()).copied()).collect())
+            .filter(|group: &Vec<usize>| group.len() > 1)
+            .collect();
+        groups.sort_by_key(|group| group.iter().min().copied());
+        for group in groups.iter_mut() {
+            group.sort_by_key(|&i| {
+                let size = images[i].dimensions;
+                (std::cmp::Reverse(size.x as u64 * size.y as u64), std::cmp::Reverse(images[i].len), i)
+            });
+        }
+        self.groups = groups.into_iter().map(|group| group.into_iter().map(Image::new).collect()).collect();
+        self.focus = focused.and_then(|index| self.position(index));
+        self.grouping = false;
+        self.relayout(state);
+        self.status(state);
+        state.update();
+    }
+
+    fn status(&self, state: &mut State) {
+        let threshold = state.config.duplicate_threshold;
+        state.view.status = if self.pending > 0 {
+            format!("Looking for similar images, {} left to hash", self.pending)
+        } else if self.grouping {
+            format!("Grouping images within {threshold} bits of each other")
+        } else if self.groups.is_empty() {
+            format!("No images within {threshold} bits of each other, +/- to change")
+        } else {
+            format!(
+                "{} groups within {threshold} bits, +/- to change. K keeps the focused image and trashes the rest of its group",
+                self.groups.len()
+            )
+        };
+    }
+
+    /// The library index of the focused image.
+    fn focused(&self) -> Option<usize> {
+        self.focus.map(|(g, i)| self.groups[g][i].index)
+    }
+
+    fn position(&self, index: usize) -> Option<(usize, usize)> {
+        self.groups.iter().enumerate().find_map(|(g, group)| Some((g, group.iter().position(|t| t.index == index)?)))
+    }
+
+    /// Puts every group in rows of its own, wrapping when it doesn't fit the window. Returns the bottom.
+    fn layout(&mut self, state: &State) -> u32 {
+        let height = state.library.thumbnail_size.min(300);
+        let below = if state.config.captions { CAPTION_HEIGHT } else { 0 } + INFO_LINES * (LINE_HEIGHT + 2);
+        let mut y = GAP;
+        for group in self.groups.iter_mut() {
+            let mut x = GAP;
+            for tile in group.iter_mut() {
+                let thumbnail = state.library.images[tile.index].size;
+                let width = (thumbnail.x * height / thumbnail.y.max(1)).clamp(1, height * 2);
+                if x > GAP && x + width + GAP > self.viewport.x {
+                    x = GAP;
+                    y += height + below + GAP;
+                }
+                tile.pos = Vec2::from(x, y);
+                tile.size = Vec2::from(width, height);
+                x += width + GAP;
+            }
+            y += height + below + GAP * 2;
+        }
+        y
+    }
+
+    fn relayout(&mut self, state: &mut State) {
+        state.view.height = self.layout(state) + STATUS_HEIGHT;
+        state.view.scroll = state.view.scroll.min(state.view.height.saturating_sub(self.viewport.y));
+    }
+
+    fn tile_at(&self, scroll: u32, pos: Vec2) -> Option<(usize, usize)> {
+        let pos = pos.add2(0, scroll);
+        self.groups.iter().enumerate().find_map(|(g, group)| {
+            let i = group.iter().position(|t| super::contains(t.pos, t.size, pos))?;
+            Some((g, i))
+        })
+    }
+
+    /// Moves the focus and scrolls so the focused image and its details are on screen.
+    fn move_focus(&mut self, state: &mut State, focus: (usize, usize)) {
+        self.focus = Some(focus);
+        let tile = &self.groups[focus.0][focus.1];
+        let below = if state.config.captions { CAPTION_HEIGHT } else { 0 } + INFO_LINES * (LINE_HEIGHT + 2);
+        let visible = self.viewport.y.saturating_sub(STATUS_HEIGHT);
+        let max = state.view.height.saturating_sub(self.viewport.y);
+        if tile.pos.y < state.view.scroll + GAP {
+            state.view.scroll = tile.pos.y.saturating_sub(GAP);
+        } else if tile.pos.y + tile.size.y + below + GAP > state.view.scroll + visible {
+            state.view.scroll = (tile.pos.y + tile.size.y + below + GAP).saturating_sub(visible).min(max);
+        }
+    }
+
+    fn trash(state: &mut State, paths: Vec<PathBuf>) {
+        if !paths.is_empty() {
+            state.view.defer(move |state| fileops::apply(state, paths, ("trash", "Trashed"), fileops::trash));
+        }
+    }
+
+    /// Puts the hashes that were computed since the last frame in the library.
+    fn collect(&mut self, state: &mut State) {
+        let results = std::mem::take(&mut *self.results.lock().unwrap());
+        if results.is_empty() {
+            return;
+        }
+        let indices: HashMap<&Path, usize> =
+            state.library.images.iter().enumerate().map(|(i, image)| (image.path.as_path(), i)).collect();
+        let hashes: Vec<(usize, u64)> = results
+            .iter()
+            .filter_map(|(path, hash)| Some((*indices.get(path.as_path())?, (*hash)?)))
+            .collect();
+        for (index, hash) in hashes {
+            state.library.set_hash(index, hash);
+        }
+        self.pending = self.pending.saturating_sub(results.len());
+        if self.pending == 0 {
+            state.library.save_index();
+            self.regroup(state);
+            self.relayout(state);
+        }
+        self.status(state);
+        state.update();
+    }
+}
+
+impl Drop for Duplicates {
+    fn drop(&mut self) {
+        if let Some(token) = &self.token {
+            token.cancel();
+        }
+    }
+}
+
+impl Widget for Duplicates {
+    fn pos(&self, _scroll: u32) -> Vec2 {
+        Vec2::zero()
+    }
+
+    fn size(&self) -> Vec2 {
+        Vec2::from(self.viewport.x, self.viewport.y.saturating_sub(STATUS_HEIGHT))
+    }
+
+    fn resize(&mut self, state: &mut State) {
+        self.viewport = state.buffer.size;
+        if self.token.is_none() {
+            let token = Token::default();
+            self.pending = Library::hash_missing(state, &token, &self.results);
+            self.token = Some(token);
+            self.regroup(state);
+        }
+        self.relayout(state);
+    }
+
+    fn changed(&mut self, state: &mut State, change: &Change) {
+        let focused = self.focused();
+        match *change {
+            Change::Added(index) => {
+                for tile in self.groups.iter_mut().flatten().filter(|t| t.index >= index) {
+                    tile.index += 1;
+                }
+            }
+            Change::Removed(index) => {
+                for group in self.groups.iter_mut() {
+                    group.retain(|t| t.index != index);
+                    for tile in group.iter_mut().filter(|t| t.index > index) {
+                        tile.index -= 1;
+                    }
+                }
+                self.groups.retain(|group| group.len() > 1);
+            }
+            Change::Modified(_) => return,
+        }
+        let focused = focused.and_then(|i| match *change {
+            Change::Added(index) if index <= i => Some(i + 1),
+            Change::Removed(index) if index == i => None,
+            Change::Removed(index) if index < i => Some(i - 1),
+            _ => Some(i),
+        });
+        self.focus = focused.and_then(|index| self.position(index));
+        self.status(state);
+    }
+
+    fn sorted(&mut self, _state: &mut State, moved: &[usize]) {
+        for tile in self.groups.iter_mut().flatten() {
+            tile.index = moved[tile.index];
+        }
+    }
+
+    fn key(&mut self, state: &mut State, key: Key) -> bool {
+        match key {
+            Key::Escape => state.view.back(),
+            Key::Left | Key::Right | Key::Up | Key::Down if !self.groups.is_empty() => {
+                let (g, i) = self.focus.unwrap_or((0, 0));
+                let focus = match key {
+                    _ if self.focus.is_none() => (0, 0),
+                    Key::Left => (g, i.saturating_sub(1)),
+                    Key::Right => (g, (i + 1).min(self.groups[g].len() - 1)),
+                    Key::Up => (g.saturating_sub(1), i),
+                    _ => ((g + 1).min(self.groups.len() - 1), i),
+                };
+                let focus = (focus.0, focus.1.min(self.groups[focus.0].len() - 1));
+                self.move_focus(state, focus);
+            }
+            Key::Enter => {
+                if let Some(index) = self.focused() {
+                    state.view.open(View::viewer(index, Filter::default()));
+                }
+            }
+            Key::Delete => {
+                let paths = self.focused().map(|i| state.library.images[i].path.clone());
+                Duplicates::trash(state, paths.into_iter().collect());
+            }
+            Key::K => {
+                if let Some((g, i)) = self.focus {
+                    let group = &self.groups[g];
+                    let paths = (0..group.len()).filter(|&j| j != i).map(|j| state.library.images[group[j].index].path.clone());
+                    Duplicates::trash(state, paths.collect());
+                }
+            }
+            Key::Equal | Key::NumPadPlus | Key::Minus | Key::NumPadMinus => {
+                let threshold = state.config.duplicate_threshold;
+                let threshold = match key {
+                    Key::Equal | Key::NumPadPlus => (threshold + 1).min(32),
+                    _ => threshold.saturating_sub(1),
+                };
+                config::edit(state, |config| config.duplicate_threshold = threshold);
+                self.regroup(state);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn mouse(&mut self, state: &mut State, event: &Mouse) -> bool {
+        let scroll = state.view.scroll;
+        match *event {
+            Mouse::Down(pos, MouseButton::Left) => {
+                self.focus = self.tile_at(scroll, pos);
+                true
+            }
+            Mouse::DoubleClick(pos) => match self.tile_at(scroll, pos) {
+                Some((g, i)) => {
+                    state.view.open(View::viewer(self.groups[g][i].index, Filter::default()));
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn tick(&mut self, state: &mut State, _elapsed: std::time::Duration) {
+        self.collect(state);
+        self.grouped(state);
+    }
+
+    fn draw(&mut self, state: &mut State) {
+        state.library.frame += 1;
+        let caption = if state.config.captions { CAPTION_HEIGHT } else { 0 };
+        let below = caption + INFO_LINES * (LINE_HEIGHT + 2);
+        let scroll = state.view.scroll as i32;
+        let focused = self.focused();
+        for tile in self.groups.iter_mut().flatten() {
+            let y = tile.pos.y as i32 - scroll;
+            if y + (tile.size.y + below) as i32 <= 0 || y >= self.viewport.y as i32 {
+                tile.scaled = None;
+                continue;
+            }
+            tile.focused = focused == Some(tile.index);
+            tile.draw(state);
+
+            let image = &state.library.images[tile.index];
+            let taken = image.exif.taken.unwrap_or_else(|| util::date((image.modified / 1000) as i64));
+            let lines = [
+                format!("{}×{}", image.dimensions.x, image.dimensions.y),
+                file_size(image.len),
+                date(taken),
+            ];
+            let mut line_y = y + (tile.size.y + caption) as i32 + 2;
+            for line in lines {
+                state.buffer.text(&line, tile.pos.x as i32, line_y, tile.size.x, Align::Center, state.config.text_color);
+                line_y += (LINE_HEIGHT + 2) as i32;
+            }
+        }
+        state.library.queue.cancel_stale(state.library.frame);
+        state.library.evict(state.config.memory_budget as usize * 1024 * 1024);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::config::{self, Source, Value};
+use crate::fileops;
+use crate::gui::{View, STATUS_HEIGHT};
+use crate::library::Library;
+use crate::text::{self, Align, LINE_HEIGHT};
+use crate::{State, Vec2};
+use minifb::Key;
+use std::path::PathBuf;
+
+/// What happens with the text when Enter is pressed.
+pub enum Action {
+    Rename(PathBuf),
+    Move(Vec<PathBuf>),
+    Copy(Vec<PathBuf>),
+    /// Filters the gallery while typing, Escape clears the filter again.
+    Filter,
+    /// Sets one of the colors of the config.
+    Color(&'static str),
+    AddLibrary,
+}
+
+/// A line of text input that replaces the status bar until Enter or Escape is pressed.
+pub struct Prompt {
+    label: String,
+    text: String,
+    /// Tab cycles through these.
+    choices: Vec<String>,
+    action: Action,
+}
+
+impl Prompt {
+    pub fn new(label: &str, text: String, choices: Vec<String>, action: Action) -> Prompt {
+        Prompt { label: label.to_string(), text, choices, action }
+    }
+
+    /// Returns `None` once the prompt is done.
+    pub fn key(mut self, state: &mut State, key: Key) -> Option<Prompt> {
+        match key {
+            Key::Escape => {
+                if let Action::Filter = self.action {
+                    View::filter(state, "");
+                }
+                return None;
+            }
+            Key::Enter | Key::NumPadEnter => {
+                self.submit(state);
+                return None;
+            }
+            Key::Backspace if state.modifiers.ctrl => self.text.clear(),
+            Key::Backspace => {
+                self.text.pop();
+            }
+            Key::Tab if !self.choices.is_empty() => {
+                let next = self.choices.iter().position(|c| *c == self.text).map_or(0, |i| i + 1);
+                self.text = self.choices[next % self.choices.len()].clone();
+            }
+            _ => {}
+        }
+        self.edited(state);
+        Some(self)
+    }
+
+    pub fn char(&mut self, state: &mut State, c: char) {
+        if !c.is_control() {
+            self.text.push(c);
+            self.edited(state);
+        }
+    }
+
+    fn edited(&self, state: &mut State) {
+        if let Action::Filter = self.action {
+            View::filter(state, &self.text);
+        }
+    }
+
+    fn submit(self, state: &mut State) {
+        let text = self.text;
+        match self.action {
+            Action::Rename(path) => {
+                fileops::apply(state, vec![path], ("rename", "Renamed"), |path| fileops::rename(path, &text));
+            }
+            Action::Move(paths) => {
+                let dir = PathBuf::from(&text);
+                fileops::apply(state, paths, ("move", "Moved"), |path| fileops::transfer(path, &dir, false));
+            }
+            Action::Copy(paths) => {
+                let dir = PathBuf::from(&text);
+                fileops::apply(state, paths, ("copy", "Copied"), |path| fileops::transfer(path, &dir, true));
+            }
+            Action::Filter => View::filter(state, &text),
+            Action::Color(key) => {
+                let value = Value::Text(text);
+                match state.config.clone().set(key, &value) {
+                    Ok(()) => config::edit(state, |config| {
+                        let _ = config.set(key, &value);
+                    }),
+                    Err(err) => state.view.status = err,
+                }
+            }
+            Action::AddLibrary => {
+                let path = text.trim().to_string();
+                if !Library::resolve(PathBuf::from(&path)).is_dir() {
+                    state.view.status = format!("There is no folder at {path}");
+                } else if state.config.libraries.iter().any(|library| library.path == path) {
+                    state.view.status = format!("{path} already is a library");
+                } else {
+                    config::edit(state, |config| config.libraries.push(Source::new(&path)));
+                    state.view.status = format!("Added {path} to the libraries");
+                }
+            }
+        }
+    }
+
+    pub fn draw(&self, state: &mut State) {
+        let size = state.buffer.size;
+        let top = size.y.saturating_sub(STATUS_HEIGHT) as i32;
+        state.buffer.fill(0, top, Vec2::from(size.x, STATUS_HEIGHT), state.config.secondary_color);
+        let y = top + ((STATUS_HEIGHT - LINE_HEIGHT) / 2) as i32;
+        let label = format!("{} ", self.label);
+        let width = text::measure(&label);
+        state.buffer.text(&label, 10, y, width, Align::Left, state.config.primary_color);
+
+        // A filter shows what it matches as it is typed, or why it can't.
+        let mut available = size.x.saturating_sub(width + 20);
+        if let Action::Filter = self.action {
+            let filter = &state.view.filter;
+            let hint = match &filter.error {
+                Some(error) => error.clone(),
+                None => format!("{} of {} images", state.view.matches, state.library.images.len()),
+            };
+            let hint_width = text::measure(&hint);
+            state.buffer.text(&hint, 10, y, size.x.saturating_sub(20), Align::Right, state.config.text_color);
+            available = available.saturating_sub(hint_width + 20);
+        }
+
+        // Keep the end of the text visible, that's where the typing happens.
+        let mut input = format!("{}_", self.text);
+        while text::measure(&input) > available && input.len() > 1 {
+            input.remove(0);
+        }
+        state.buffer.text(&input, 10 + width as i32, y, available, Align::Left, state.config.text_color);
+    }
+}
@@ -0,0 +1,461 @@
+use crate::buffer::Kernel;
+use crate::config::{self, Config, Layout, Value};
+use crate::gui::{Action, Prompt, Widget, STATUS_HEIGHT};
+use crate::input::Mouse;
+use crate::library::Library;
+use crate::text::{Align, LINE_HEIGHT};
+use crate::{State, Vec2};
+use minifb::{Key, MouseButton};
+use std::fs;
+use std::path::PathBuf;
+
+const ROW_HEIGHT: u32 = LINE_HEIGHT + 14;
+const MARGIN: u32 = 20;
+/// Where the controls start, right of the labels.
+const LABEL_WIDTH: u32 = 220;
+const CONTROL_WIDTH: u32 = 360;
+const COLORS: [&str; 4] = ["background_color", "text_color", "primary_color", "secondary_color"];
+/// The byte of `0xAARRGGBB` every slider of a color picker changes.
+const CHANNELS: [(&str, u32); 4] = [("Alpha", 24), ("Red", 16), ("Green", 8), ("Blue", 0)];
+
+#[derive(Copy, Clone, PartialEq)]
+enum Row {
+    Heading(&'static str),
+    /// A swatch that opens its picker, and the hex code that can be typed in.
+    Color(&'static str),
+    /// One slider of the open color picker.
+    Channel(&'static str, u32),
+    Library(usize),
+    AddLibrary,
+    Toggle(&'static str),
+    /// Clicking the left half goes to the previous option, the right half to the next.
+    Choice(&'static str),
+    Slider(&'static str, u32, u32),
+}
+
+/// Every option of the config, applied as soon as it is changed and saved to the config file.
+#[derive(Default)]
+pub struct Settings {
+    viewport: Vec2,
+    rows: Vec<Row>,
+    /// The color whose picker is open.
+    open: Option<&'static str>,
+    focus: Option<usize>,
+    /// The slider being dragged and its value, which is only applied once it is let go.
+    dragging: Option<(usize, u32)>,
+}
+
+fn label(key: &str) -> &'static str {
+    match key {
+        "background_color" => "Background",
+        "text_color" => "Text",
+        "primary_color" => "Primary",
+        "secondary_color" => "Secondary",
+        "thumbnail_size" => "Thumbnail size",
+        "layout" => "Layout",
+        "captions" => "Captions",
+        "animate_on_hover" => "Animate on hover",
+        "thumbnail_filter" => "Thumbnail filter",
+        "viewer_filter" => "Viewer filter",
+        "checkerboard" => "Checkerboard behind transparency",
+        "slideshow_interval" => "Slideshow seconds",
+        "pico" => "Pico mode",
+        "memory_budget" => "Thumbnail memory in MiB",
+        "prefetch_screens" => "Screens to load ahead",
+        "duplicate_threshold" => "Duplicate threshold in bits",
+        _ => "",
+    }
+}
+
+fn choices(key: &str) -> Vec<&'static str> {
+    match key {
+        "layout" => Layout::ALL.map(Layout::name).to_vec(),
+        "thumbnail_filter" | "viewer_filter" => Kernel::ALL.map(Kernel::name).to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+fn color(config: &Config, key: &str) -> u32 {
+    match config.get(key) {
+        Some(Value::Text(hex)) => u32::from_str_radix(hex.trim_start_matches('#'), 16).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn number(config: &Config, key: &str) -> u32 {
+    match config.get(key) {
+        Some(Value::Int(n)) => n as u32,
+        _ => 0,
+    }
+}
+
+/// Changes one setting once the current event has been handled, so the views can be updated. A value the config
+/// doesn't take is shown in the status bar instead.
+fn set(state: &mut State, key: &'static str, value: Value) {
+    state.view.defer(move |state| match state.config.clone().set(key, &value) {
+        Ok(()) => config::edit(state, |config| {
+            let _ = config.set(key, &value);
+        }),
+        Err(err) => state.view.status = err,
+    });
+}
+
+impl Settings {
+    fn build(&mut self, state: &State) {
+        let mut rows = vec![Row::Heading("Colors")];
+        for key in COLORS {
+            rows.push(Row::Color(key));
+            if self.open == Some(key) {
+                rows.extend(CHANNELS.map(|(_, shift)| Row::Channel(key, shift)));
+            }
+        }
+        rows.push(Row::Heading("Libraries"));
+        rows.extend((0..state.config.libraries.len()).map(Row::Library));
+        rows.push(Row::AddLibrary);
+        rows.extend([
+            Row::Heading("Gallery"),
+            Row::Slider("thumbnail_size", 50, 2000),
+            Row::Choice("layout"),
+            Row::Toggle("captions"),
+            Row::Toggle("animate_on_hover"),
+            Row::Choice("thumbnail_filter"),
+            Row::Heading("Viewer"),
+            Row::Choice("viewer_filter"),
+            Row::Toggle("checkerboard"),
+            Row::Slider("slideshow_interval", 1, 60),
+            Row::Heading("Performance"),
+            Row::Toggle("pico"),
+            Row::Slider("memory_budget", 16, 4096),
+            Row::Slider("prefetch_screens", 0, 20),
+            Row::Slider("duplicate_threshold", 0, 32),
+        ]);
+        self.rows = rows;
+        self.focus = self.focus.filter(|&i| i < self.rows.len());
+    }
+
+    fn top(&self, i: usize) -> u32 {
+        MARGIN + i as u32 * ROW_HEIGHT
+    }
+
+    fn row_at(&self, scroll: u32, pos: Vec2) -> Option<usize> {
+        let y = (pos.y + scroll).checked_sub(MARGIN)?;
+        let i = (y / ROW_HEIGHT) as usize;
+        (i < self.rows.len()).then_some(i)
+    }
+
+    fn control_x(&self) -> u32 {
+        MARGIN + LABEL_WIDTH
+    }
+
+    fn control_width(&self) -> u32 {
+        CONTROL_WIDTH.min(self.viewport.x.saturating_sub(self.control_x() + MARGIN + 80))
+    }
+
+    /// The value of a slider, while it is being dragged as well.
+    fn slider_value(&self, state: &State, i: usize) -> u32 {
+        if let Some((row, value)) = self.dragging
+            && row == i
+        {
+            return value;
+        }
+        match self.rows[i] {
+            Row::Slider(key, min, max) => number(&state.config, key).clamp(min, max),
+            Row::Channel(key, shift) => (color(&state.config, key) >> shift) & 0xFF,
+            _ => 0,
+        }
+    }
+
+    fn range(row: Row) -> Option<(u32, u32)> {
+        match row {
+            Row::Slider(_, min, max) => Some((min, max)),
+            Row::Channel(..) => Some((0, 255)),
+            _ => None,
+        }
+    }
+
+    /// The value of a slider with the mouse at `x`.
+    fn value_at(&self, row: Row, x: u32) -> u32 {
+        let (min, max) = Settings::range(row).unwrap_or_default();
+        let t = (x as f32 - self.control_x() as f32) / self.control_width().max(1) as f32;
+        min + ((max - min) as f32 * t.clamp(0.0, 1.0)).round() as u32
+    }
+
+    fn commit(&self, state: &mut State, row: Row, value: u32) {
+        match row {
+            Row::Slider(key, ..) => set(state, key, Value::Int(value as i64)),
+            Row::Channel(key, shift) => {
+                let color = (color(&state.config, key) & !(0xFF << shift)) | (value << shift);
+                set(state, key, Value::Text(format!("#{color:08X}")));
+            }
+            _ => {}
+        }
+    }
+
+    /// Does what clicking the row does, `forward` is false for the left half of a choice.
+    fn activate(&mut self, state: &mut State, i: usize, forward: bool) {
+        match self.rows[i] {
+            Row::Color(key) => {
+                self.open = if self.open == Some(key) { None } else { Some(key) };
+                self.resize(state);
+            }
+            Row::Library(index) => {
+                let path = state.config.libraries[index].path.clone();
+                state.view.status = format!("Removed {path} from the libraries, its files stay where they are");
+                state.view.defer(move |state| {
+                    config::edit(state, |config| config.libraries.retain(|library| library.path != path))
+                });
+            }
+            Row::AddLibrary => {
+                // Relative paths start in the home folder, so offer the folders there.
+                let home = Library::resolve(PathBuf::new());
+                let mut folders: Vec<String> = fs::read_dir(home)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+                    .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                    .filter(|name| !name.starts_with('.'))
+                    .collect();
+                folders.sort();
+                let label = "Add library (Tab for folders):";
+                state.view.prompt = Some(Prompt::new(label, String::new(), folders, Action::AddLibrary));
+            }
+            Row::Toggle(key) => {
+                let on = state.config.get(key) == Some(Value::Bool(true));
+                set(state, key, Value::Bool(!on));
+            }
+            Row::Choice(key) => {
+                let choices = choices(key);
+                let current = match state.config.get(key) {
+                    Some(Value::Text(name)) => choices.iter().position(|c| *c == name).unwrap_or(0),
+                    _ => 0,
+                };
+                let next = if forward { current + 1 } else { current + choices.len() - 1 };
+                set(state, key, Value::Text(choices[next % choices.len()].to_string()));
+            }
+            Row::Heading(_) | Row::Channel(..) | Row::Slider(..) => {}
+        }
+    }
+
+    /// Moves a slider by one step, or a choice by one option.
+    fn step(&mut self, state: &mut State, i: usize, forward: bool) {
+        let row = self.rows[i];
+        match Settings::range(row) {
+            Some((min, max)) => {
+                let step = ((max - min) / 100).max(1);
+                let value = self.slider_value(state, i);
+                let value = if forward { (value + step).min(max) } else { value.saturating_sub(step).max(min) };
+                self.commit(state, row, value);
+            }
+            None if matches!(row, Row::Choice(_)) => self.activate(state, i, forward),
+            None => {}
+        }
+    }
+
+    /// Keeps the focused row on screen.
+    fn move_focus(&mut self, state: &mut State, i: usize) {
+        self.focus = Some(i);
+        let visible = self.viewport.y.saturating_sub(STATUS_HEIGHT);
+        let top = self.top(i);
+        if top < state.view.scroll + MARGIN {
+            state.view.scroll = top.saturating_sub(MARGIN);
+        } else if top + ROW_HEIGHT + MARGIN > state.view.scroll + visible {
+            state.view.scroll = (top + ROW_HEIGHT + MARGIN).saturating_sub(visible);
+        }
+    }
+
+    fn draw_slider(&self, state: &mut State, i: usize, y: i32) {
+        let (min, max) = Settings::range(self.rows[i]).unwrap_or_default();
+        let value = self.slider_value(state, i);
+        let (x, width) = (self.control_x(), self.control_width());
+        let filled = ((value - min) as f32 / (max - min).max(1) as f32 * width as f32) as u32;
+        let track = y + (ROW_HEIGHT / 2) as i32 - 2;
+        state.buffer.fill(x as i32, track, Vec2::from(width, 4), state.config.secondary_color);
+        state.buffer.fill(x as i32, track, Vec2::from(filled, 4), state.config.primary_color);
+        state.buffer.fill((x + filled) as i32 - 4, track - 6, Vec2::from(8, 16), state.config.primary_color);
+        let text_y = y + ((ROW_HEIGHT - LINE_HEIGHT) / 2) as i32;
+        let value_x = (x + width + 16) as i32;
+        state.buffer.text(&value.to_string(), value_x, text_y, 80, Align::Left, state.config.text_color);
+    }
+}
+
+impl Widget for Settings {
+    fn pos(&self, _scroll: u32) -> Vec2 {
+        Vec2::zero()
+    }
+
+    fn size(&self) -> Vec2 {
+        Vec2::from(self.viewport.x, self.viewport.y.saturating_sub(STATUS_HEIGHT))
+    }
+
+    fn resize(&mut self, state: &mut State) {
+        self.viewport = state.buffer.size;
+        self.build(state);
+        state.view.height = self.top(self.rows.len()) + MARGIN + STATUS_HEIGHT;
+        state.view.scroll = state.view.scroll.min(state.view.height.saturating_sub(self.viewport.y));
+        if !state.args.paths.is_empty() && state.view.status.is_empty() {
+            state.view.status = "Libraries are saved, but the paths on the command line are shown instead".to_string();
+        }
+    }
+
+    fn key(&mut self, state: &mut State, key: Key) -> bool {
+        let interactive = |row: &Row| !matches!(row, Row::Heading(_));
+        match key {
+            Key::Escape => state.view.back(),
+            Key::Up | Key::Down => {
+                let positions: Vec<usize> = (0..self.rows.len()).filter(|&i| interactive(&self.rows[i])).collect();
+                let current = self.focus.and_then(|f| positions.iter().position(|&i| i == f));
+                let next = match (key, current) {
+                    (_, None) => 0,
+                    (Key::Up, Some(c)) => c.saturating_sub(1),
+                    (_, Some(c)) => (c + 1).min(positions.len() - 1),
+                };
+                if let Some(&i) = positions.get(next) {
+                    self.move_focus(state, i);
+                }
+            }
+            Key::Left | Key::Right => {
+                if let Some(i) = self.focus {
+                    self.step(state, i, key == Key::Right);
+                }
+            }
+            Key::Enter | Key::Space => {
+                if let Some(i) = self.focus {
+                    self.activate(state, i, true);
+                }
+            }
+            Key::Delete => {
+                if let Some(i) = self.focus.filter(|&i| matches!(self.rows[i], Row::Library(_))) {
+                    self.activate(state, i, true);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn mouse(&mut self, state: &mut State, event: &Mouse) -> bool {
+        let scroll = state.view.scroll;
+        match *event {
+            Mouse::Down(pos, MouseButton::Left) => {
+                let Some(i) = self.row_at(scroll, pos) else {
+                    return false;
+                };
+                self.focus = Some(i).filter(|&i| !matches!(self.rows[i], Row::Heading(_)));
+                let row = self.rows[i];
+                let control = pos.x >= self.control_x();
+                if Settings::range(row).is_some() {
+                    if control {
+                        self.dragging = Some((i, self.value_at(row, pos.x)));
+                    }
+                } else if let Row::Color(key) = row
+                    && pos.x >= self.control_x() + 60
+                {
+                    let hex = format!("#{:08X}", color(&state.config, key));
+                    let label = "Color as #RRGGBB or #AARRGGBB:";
+                    state.view.prompt = Some(Prompt::new(label, hex, Vec::new(), Action::Color(key)));
+                } else if let Row::Library(_) = row {
+                    // Only the remove button at the end of the row removes.
+                    if pos.x >= self.control_x() + self.control_width() + 16 {
+                        self.activate(state, i, true);
+                    }
+                } else if control || matches!(row, Row::Toggle(_) | Row::AddLibrary) {
+                    let middle = self.control_x() + self.control_width() / 2;
+                    self.activate(state, i, !matches!(row, Row::Choice(_)) || pos.x >= middle);
+                }
+                true
+            }
+            Mouse::Move(pos) => match self.dragging {
+                Some((i, _)) => {
+                    self.dragging = Some((i, self.value_at(self.rows[i], pos.x)));
+                    true
+                }
+                None => false,
+            },
+            Mouse::Up(_, MouseButton::Left) => match self.dragging.take() {
+                Some((i, value)) => {
+                    self.commit(state, self.rows[i], value);
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn draw(&mut self, state: &mut State) {
+        let scroll = state.view.scroll as i32;
+        let (x, width) = (self.control_x() as i32, self.control_width());
+        for i in 0..self.rows.len() {
+            let y = self.top(i) as i32 - scroll;
+            if y + ROW_HEIGHT as i32 <= 0 || y >= self.viewport.y as i32 {
+                continue;
+            }
+            let text_y = y + ((ROW_HEIGHT - LINE_HEIGHT) / 2) as i32;
+            let text_color = state.config.text_color;
+            if self.focus == Some(i) {
+                let size = Vec2::from(self.viewport.x.saturating_sub(MARGIN * 2), ROW_HEIGHT);
+                state.buffer.tint(MARGIN as i32 - 8, y, size, state.config.secondary_color, 0.5);
+            }
+            let name = match self.rows[i] {
+                Row::Heading(title) => title.to_string(),
+                Row::Channel(_, shift) => format!("    {}", CHANNELS.iter().find(|c| c.1 == shift).map_or("", |c| c.0)),
+                Row::Library(index) => format!("Library {}", index + 1),
+                Row::AddLibrary => String::new(),
+                Row::Color(key) | Row::Toggle(key) | Row::Choice(key) | Row::Slider(key, ..) => label(key).to_string(),
+            };
+            let label_color = if let Row::Heading(_) = self.rows[i] { state.config.primary_color } else { text_color };
+            state.buffer.text(&name, MARGIN as i32, text_y, LABEL_WIDTH - 10, Align::Left, label_color);
+
+            match self.rows[i] {
+                Row::Heading(_) => {
+                    let line_y = y + ROW_HEIGHT as i32 - 4;
+                    let size = Vec2::from(self.viewport.x.saturating_sub(MARGIN * 2), 1);
+                    state.buffer.fill(MARGIN as i32, line_y, size, state.config.secondary_color);
+                }
+                Row::Color(key) => {
+                    let color = color(&state.config, key);
+                    let swatch = Vec2::from(44, ROW_HEIGHT - 8);
+                    // Swatches are drawn opaque, the hex code shows the alpha.
+                    state.buffer.fill(x, y + 4, swatch, color | 0xFF000000);
+                    state.buffer.outline(x, y + 4, swatch, 1, state.config.secondary_color);
+                    let hint = if self.open == Some(key) { "" } else { "  click the swatch to pick" };
+                    state.buffer.text(&format!("#{color:08X}{hint}"), x + 60, text_y, width, Align::Left, text_color);
+                }
+                Row::Channel(..) | Row::Slider(..) => self.draw_slider(state, i, y),
+                Row::Library(index) => {
+                    let library = &state.config.libraries[index];
+                    let mut path = library.path.clone();
+                    if !library.watch {
+                        path.push_str(" (not watched)");
+                    }
+                    state.buffer.text(&path, x, text_y, width, Align::Left, text_color);
+                    let remove_x = x + width as i32 + 16;
+                    state.buffer.text("Remove", remove_x, text_y, 80, Align::Left, state.config.primary_color);
+                }
+                Row::AddLibrary => {
+                    state.buffer.text("+ Add a folder", x, text_y, width, Align::Left, state.config.primary_color);
+                }
+                Row::Toggle(key) => {
+                    let on = state.config.get(key) == Some(Value::Bool(true));
+                    let size = Vec2::from(16, 16);
+                    let box_y = y + (ROW_HEIGHT as i32 - 16) / 2;
+                    state.buffer.outline(x, box_y, size, 2, state.config.primary_color);
+                    if on {
+                        state.buffer.fill(x + 4, box_y + 4, Vec2::from(8, 8), state.config.primary_color);
+                    }
+                    state.buffer.text(if on { "On" } else { "Off" }, x + 26, text_y, width, Align::Left, text_color);
+                }
+                Row::Choice(key) => {
+                    let current = match state.config.get(key) {
+                        Some(Value::Text(name)) => name,
+                        _ => String::new(),
+                    };
+                    state.buffer.text("<", x, text_y, width, Align::Left, state.config.primary_color);
+                    state.buffer.text(&current, x, text_y, width, Align::Center, text_color);
+                    state.buffer.text(">", x, text_y, width, Align::Right, state.config.primary_color);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use crate::buffer::{self, Buffer, BufferView, Kernel};
+use crate::filter::Filter;
+use crate::gui::{Widget, STATUS_HEIGHT};
+use crate::input::Mouse;
+use crate::animation::Animation;
+use crate::library::{self, Change};
+use crate::pool::{Priority, Token};
+use crate::{lock_state, State, Vec2};
+use minifb::{Key, MouseButton};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Shows a single image at full resolution.
+pub struct Viewer {
+    index: usize,
+    viewport: Vec2,
+    original: Option<Arc<RwLock<Option<Buffer>>>>,
+    /// Every frame, used instead of `original` for animated images.
+    animation: Option<Arc<RwLock<Option<Animation>>>>,
+    /// `None` fits the image to the window.
+    zoom: Option<f32>,
+    /// The image pixel that is shown in the middle of the window.
+    center: (f32, f32),
+    rendered: Option<Rendered>,
+    /// The render the pool is working on, `rendered` stays on screen until it's done.
+    pending: Option<(Request, Arc<RwLock<Option<Rendered>>>)>,
+    /// The last mouse position while panning by dragging.
+    dragging: Option<Vec2>,
+    /// Cancels decoding the original when it isn't needed anymore.
+    token: Token,
+    /// How long the current image has been on screen while the slideshow plays, `None` when it doesn't.
+    slideshow: Option<Duration>,
+    /// The filter of the gallery, the arrow keys and the slideshow skip images it hides.
+    filter: Filter,
+}
+
+/// What the visible part of the image gets scaled for, so it only gets scaled again when something changes.
+#[derive(Clone, Copy, PartialEq)]
+struct Request {
+    kernel: Kernel,
+    scale: f32,
+    center: (f32, f32),
+    viewport: Vec2,
+    /// The frame of an animation.
+    frame: usize,
+}
+
+/// The visible part of the image at the current zoom.
+struct Rendered {
+    request: Request,
+    pos: Vec2,
+    buffer: Buffer,
+}
+
+impl Viewer {
+    pub fn new(index: usize, filter: Filter) -> Self {
+        Viewer {
+            index,
+            viewport: Vec2::zero(),
+            original: None,
+            animation: None,
+            zoom: None,
+            center: (0.0, 0.0),
+            rendered: None,
+            pending: None,
+            dragging: None,
+            token: Token::default(),
+            slideshow: None,
+            filter,
+        }
+    }
+
+    /// Starts at `index` and moves on to the next image every `slideshow_interval` seconds.
+    pub fn slideshow(index: usize, filter: Filter) -> Self {
+        let mut viewer = Viewer::new(index, filter);
+        viewer.slideshow = Some(Duration::ZERO);
+        viewer
+    }
+
+    /// Replacing the viewer drops the old one, which cancels decoding its original.
+    fn show(&mut self, index: usize) {
+        let (viewport, playing, filter) = (self.viewport, self.slideshow.is_some(), self.filter.clone());
+        *self = if playing { Viewer::slideshow(index, filter) } else { Viewer::new(index, filter) };
+        self.viewport = viewport;
+    }
+
+    /// The first of `indices` that the filter shows.
+    fn shown(&self, state: &State, mut indices: impl Iterator<Item = usize>) -> Option<usize> {
+        indices.find(|&i| self.filter.matches(&state.library.images[i]))
+    }
+
+    fn scale(&self, size: Vec2) -> f32 {
+        self.zoom.unwrap_or_else(|| {
+            (self.viewport.x as f32 / size.x as f32).min(self.viewport.y as f32 / size.y as f32)
+        })
+    }
+
+    fn zoom(&mut self, factor: f32) {
+        let Some(size) = self.size_of_original() else {
+            return;
+        };
+        let scale = self.scale(size);
+        self.zoom = Some((scale * factor).clamp(0.01, 32.0));
+    }
+
+    fn pan(&mut self, x: f32, y: f32) {
+        let Some(size) = self.size_of_original() else {
+            return;
+        };
+        let scale = self.scale(size);
+        self.center.0 += x / scale;
+        self.center.1 += y / scale;
+    }
+
+    fn size_of_original(&self) -> Option<Vec2> {
+        if let Some(animation) = &self.animation {
+            return animation.read().ok()?.as_ref().map(Animation::size);
+        }
+        let original = self.original.as_ref()?.read().ok()?;
+        original.as_ref().map(|buf| buf.size)
+    }
+
+    /// Keeps the image on screen, centering it along axes where it fits entirely.
+    fn clamp(&mut self, size: Vec2, scale: f32) {
+        let clamp = |center: f32, image: u32, viewport: u32| {
+            let half = viewport as f32 / 2.0 / scale;
+            if image as f32 <= half * 2.0 {
+                image as f32 / 2.0
+            } else {
+                center.clamp(half, image as f32 - half)
+            }
+        };
+        self.center.0 = clamp(self.center.0, size.x, self.viewport.x);
+        self.center.1 = clamp(self.center.1, size.y, self.viewport.y);
+    }
+
+    /// Scales the visible part of the original on the pool, unless it's on screen or being scaled already.
+    fn render(
+        &mut self,
+        state: &mut State,
+        size: Vec2,
+        frame: usize,
+        original: impl FnOnce() -> Arc<RwLock<Option<Buffer>>>,
+    ) {
+        if let Some((_, pending)) = &self.pending
+            && let Some(rendered) = pending.write().ok().and_then(|mut rendered| rendered.take())
+        {
+            self.rendered = Some(rendered);
+            self.pending = None;
+        }
+
+        let scale = self.scale(size);
+        self.clamp(size, scale);
+        let request =
+            Request { kernel: state.config.viewer_filter, scale, center: self.center, viewport: self.viewport, frame };
+        if self.rendered.as_ref().is_some_and(|r| r.request == request)
+            || self.pending.as_ref().is_some_and(|(r, _)| *r == request)
+        {
+            return;
+        }
+
+        let out = Vec2::from(
+            ((size.x as f32 * scale).round() as u32).clamp(1, self.viewport.x.max(1)),
+            ((size.y as f32 * scale).round() as u32).clamp(1, self.viewport.y.max(1)),
+        );
+        let (w, h) = (out.x as f32 / scale, out.y as f32 / scale);
+        let x = ((self.center.0 - w / 2.0).floor().max(0.0) as u32).min(size.x - 1);
+        let y = ((self.center.1 - h / 2.0).floor().max(0.0) as u32).min(size.y - 1);
+        let (from, crop) = (
+            Vec2::from(x, y),
+            Vec2::from((w.round() as u32).clamp(1, size.x - x), (h.round() as u32).clamp(1, size.y - y)),
+        );
+        let pos = Vec2::from((self.viewport.x - out.x) / 2, (self.viewport.y - out.y) / 2);
+
+        // The original is decoded already, so the token only holds back the render that is outdated now.
+        if self.pending.take().is_some() {
+            std::mem::take(&mut self.token).cancel();
+        }
+        let arc = Arc::new(RwLock::new(None));
+        let result = arc.clone();
+        let (original, pool) = (original(), state.thread_pool.handle());
+        state.thread_pool.submit(Priority::High, Some(self.token.clone()), move || {
+            let Some(pixels) = original.read().ok().and_then(|buf| buf.as_ref().map(|buf| buf.crop_rgba8(from, crop)))
+            else {
+                return;
+            };
+            let buffer = buffer::scale_rgba8(pixels, crop, out, request.kernel, &pool);
+            let _ = result.write().map(|mut rendered| *rendered = Some(Rendered { request, pos, buffer }));
+            lock_state().update();
+        });
+        self.pending = Some((request, arc));
+    }
+}
+
+impl Drop for Viewer {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+impl Widget for Viewer {
+    fn pos(&self, _scroll: u32) -> Vec2 {
+        Vec2::zero()
+    }
+
+    fn size(&self) -> Vec2 {
+        self.viewport
+    }
+
+    fn resize(&mut self, state: &mut State) {
+        self.viewport = state.buffer.size.sub2(0, STATUS_HEIGHT);
+        state.view.height = state.buffer.size.y;
+        state.view.scroll = 0;
+    }
+
+    fn changed(&mut self, state: &mut State, change: &Change) {
+        match *change {
+            Change::Added(index) if index <= self.index => self.index += 1,
+            Change::Removed(index) if index < self.index => self.index -= 1,
+            Change::Removed(index) if index == self.index => {
+                // The removed image is gone already, so whatever took its place comes first.
+                let count = state.library.images.len();
+                let next = self.shown(state, self.index..count);
+                match next.or_else(|| self.shown(state, (0..self.index.min(count)).rev())) {
+                    Some(index) => self.show(index),
+                    None => state.view.back(),
+                }
+            }
+            Change::Modified(index) if index == self.index => {
+                std::mem::take(&mut self.token).cancel();
+                self.original = None;
+                self.animation = None;
+                self.rendered = None;
+                self.pending = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn sorted(&mut self, _state: &mut State, moved: &[usize]) {
+        self.index = moved[self.index];
+    }
+
+    fn key(&mut self, state: &mut State, key: Key) -> bool {
+        let step = self.viewport.y as f32 / 10.0;
+        match key {
+            Key::Escape => state.view.back(),
+            Key::Left | Key::Right => {
+                let next = match key {
+                    Key::Left => self.shown(state, (0..self.index).rev()),
+                    _ => self.shown(state, self.index + 1..state.library.images.len()),
+                };
+                if let Some(index) = next {
+                    self.show(index);
+                }
+            }
+            Key::Space => self.slideshow = if self.slideshow.is_some() { None } else { Some(Duration::ZERO) },
+            Key::F => self.zoom = None,
+            Key::Key1 | Key::NumPad1 => self.zoom = Some(1.0),
+            Key::Equal | Key::NumPadPlus => self.zoom(1.25),
+            Key::Minus | Key::NumPadMinus => self.zoom(0.8),
+            Key::Up | Key::W => self.pan(0.0, -step),
+            Key::Down | Key::S => self.pan(0.0, step),
+            Key::A => self.pan(-step, 0.0),
+            Key::D => self.pan(step, 0.0),
+            _ => return false,
+        }
+        true
+    }
+
+    fn scroll(&mut self, _state: &mut State, delta: f32) -> bool {
+        self.zoom(1.1f32.powf(delta.signum()));
+        true
+    }
+
+    fn mouse(&mut self, _state: &mut State, event: &Mouse) -> bool {
+        match *event {
+            Mouse::Down(pos, MouseButton::Left) => self.dragging = Some(pos),
+            Mouse::Move(pos) => {
+                let Some(last) = self.dragging.replace(pos) else {
+                    return false;
+                };
+                self.pan(last.x as f32 - pos.x as f32, last.y as f32 - pos.y as f32);
+            }
+            Mouse::Up(_, MouseButton::Left) => return self.dragging.take().is_some(),
+            Mouse::DoubleClick(_) => {
+                self.zoom = if self.zoom.is_none() { Some(1.0) } else { None };
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn tick(&mut self, state: &mut State, elapsed: Duration) {
+        if let Some(animation) = &self.animation
+            && let Ok(mut animation) = animation.try_write()
+            && let Some(animation) = animation.as_mut()
+            && animation.tick(elapsed)
+        {
+            state.update();
+        }
+
+        // The time only counts once the image is on screen, so slow decodes don't cut it short.
+        if let Some(shown) = self.slideshow.as_mut()
+            && self.rendered.is_some()
+        {
+            *shown += elapsed;
+            if *shown >= Duration::from_secs(state.config.slideshow_interval as u64) {
+                // Starts over at the first image the filter shows after the last one.
+                let count = state.library.images.len();
+                let next = self.shown(state, self.index + 1..count).or_else(|| self.shown(state, 0..count));
+                if let Some(index) = next {
+                    self.show(index);
+                    state.update();
+                }
+            }
+        }
+    }
+
+    fn draw(&mut self, state: &mut State) {
+        let Some(image) = state.library.images.get(self.index) else {
+            return;
+        };
+        let (path, orientation, token) = (image.path.clone(), image.exif.orientation, self.token.clone());
+        if image.animated {
+            let animation = self
+                .animation
+                .get_or_insert_with(|| library::Image::animation(path, orientation, token, state))
+                .clone();
+            if let Ok(animation) = animation.try_read()
+                && let Some(ref animation) = *animation
+            {
+                let frame = animation.frame();
+                self.render(state, frame.size, animation.current(), || Arc::new(RwLock::new(Some(frame.clone()))));
+            }
+        } else {
+            let original = self
+                .original
+                .get_or_insert_with(|| library::Image::original(path, orientation, token, state))
+                .clone();
+            let size = original.try_read().ok().and_then(|buf| buf.as_ref().map(|buf| buf.size));
+            if let Some(size) = size {
+                self.render(state, size, 0, || original);
+            }
+        }
+
+        let image = &state.library.images[self.index];
+        state.view.status = format!(
+            "{}/{} — {} — {}×{}",
+            self.index + 1,
+            state.library.images.len(),
+            image.name,
+            image.dimensions.x,
+            image.dimensions.y
+        );
+        let summary = image.exif.summary();
+        if !summary.is_empty() {
+            state.view.status.push_str(&format!(" — {summary}"));
+        }
+        if self.slideshow.is_some() {
+            state.view.status.push_str(" — slideshow, Space to pause");
+        }
+        if let Some(rendered) = &self.rendered {
+            state.view.status.push_str(&format!(" — {:.0}%", rendered.request.scale * 100.0));
+            state.buffer.composite(&rendered.buffer, rendered.pos.x, rendered.pos.y as i32, state.config.backdrop());
+            return;
+        }
+
+        // Show the thumbnail while the original is still being decoded.
+        let mut image = state.library.images.remove(self.index);
+        let arc = image.get(state, 0);
+        if let Ok(buf) = arc.try_read()
+            && let Some(ref buf) = *buf
+        {
+            state.buffer.composite(
+                buf,
+                self.viewport.x.saturating_sub(buf.size.x) / 2,
+                (self.viewport.y.saturating_sub(buf.size.y) / 2) as i32,
+                state.config.backdrop(),
+            );
+        }
+        state.library.images.insert(self.index, image);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::cache;
+use crate::exif::{self, Exif};
+use crate::util::Vec2;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What we know about a file from the last time it was probed.
+/// Files that aren't images are kept with zero dimensions so they don't get probed again either.
+#[derive(Clone)]
+pub struct Entry {
+    pub len: u64,
+    pub modified: u64,
+    pub dimensions: Vec2,
+    pub animated: bool,
+    pub exif: Exif,
+    /// Only known once something asked for it, see [`crate::similar::dhash`].
+    pub hash: Option<u64>,
+    seen: bool,
+}
+
+impl Entry {
+    pub fn new(len: u64, modified: u64, dimensions: Vec2, animated: bool, exif: Exif) -> Entry {
+        Entry { len, modified, dimensions, animated, exif, hash: None, seen: true }
+    }
+}
+
+#[derive(Default)]
+pub struct Index {
+    entries: HashMap<PathBuf, Entry>,
+    dirty: bool,
+    /// Whether [`Index::save`] forgets files that weren't seen, which is only right when every library was loaded.
+    prune: bool,
+}
+
+impl Index {
+    fn file() -> PathBuf {
+        cache::dir().join("library.idx")
+    }
+
+    pub fn load(prune: bool) -> Index {
+        let mut index = Index { prune, ..Index::default() };
+        let Ok(content) = fs::read_to_string(Index::file()) else {
+            return index;
+        };
+
+        // Lines that don't parse, like ones written by an older version, just get probed again.
+        for line in content.lines() {
+            let mut parts = line.splitn(7 + exif::COLUMNS, '\t');
+            let mut next = || parts.next().and_then(|p| p.parse::<u64>().ok());
+            let (Some(modified), Some(len), Some(w), Some(h), Some(animated)) = (next(), next(), next(), next(), next())
+            else {
+                continue;
+            };
+            let hash = match parts.next() {
+                Some("") => None,
+                Some(hash) if hash.len() == 16 => match u64::from_str_radix(hash, 16) {
+                    Ok(hash) => Some(hash),
+                    Err(_) => continue,
+                },
+                _ => continue,
+            };
+            let (Some(exif), Some(path)) = (Exif::from_columns(&mut parts), parts.next()) else {
+                continue;
+            };
+            index.entries.insert(PathBuf::from(path), Entry {
+                len,
+                modified,
+                dimensions: Vec2::from(w as u32, h as u32),
+                animated: animated != 0,
+                exif,
+                hash,
+                seen: false,
+            });
+        }
+        index
+    }
+
+    /// Returns the entry for `path` if the file hasn't changed since it was indexed.
+    pub fn get(&mut self, path: &Path, len: u64, modified: u64) -> Option<Entry> {
+        let entry = self.entries.get_mut(path)?;
+        if entry.len != len || entry.modified != modified {
+            return None;
+        }
+        entry.seen = true;
+        Some(entry.clone())
+    }
+
+    pub fn insert(&mut self, path: PathBuf, entry: Entry) {
+        self.entries.insert(path, entry);
+        self.dirty = true;
+    }
+
+    pub fn set_hash(&mut self, path: &Path, hash: u64) {
+        if let Some(entry) = self.entries.get_mut(path) {
+            entry.hash = Some(hash);
+            self.dirty = true;
+        }
+    }
+
+    /// Forgets every file under `roots`.
+    pub fn clear(&mut self, roots: &[PathBuf]) {
+        self.entries.retain(|path, _| !roots.iter().any(|root| path.starts_with(root)));
+        self.dirty = true;
+    }
+
+    /// Forgets files that weren't seen since loading when pruning, and writes the index back if anything changed.
+    pub fn save(&mut self) {
+        let len = self.entries.len();
+        if self.prune {
+            self.entries.retain(|_, entry| entry.seen);
+        }
+        if !self.dirty && len == self.entries.len() {
+            return;
+        }
+
+        let mut content = String::new();
+        for (path, entry) in self.entries.iter() {
+            let Some(path) = path.to_str() else {
+                continue;
+            };
+            if path.contains(['\n', '\r']) {
+                continue;
+            }
+            content.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{path}\n",
+                entry.modified,
+                entry.len,
+                entry.dimensions.x,
+                entry.dimensions.y,
+                entry.animated as u8,
+                entry.hash.map(|hash| format!("{hash:016x}")).unwrap_or_default(),
+                entry.exif.columns(),
+            ));
+        }
+        let _ = fs::create_dir_all(cache::dir());
+        match fs::write(Index::file(), content) {
+            Ok(_) => self.dirty = false,
+            Err(err) => eprintln!("Failed to save library index: {}", err),
+        }
+    }
+}
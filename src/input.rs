@@ -1,19 +1,113 @@
-use crate::GLOBAL_STATE;
+use crate::gui::View;
+use crate::library;
+use crate::{lock_state, Vec2};
 use allocative::FlameGraphBuilder;
-use minifb::{InputCallback, Key};
+use minifb::{InputCallback, Key, MouseButton, MouseMode, Window};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 pub struct Input {}
 
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Mouse {
+    Move(Vec2),
+    Down(Vec2, MouseButton),
+    Up(Vec2, MouseButton),
+    DoubleClick(Vec2),
+    Leave,
+}
+
+const BUTTONS: [MouseButton; 3] = [MouseButton::Left, MouseButton::Middle, MouseButton::Right];
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Turns the mouse state minifb gives us every frame into events.
+#[derive(Default)]
+pub struct Pointer {
+    pub pos: Option<Vec2>,
+    down: [bool; 3],
+    last_click: Option<(Instant, Vec2)>,
+}
+
+impl Pointer {
+    pub fn poll(&mut self, window: &Window, size: Vec2) -> Vec<Mouse> {
+        let mut events = Vec::new();
+        // Keep tracking the mouse outside the window while a button is held, so drags don't get stuck.
+        let mode = if self.down.contains(&true) { MouseMode::Clamp } else { MouseMode::Discard };
+        let (width, height) = window.get_size();
+        let pos = window.get_mouse_pos(mode).map(|(x, y)| {
+            Vec2::from(
+                ((x * size.x as f32 / width.max(1) as f32) as u32).min(size.x.saturating_sub(1)),
+                ((y * size.y as f32 / height.max(1) as f32) as u32).min(size.y.saturating_sub(1)),
+            )
+        });
+
+        if pos != self.pos {
+            events.push(pos.map_or(Mouse::Leave, Mouse::Move));
+            self.pos = pos;
+        }
+        let Some(pos) = pos else {
+            return events;
+        };
+
+        for (i, &button) in BUTTONS.iter().enumerate() {
+            let down = window.get_mouse_down(button);
+            if down == self.down[i] {
+                continue;
+            }
+            self.down[i] = down;
+            if !down {
+                events.push(Mouse::Up(pos, button));
+                continue;
+            }
+
+            events.push(Mouse::Down(pos, button));
+            if button == MouseButton::Left {
+                let now = Instant::now();
+                match self.last_click {
+                    Some((time, last)) if now - time < DOUBLE_CLICK
+                        && last.x.abs_diff(pos.x) < 4 && last.y.abs_diff(pos.y) < 4 => {
+                        events.push(Mouse::DoubleClick(pos));
+                        self.last_click = None;
+                    }
+                    _ => self.last_click = Some((now, pos)),
+                }
+            }
+        }
+        events
+    }
+}
+
 impl InputCallback for Input {
-    fn add_char(&mut self, _uni_char: u32) {}
+    fn add_char(&mut self, uni_char: u32) {
+        let state = &mut *lock_state();
+        if let Some(c) = char::from_u32(uni_char)
+            && View::char(state, c)
+        {
+            state.update();
+        }
+    }
 
     fn set_key_state(&mut self, key: Key, down: bool) {
+        let state = &mut *lock_state();
+        match key {
+            Key::LeftCtrl | Key::RightCtrl => state.modifiers.ctrl = down,
+            Key::LeftShift | Key::RightShift => state.modifiers.shift = down,
+            _ => {}
+        }
         if !down {
             return;
         }
 
-        let state = &mut *GLOBAL_STATE.write().unwrap();
+        if View::key(state, key) {
+            state.update();
+            return;
+        }
         match key {
             Key::Up => {
                 state.view.scroll -= 100.min(state.view.scroll);
@@ -33,6 +127,12 @@ impl InputCallback for Input {
             Key::PageDown => {
                 state.view.scroll = (state.view.scroll + state.buffer.size.y).min(state.view.height - state.buffer.size.y);
             }
+            Key::F5 => {
+                let roots = state.library.roots.clone();
+                state.library.load_all(roots, true);
+                state.view = View::gallery();
+                View::resize(state);
+            }
             Key::F10 => {
                 let mut builder = FlameGraphBuilder::default();
                 builder.visit_root(state);
@@ -62,6 +162,22 @@ impl InputCallback for Input {
                 println!("total: {total:.4}KiB unused: {unused:.4}KiB");
                 println!("screen: {screen:.4}KiB ({}x{}x4={:.4}KiB)", state.buffer.size.x, state.buffer.size.y, (state.buffer.size.x * state.buffer.size.y * 4) as f32 / 1024.0);
                 println!("images: {images:.4}KiB data: {image_data:.4}KiB ({}/{})", state.library.images.iter().filter(|i| i.loaded()).count(), state.library.images.len());
+                println!(
+                    "thumbnails: {:.1}MiB of {}MiB budget, {} evicted, {} queued",
+                    library::memory_used() as f32 / 1024.0 / 1024.0,
+                    state.config.memory_budget,
+                    library::evictions(),
+                    state.library.queue.queued()
+                );
+                let pool = &state.thread_pool;
+                println!(
+                    "pool: {} workers, {} queued, {} running, {} completed, {} panicked",
+                    pool.workers(),
+                    pool.queued(),
+                    pool.running(),
+                    pool.completed(),
+                    pool.panicked()
+                );
             }
             _ => {}
         }
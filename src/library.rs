@@ -1,11 +1,50 @@
-use crate::buffer::{Buffer, BufferView};
-use crate::{State, Vec2, GLOBAL_STATE};
+use crate::animation::{self, Animation};
+use crate::buffer::{self, Buffer, BufferView, Kernel};
+use crate::exif::Exif;
+use crate::filter;
+use crate::gui::View;
+use crate::index::{Entry, Index};
+use crate::pool::{Handle, Priority, Token};
+use crate::similar;
+use crate::util::{hash, natural_cmp};
+use crate::xmp::{self, Xmp};
+use crate::{cache, lock_state, State, Vec2};
 use allocative::Allocative;
-use image::{DynamicImage, GenericImageView, Limits};
+use image::{DynamicImage, GenericImageView, ImageDecoder, Limits};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Bytes used by all loaded thumbnails.
+static USED: AtomicUsize = AtomicUsize::new(0);
+/// How many thumbnails were unloaded to stay within the memory budget.
+static EVICTED: AtomicUsize = AtomicUsize::new(0);
+
+/// Puts `buffer` in a thumbnail slot, keeping track of the memory used.
+fn store(slot: &RwLock<Option<Buffer>>, buffer: Option<Buffer>) {
+    let Ok(mut slot) = slot.write() else {
+        return;
+    };
+    let bytes = |buffer: &Option<Buffer>| buffer.as_ref().map_or(0, |b| b.data.len() * 4);
+    USED.fetch_add(bytes(&buffer), Relaxed);
+    USED.fetch_sub(bytes(&slot), Relaxed);
+    *slot = buffer;
+}
+
+/// Where [`Library::hash_missing`] puts the hashes it computed.
+pub type Hashes = Arc<Mutex<Vec<(PathBuf, Option<u64>)>>>;
+
+pub fn memory_used() -> usize {
+    USED.load(Relaxed)
+}
+
+pub fn evictions() -> usize {
+    EVICTED.load(Relaxed)
+}
 
 #[derive(Allocative)]
 pub struct Image {
@@ -13,40 +52,112 @@ pub struct Image {
     pub name: String,
     buffer: Arc<RwLock<Option<Buffer>>>,
     pub size: Vec2,
+    pub dimensions: Vec2,
+    pub modified: u64,
+    pub len: u64,
+    pub exif: Exif,
+    /// Has more than one frame, see [`Image::animation`].
+    pub animated: bool,
+    /// Part of the selection that batch operations work on.
+    pub selected: bool,
+    /// Rating, label and flag from the sidecar.
+    pub xmp: Xmp,
+    /// Perceptual hash of the thumbnail, once [`Library::hash_missing`] computed it.
+    pub hash: Option<u64>,
+    /// The last frame the thumbnail was drawn in, to find the ones that can be unloaded.
+    pub drawn: u64,
 }
 
 impl Image {
-    pub fn get(&mut self, state: &mut State) -> Arc<RwLock<Option<Buffer>>> {
-        let _ = self.buffer.try_write().and_then(|mut buf| {
-            if buf.is_none() {
-                *buf = Some(Buffer::empty());
-
-                if state.config.pico {
-                    drop(buf);
-                    Image::load(state.config.pico, self.path.clone(), self.buffer.clone(), self.size);
-                } else {
-                    let pico = state.config.pico;
-                    let path = self.path.clone();
-                    let arc = self.buffer.clone();
-                    let size = self.size;
-                    state.thread_pool.execute(move || {
-                        Image::load(pico, path, arc, size);
-                    });
-                }
+    /// Returns the thumbnail slot, queueing the thumbnail to be loaded if it isn't yet.
+    /// Queued thumbnails with the lowest `priority` are loaded first, and ones that aren't asked for again
+    /// before the next [`Queue::cancel_stale`] are dropped from the queue.
+    pub fn get(&mut self, state: &mut State, priority: u32) -> Arc<RwLock<Option<Buffer>>> {
+        self.drawn = state.library.frame;
+        let Ok(mut buf) = self.buffer.try_write() else {
+            return self.buffer.clone();
+        };
+        // An empty buffer is a placeholder while the thumbnail waits in the queue, which only needs to know it's
+        // still wanted. This runs for every thumbnail on screen every frame, so it has to stay cheap.
+        if let Some(loaded) = buf.as_ref() {
+            if loaded.data.is_empty() {
+                state.library.queue.touch(&self.path, priority, state.library.frame);
             }
-            Ok(())
-        });
+            return self.buffer.clone();
+        }
+        let job = Job {
+            priority,
+            frame: state.library.frame,
+            settings: Settings::new(state),
+            path: self.path.clone(),
+            key: cache::key(&self.path, self.modified, self.size, state.library.kernel),
+            arc: self.buffer.clone(),
+            size: self.size,
+            orientation: self.exif.orientation,
+        };
+        if state.config.pico {
+            *buf = Some(Buffer::empty());
+            drop(buf);
+            job.run();
+        } else {
+            *buf = Some(Buffer::empty());
+            let queue = state.library.queue.clone();
+            queue.push(job);
+            state.thread_pool.execute(move || queue.run_next());
+        }
 
         self.buffer.clone()
     }
 
-    fn load(limits: bool, path: PathBuf, arc: Arc<RwLock<Option<Buffer>>>, size: Vec2) {
+    /// `size` is the size of the thumbnail after `orientation` is applied.
+    fn load(settings: &Settings, path: PathBuf, key: String, arc: Arc<RwLock<Option<Buffer>>>, size: Vec2, orientation: u8) {
+        // The image was removed from the library before it got its turn.
+        if Arc::strong_count(&arc) == 1 {
+            return;
+        }
+        let Some(thumbnail) = Image::render(settings, &path, &key, size, orientation) else {
+            return;
+        };
+        // The image was removed from the library while it was loading.
+        if Arc::strong_count(&arc) == 1 {
+            return;
+        }
+        store(&arc, Some(thumbnail));
+
+        lock_state().update();
+    }
+
+    /// Reads the thumbnail from the cache, or decodes and scales the image and caches the result.
+    fn render(settings: &Settings, path: &Path, key: &str, size: Vec2, orientation: u8) -> Option<Buffer> {
+        if let Some(cached) = cache::read(key) {
+            return Some(cached);
+        }
+
         let start = std::time::Instant::now();
-        let mut image = match image::ImageReader::open(&path).and_then(|img| img.with_guessed_format()) {
+        let image = Image::decode(settings.limits, path)?;
+        let name = path.file_name().unwrap_or("unknown".as_ref());
+        let loaded = start.elapsed();
+        if loaded.as_secs() > 0 {
+            println!("Decoded {name:?} in {loaded:.2?}");
+        }
+        let stored = if orientation >= 5 { Vec2::from(size.y, size.x) } else { size };
+        let source = Vec2::from(image.width(), image.height());
+        let pixels = image.into_rgba8().into_raw();
+        let scaled = buffer::scale_rgba8(pixels, source, stored, settings.kernel, &settings.pool).orient(orientation);
+        let elapsed = start.elapsed() - loaded;
+        if elapsed.as_secs() > 0 {
+            println!("Scaled {name:?} in {elapsed:.2?}");
+        }
+        cache::write(key, &scaled);
+        Some(scaled)
+    }
+
+    fn decode(limits: bool, path: &Path) -> Option<DynamicImage> {
+        let mut image = match image::ImageReader::open(path).and_then(|img| img.with_guessed_format()) {
             Ok(reader) => reader,
             Err(err) => {
                 println!("Failed to open image: {}", err);
-                return;
+                return None;
             }
         };
         if limits {
@@ -54,29 +165,48 @@ impl Image {
             limits.max_alloc = Some(50 * 1024 * 1024);
             image.limits(limits);
         }
-        let image = match image.decode() {
-            Ok(img) => img,
+        match image.decode() {
+            Ok(img) => Some(img),
             Err(err) => {
                 println!("Failed to decode image: {}", err);
-                return;
+                None
             }
-        };
-        let name = path.file_name().unwrap_or("unknown".as_ref());
-        let loaded = start.elapsed();
-        if loaded.as_secs() > 0 {
-            println!("Decoded {name:?} in {loaded:.2?}");
-        }
-        let _ = arc.write().map(|mut buf| *buf = Some(image.scale(size)));
-        let scaled = start.elapsed() - loaded;
-        if scaled.as_secs() > 0 {
-            println!("Scaled {name:?} in {scaled:.2?}");
         }
+    }
+
+    /// Decodes the image at `path` at full resolution in the background, for when the thumbnail isn't enough.
+    /// Cancelling `token` drops the decode if it hasn't started yet.
+    pub fn original(path: PathBuf, orientation: u8, token: Token, state: &mut State) -> Arc<RwLock<Option<Buffer>>> {
+        let arc = Arc::new(RwLock::new(None));
+        let result = arc.clone();
+        let pico = state.config.pico;
+        state.thread_pool.submit(Priority::High, Some(token), move || {
+            if let Some(image) = Image::decode(pico, &path) {
+                let size = Vec2::from(image.width(), image.height());
+                let original = Buffer::from_rgba8(size, image.into_rgba8().as_raw()).orient(orientation);
+                let _ = result.write().map(|mut buf| *buf = Some(original));
+                lock_state().update();
+            }
+        });
+        arc
+    }
 
-        GLOBAL_STATE.write().unwrap().update();
+    /// Decodes every frame of an animated image in the background.
+    pub fn animation(path: PathBuf, orientation: u8, token: Token, state: &mut State) -> Arc<RwLock<Option<Animation>>> {
+        let arc = Arc::new(RwLock::new(None));
+        let result = arc.clone();
+        let pico = state.config.pico;
+        state.thread_pool.submit(Priority::High, Some(token), move || {
+            if let Some(animation) = Animation::decode(&path, pico, orientation) {
+                let _ = result.write().map(|mut anim| *anim = Some(animation));
+                lock_state().update();
+            }
+        });
+        arc
     }
 
     pub fn unload(&mut self) {
-        let _ = self.buffer.write().map(|mut b| *b = None);
+        store(&self.buffer, None);
     }
 
     pub fn loaded(&self) -> bool {
@@ -84,6 +214,101 @@ impl Image {
     }
 }
 
+/// How thumbnails are made, copied into every job because jobs can't reach the state.
+#[derive(Clone)]
+struct Settings {
+    /// Decode within the memory limits of pico mode.
+    limits: bool,
+    kernel: Kernel,
+    /// Large images are scaled on several workers.
+    pool: Handle,
+}
+
+impl Settings {
+    fn new(state: &State) -> Settings {
+        Settings { limits: state.config.pico, kernel: state.library.kernel, pool: state.thread_pool.handle() }
+    }
+}
+
+/// A thumbnail waiting to be loaded.
+struct Job {
+    priority: u32,
+    /// The frame the thumbnail was last asked for in.
+    frame: u64,
+    settings: Settings,
+    path: PathBuf,
+    key: String,
+    arc: Arc<RwLock<Option<Buffer>>>,
+    size: Vec2,
+    orientation: u8,
+}
+
+impl Job {
+    fn run(self) {
+        Image::load(&self.settings, self.path, self.key, self.arc, self.size, self.orientation);
+    }
+}
+
+/// Thumbnails waiting to be loaded, by path. The pool gets one task per job, which loads whatever
+/// job is most important by the time it runs rather than the one it was queued for.
+#[derive(Clone, Default)]
+pub struct Queue(Arc<Mutex<HashMap<PathBuf, Job>>>);
+
+impl Queue {
+    fn push(&self, job: Job) {
+        self.0.lock().unwrap().insert(job.path.clone(), job);
+    }
+
+    /// Updates the priority of a job that is still waiting.
+    fn touch(&self, path: &Path, priority: u32, frame: u64) {
+        if let Some(queued) = self.0.lock().unwrap().get_mut(path) {
+            queued.priority = priority;
+            queued.frame = frame;
+        }
+    }
+
+    fn run_next(&self) {
+        let job = {
+            let mut jobs = self.0.lock().unwrap();
+            let Some(path) = jobs.iter().min_by_key(|(_, job)| job.priority).map(|(path, _)| path.clone()) else {
+                return;
+            };
+            jobs.remove(&path)
+        };
+        if let Some(job) = job {
+            job.run();
+        }
+    }
+
+    /// Drops the jobs that weren't asked for in `frame`, so their thumbnails get queued again when needed.
+    pub fn cancel_stale(&self, frame: u64) {
+        self.0.lock().unwrap().retain(|_, job| {
+            if job.frame < frame {
+                store(&job.arc, None);
+            }
+            job.frame >= frame
+        });
+    }
+
+    /// Drops every job, so the thumbnails get queued again at the size they are asked for by then.
+    fn cancel_all(&self) {
+        for (_, job) in self.0.lock().unwrap().drain() {
+            store(&job.arc, None);
+        }
+    }
+
+    /// How many thumbnails are waiting to be loaded.
+    pub fn queued(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
 impl BufferView for DynamicImage {
     fn size(&self) -> Vec2 {
         Vec2::from(DynamicImage::width(self), DynamicImage::height(self))
@@ -91,31 +316,277 @@ impl BufferView for DynamicImage {
 
     fn get(&self, pos: Vec2) -> u32 {
         let pixel = self.get_pixel(pos.x, pos.y).0;
-        (0xFF << 24)
+        ((pixel[3] as u32) << 24)
             | ((pixel[0] as u32) << 16)
             | ((pixel[1] as u32) << 8)
             | pixel[2] as u32
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Sort {
+    /// File name, with numbers compared by value.
+    Name,
+    Modified,
+    /// When the photo was taken according to EXIF, images without a date go last.
+    Taken,
+    /// File size.
+    Size,
+    /// Number of pixels.
+    Dimensions,
+    Random,
+}
+
+impl Sort {
+    pub const ALL: [Sort; 6] = [Sort::Name, Sort::Modified, Sort::Taken, Sort::Size, Sort::Dimensions, Sort::Random];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Sort::Name => "name",
+            Sort::Modified => "modified",
+            Sort::Taken => "taken",
+            Sort::Size => "size",
+            Sort::Dimensions => "dimensions",
+            Sort::Random => "random",
+        }
+    }
+
+    pub fn next(self) -> Sort {
+        let i = Sort::ALL.iter().position(|&sort| sort == self).unwrap_or(0);
+        Sort::ALL[(i + 1) % Sort::ALL.len()]
+    }
+}
+
+/// The size and modification time of a file, which tell whether the index still knows it.
+fn stat(path: &Path) -> Option<(u64, u64)> {
+    let meta = fs::metadata(path).ok()?;
+    let modified = meta.modified().ok()?.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some((meta.len(), modified.as_millis() as u64))
+}
+
+/// An incremental update to `Library.images`, applied in order.
+#[derive(Copy, Clone)]
+pub enum Change {
+    Added(usize),
+    Removed(usize),
+    Modified(usize),
+}
+
+/// A folder or file the library shows the images of.
+#[derive(Clone)]
+pub struct Root {
+    pub path: PathBuf,
+    /// Lowercase globs for names of files and folders below `path` to leave out.
+    pub exclude: Vec<Vec<char>>,
+}
+
+impl Root {
+    pub fn new(path: PathBuf, exclude: &[String]) -> Root {
+        Root { path, exclude: exclude.iter().map(|glob| glob.to_lowercase().chars().collect()).collect() }
+    }
+
+    /// Whether `path` is below this root and has a name in it that is excluded.
+    fn excludes(&self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.path) else {
+            return false;
+        };
+        relative.iter().any(|name| {
+            let name: Vec<char> = name.to_string_lossy().to_lowercase().chars().collect();
+            self.exclude.iter().any(|glob| filter::glob(glob, &name))
+        })
+    }
+}
+
+/// A file that appeared or changed on disk, read by [`Library::scan`] so [`Library::merge`] doesn't have to touch
+/// the disk while the state is locked.
+pub enum Scanned {
+    Sidecar(PathBuf),
+    /// `None` if the file is gone again.
+    File(PathBuf, Option<(Entry, Xmp)>),
+}
+
 #[derive(Allocative)]
 pub struct Library {
     pub images: Vec<Image>,
+    /// The folders and files the images were loaded from, the configured libraries or the paths on the command line.
+    #[allocative(skip)]
+    pub roots: Vec<Root>,
+    /// Thumbnails fit in a square of this size.
+    pub thumbnail_size: u32,
+    /// How thumbnails are scaled.
+    pub kernel: Kernel,
+    /// Counts the frames the gallery was drawn in, see [`Image::drawn`].
+    pub frame: u64,
+    #[allocative(skip)]
+    pub queue: Queue,
+    #[allocative(skip)]
+    pub sort: Sort,
+    pub reverse: bool,
+    /// Shuffles [`Sort::Random`] differently every run, while keeping the order stable within one.
+    seed: u64,
+    #[allocative(skip)]
+    index: Index,
+    /// Only some of the files are shown, like paths from the command line, so what's known about the rest is kept.
+    pub partial: bool,
 }
 
 impl Library {
     pub fn new() -> Library {
-        Library { images: Vec::new() }
+        Library {
+            images: Vec::new(),
+            roots: Vec::new(),
+            thumbnail_size: 500,
+            kernel: Kernel::Lanczos3,
+            frame: 0,
+            queue: Queue::default(),
+            sort: Sort::Name,
+            reverse: false,
+            seed: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos() as u64),
+            index: Index::default(),
+            partial: false,
+        }
     }
 
-    pub fn load(&mut self, mut dir: PathBuf) {
-        if Path::new(&dir).is_relative() {
-            if let Ok(home) = std::env::var(if cfg!(windows) { "USERPROFILE" } else { "HOME" }) {
-                dir = PathBuf::from(home).join(dir);
+    fn compare(&self, a: &Image, b: &Image) -> Ordering {
+        let random = |image: &Image| {
+            let mut bytes = image.path.as_os_str().as_encoded_bytes().to_vec();
+            bytes.extend_from_slice(&self.seed.to_le_bytes());
+            hash(&bytes)
+        };
+        let ordering = match self.sort {
+            Sort::Name => Ordering::Equal,
+            Sort::Modified => a.modified.cmp(&b.modified),
+            Sort::Taken => (a.exif.taken.is_none(), a.exif.taken).cmp(&(b.exif.taken.is_none(), b.exif.taken)),
+            Sort::Size => a.len.cmp(&b.len),
+            Sort::Dimensions => {
+                (a.dimensions.x as u64 * a.dimensions.y as u64).cmp(&(b.dimensions.x as u64 * b.dimensions.y as u64))
             }
+            Sort::Random => random(a).cmp(&random(b)),
+        }
+        .then_with(|| natural_cmp(&a.name, &b.name))
+        .then_with(|| a.path.cmp(&b.path));
+        if self.reverse { ordering.reverse() } else { ordering }
+    }
+
+    /// Puts the images in the order of [`Library::sort`], returns the new index of every image by its old one.
+    pub fn sort(&mut self) -> Vec<usize> {
+        let mut images: Vec<(usize, Image)> = std::mem::take(&mut self.images).into_iter().enumerate().collect();
+        images.sort_by(|(_, a), (_, b)| self.compare(a, b));
+        let mut moved = vec![0; images.len()];
+        for (new, &(old, _)) in images.iter().enumerate() {
+            moved[old] = new;
+        }
+        self.images = images.into_iter().map(|(_, image)| image).collect();
+        moved
+    }
+
+    /// Unloads the thumbnails that were drawn the longest time ago until they fit in `budget` bytes.
+    /// Thumbnails drawn in the current frame are kept, even if that goes over the budget.
+    pub fn evict(&mut self, budget: usize) {
+        let frame = self.frame;
+        if memory_used() <= budget {
+            return;
+        }
+        let mut loaded: Vec<usize> = (0..self.images.len())
+            .filter(|&i| {
+                let image = &self.images[i];
+                image.drawn < frame
+                    && image.buffer.try_read().is_ok_and(|buf| buf.as_ref().is_some_and(|b| b.size != Vec2::zero()))
+            })
+            .collect();
+        loaded.sort_by_key(|&i| self.images[i].drawn);
+        for i in loaded {
+            if memory_used() <= budget {
+                break;
+            }
+            self.images[i].unload();
+            EVICTED.fetch_add(1, Relaxed);
+        }
+    }
+
+    fn thumbnail(&self, dimensions: Vec2) -> Vec2 {
+        let (w, h) = (dimensions.x as f32, dimensions.y as f32);
+        let size = self.thumbnail_size as f32;
+        let scale = (size / w).min(size / h);
+        Vec2::from(((w * scale).round() as u32).max(1), ((h * scale).round() as u32).max(1))
+    }
+
+    /// Changes the size of every thumbnail. Thumbnails that are already loaded get rescaled in the
+    /// background, and decoded again if they grew so they don't stay blurry. Queued ones are queued again.
+    pub fn set_thumbnail_size(state: &mut State, size: u32) {
+        if size == state.library.thumbnail_size {
+            return;
+        }
+        let settings = Settings::new(state);
+        let library = &mut state.library;
+        library.thumbnail_size = size;
+        library.queue.cancel_all();
+        for i in 0..library.images.len() {
+            let size = library.thumbnail(library.images[i].dimensions);
+            let image = &mut library.images[i];
+            image.size = size;
+
+            let arc = image.buffer.clone();
+            if !arc.read().is_ok_and(|buf| buf.as_ref().is_some_and(|buf| buf.size != Vec2::zero())) {
+                continue;
+            }
+            let settings = settings.clone();
+            let path = image.path.clone();
+            let orientation = image.exif.orientation;
+            let key = cache::key(&image.path, image.modified, size, settings.kernel);
+            state.thread_pool.execute(move || {
+                let (scaled, grew) = match arc.read() {
+                    Ok(buf) => match buf.as_ref() {
+                        Some(buf) if buf.size != Vec2::zero() && buf.size != size => {
+                            (buf.scale(size, settings.kernel), size.x > buf.size.x)
+                        }
+                        _ => return,
+                    },
+                    Err(_) => return,
+                };
+                if Arc::strong_count(&arc) == 1 {
+                    return;
+                }
+                store(&arc, Some(scaled));
+                lock_state().update();
+                if grew {
+                    Image::load(&settings, path, key, arc, size, orientation);
+                }
+            });
         }
+    }
 
-        for result in match fs::read_dir(&dir) {
+    /// Loads every library directory, only probing files that changed since the index was saved.
+    /// With `rescan` the index is thrown away and every file gets probed again.
+    pub fn load_all(&mut self, roots: Vec<Root>, rescan: bool) {
+        self.images.clear();
+        self.index = Index::load(!self.partial);
+        if rescan {
+            let paths: Vec<PathBuf> = roots.iter().map(|root| Library::resolve(root.path.clone())).collect();
+            self.index.clear(&paths);
+        }
+        self.roots = roots;
+        for root in self.roots.clone() {
+            self.load(root.path);
+        }
+        self.index.save();
+        self.sort();
+    }
+
+    pub fn resolve(dir: PathBuf) -> PathBuf {
+        if dir.is_relative()
+            && let Ok(home) = std::env::var(if cfg!(windows) { "USERPROFILE" } else { "HOME" })
+        {
+            return PathBuf::from(home).join(dir);
+        }
+        dir
+    }
+
+    pub fn load(&mut self, dir: PathBuf) {
+        let dir = Library::resolve(dir);
+        for entry in match fs::read_dir(&dir) {
             Ok(content) => content,
             Err(err) => {
                 match err.kind() {
@@ -129,31 +600,272 @@ impl Library {
                 };
                 return;
             }
-        } {
-            if let Ok(entry) = result {
-                if entry.metadata().is_ok_and(|meta| meta.is_dir()) {
-                    self.load(entry.path());
-                } else {
-                    self.load_file(entry.path());
-                }
+        }
+        .flatten()
+        {
+            if self.roots.iter().any(|root| root.excludes(&entry.path())) {
+                continue;
+            }
+            if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                self.load(entry.path());
+            } else {
+                self.load_file(entry.path());
             }
         }
     }
 
     pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Option<()> {
-        let dimensions = image::ImageReader::open(&path).ok()?
-            .with_guessed_format().ok()?
-            .into_dimensions().ok()?;
+        let image = self.probe(path.as_ref())?;
+        self.images.push(image);
+        Some(())
+    }
+
+    /// Picks up files or directories that appeared on disk, or re-probes files that are already known. They are
+    /// read on the pool and the views hear about them once they are merged.
+    pub fn add(state: &mut State, paths: Vec<PathBuf>) {
+        if paths.is_empty() {
+            return;
+        }
+        let roots = state.library.roots.clone();
+        state.thread_pool.submit(Priority::High, None, move || {
+            let scanned = paths.iter().flat_map(|path| Library::scan(&roots, path)).collect();
+            let state = &mut *lock_state();
+            let changes = state.library.merge(scanned);
+            state.library.save_index();
+            View::changed(state, &changes);
+        });
+    }
 
-        let (w, h) = (dimensions.0 as f32, dimensions.1 as f32);
-        let scale = (500.0 / w).min(500.0 / h);
+    /// Reads a file or everything below a directory without the library, so the state doesn't have to be locked
+    /// while the disk is read. [`Library::merge`] adds the result.
+    pub fn scan(roots: &[Root], path: &Path) -> Vec<Scanned> {
+        if roots.iter().any(|root| root.excludes(path)) {
+            return Vec::new();
+        }
+        if path.is_dir() {
+            let entries = fs::read_dir(path).into_iter().flatten().flatten();
+            return entries.flat_map(|entry| Library::scan(roots, &entry.path())).collect();
+        }
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("xmp")) {
+            return vec![Scanned::Sidecar(path.to_path_buf())];
+        }
+        let file = stat(path).map(|(len, modified)| {
+            let entry = Library::read(path, len, modified);
+            let xmp = if entry.dimensions == Vec2::zero() { Xmp::default() } else { Xmp::read(path) };
+            (entry, xmp)
+        });
+        vec![Scanned::File(path.to_path_buf(), file)]
+    }
 
-        self.images.push(Image {
-            path: path.as_ref().to_path_buf(),
-            name: path.as_ref().file_name()?.to_string_lossy().into(),
+    /// Adds, updates or removes the images that [`Library::scan`] read.
+    pub fn merge(&mut self, scanned: Vec<Scanned>) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for scanned in scanned {
+            match scanned {
+                Scanned::Sidecar(path) => changes.extend(self.sidecar_changed(&path)),
+                Scanned::File(path, file) => {
+                    let image = file.and_then(|(entry, xmp)| {
+                        self.index.insert(path.clone(), entry.clone());
+                        self.image(&path, entry, xmp)
+                    });
+                    changes.extend(self.put(&path, image));
+                }
+            }
+        }
+        changes
+    }
+
+    /// Replaces, removes or inserts the image at `path`.
+    fn put(&mut self, path: &Path, image: Option<Image>) -> Option<Change> {
+        match (self.images.iter().position(|i| i.path == path), image) {
+            (Some(index), Some(mut image)) => {
+                image.selected = self.images[index].selected;
+                self.images[index] = image;
+                Some(Change::Modified(index))
+            }
+            (Some(index), None) => {
+                self.images.remove(index);
+                Some(Change::Removed(index))
+            }
+            (None, Some(image)) => {
+                let index = self.images.partition_point(|i| self.compare(i, &image) == Ordering::Less);
+                self.images.insert(index, image);
+                Some(Change::Added(index))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Reads the sidecar again for the images it belongs to.
+    fn sidecar_changed(&mut self, path: &Path) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for (index, image) in self.images.iter_mut().enumerate() {
+            if xmp::sidecar_for(path, &image.path) == path {
+                let xmp = Xmp::read(&image.path);
+                if xmp != image.xmp {
+                    image.xmp = xmp;
+                    changes.push(Change::Modified(index));
+                }
+            }
+        }
+        changes
+    }
+
+    /// Drops a file, or everything below a directory, that disappeared from disk.
+    pub fn remove(&mut self, path: &Path) -> Vec<Change> {
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("xmp")) {
+            return self.sidecar_changed(path);
+        }
+        let mut changes = Vec::new();
+        for index in (0..self.images.len()).rev() {
+            if self.images[index].path.starts_with(path) {
+                self.images.remove(index);
+                changes.push(Change::Removed(index));
+            }
+        }
+        changes
+    }
+
+    fn probe(&mut self, path: &Path) -> Option<Image> {
+        let (len, modified) = stat(path)?;
+        let entry = match self.index.get(path, len, modified) {
+            Some(entry) => entry,
+            None => {
+                let entry = Library::read(path, len, modified);
+                self.index.insert(path.to_path_buf(), entry.clone());
+                entry
+            }
+        };
+        if entry.dimensions == Vec2::zero() {
+            return None;
+        }
+        self.image(path, entry, Xmp::read(path))
+    }
+
+    /// Reads the header of a file for the index. Files that aren't images get zero dimensions.
+    fn read(path: &Path, len: u64, modified: u64) -> Entry {
+        let (dimensions, exif) = image::ImageReader::open(path).ok()
+            .and_then(|img| img.with_guessed_format().ok())
+            .and_then(|img| img.into_decoder().ok())
+            .map(|mut decoder| {
+                let (w, h) = decoder.dimensions();
+                let exif = decoder.exif_metadata().ok().flatten();
+                let exif = exif.map(|data| Exif::parse(&data)).unwrap_or_default();
+                // Keep the dimensions the image is shown at, so thumbnails of rotated photos fit.
+                let dimensions = if exif.transposed() { Vec2::from(h, w) } else { Vec2::from(w, h) };
+                (dimensions, exif)
+            })
+            .unwrap_or_default();
+        let animated = dimensions != Vec2::zero() && animation::detect(path);
+        Entry::new(len, modified, dimensions, animated, exif)
+    }
+
+    /// The image for a file the index knows, `None` if it isn't one.
+    fn image(&self, path: &Path, entry: Entry, xmp: Xmp) -> Option<Image> {
+        if entry.dimensions == Vec2::zero() {
+            return None;
+        }
+        Some(Image {
+            path: path.to_path_buf(),
+            name: path.file_name()?.to_string_lossy().into(),
             buffer: Arc::new(RwLock::new(None)),
-            size: Vec2::from((w * scale).round() as u32, (h * scale).round() as u32),
+            size: self.thumbnail(entry.dimensions),
+            dimensions: entry.dimensions,
+            modified: entry.modified,
+            len: entry.len,
+            exif: entry.exif,
+            animated: entry.animated,
+            selected: false,
+            xmp,
+            hash: entry.hash,
+            drawn: 0,
+        })
+    }
+
+    /// Hashes the images that don't have a perceptual hash yet in the background, from their thumbnails.
+    /// Each one ends up in `results`, with `None` if it couldn't be decoded. Returns how many were queued.
+    pub fn hash_missing(state: &mut State, token: &Token, results: &Hashes) -> usize {
+        let mut queued = 0;
+        let settings = Settings::new(state);
+        for image in state.library.images.iter().filter(|image| image.hash.is_none()) {
+            let (path, size, orientation) = (image.path.clone(), image.size, image.exif.orientation);
+            let key = cache::key(&path, image.modified, size, settings.kernel);
+            let (settings, results) = (settings.clone(), results.clone());
+            state.thread_pool.submit(Priority::Low, Some(token.clone()), move || {
+                let hash = Image::render(&settings, &path, &key, size, orientation).map(|thumbnail| similar::dhash(&thumbnail));
+                results.lock().unwrap().push((path, hash));
+            });
+            queued += 1;
+        }
+        queued
+    }
+
+    pub fn set_hash(&mut self, index: usize, hash: u64) {
+        let image = &mut self.images[index];
+        image.hash = Some(hash);
+        self.index.set_hash(&image.path, hash);
+    }
+
+    pub fn save_index(&mut self) {
+        self.index.save();
+    }
+
+    /// The indices of the selected images, in library order.
+    pub fn selection(&self) -> Vec<usize> {
+        (0..self.images.len()).filter(|&i| self.images[i].selected).collect()
+    }
+
+    pub fn cache_keys(&self) -> HashSet<String> {
+        self.images
+            .iter()
+            .map(|image| cache::key(&image.path, image.modified, image.size, self.kernel))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::Pool;
+    use std::sync::mpsc;
+
+    #[test]
+    fn zooming_queues_pending_thumbnails_again() {
+        let mut state = State::new();
+        // Keeps the only worker busy, so the thumbnail stays in the queue.
+        state.thread_pool = Pool::new(1);
+        let (started, running) = mpsc::channel();
+        let (release, wait) = mpsc::channel::<()>();
+        state.thread_pool.submit(Priority::High, None, move || {
+            started.send(()).unwrap();
+            let _ = wait.recv();
         });
-        Some(())
+        running.recv().unwrap();
+
+        let path = Path::new("/photos/a.jpg");
+        let entry = Entry::new(1000, 1, Vec2::from(400, 300), false, Exif::default());
+        let image = state.library.image(path, entry, Xmp::default()).unwrap();
+        state.library.images.push(image);
+        let get = |state: &mut State| {
+            let mut image = state.library.images.remove(0);
+            image.get(state, 0);
+            state.library.images.insert(0, image);
+        };
+        get(&mut state);
+        assert_eq!(state.library.queue.queued(), 1);
+
+        let size = state.library.thumbnail_size * 2;
+        Library::set_thumbnail_size(&mut state, size);
+        assert_eq!(state.library.queue.queued(), 0);
+        assert!(state.library.images[0].buffer.read().unwrap().is_none());
+
+        get(&mut state);
+        {
+            let jobs = state.library.queue.0.lock().unwrap();
+            let (image, job) = (&state.library.images[0], &jobs[path]);
+            assert_eq!(job.size, image.size);
+            assert_eq!(job.key, cache::key(path, image.modified, image.size, state.library.kernel));
+        }
+        release.send(()).unwrap();
     }
 }
@@ -1,22 +1,42 @@
 #![feature(portable_simd)]
 
 use crate::buffer::Buffer;
-use crate::config::Config;
+use crate::cli::{Args, Mode};
+use crate::config::{Config, Source, Value};
+use crate::fileops::Operation;
+use crate::filter::Filter;
 use crate::gui::View;
-use crate::library::Library;
-use crate::util::{Pool, Vec2};
+use crate::input::Modifiers;
+use crate::library::{Library, Root};
+use crate::pool::{Pool, Priority};
+use crate::util::Vec2;
+use crate::watch::Watcher;
 use allocative::Allocative;
 use std::env::current_exe;
 use std::path::PathBuf;
-use std::sync::{LazyLock, RwLock};
+use std::sync::{LazyLock, PoisonError, RwLock, RwLockWriteGuard};
 
+mod animation;
+mod bench;
 mod buffer;
+mod cache;
+mod cli;
 mod config;
+mod exif;
+mod fileops;
+mod filter;
+mod font;
 mod gui;
+mod index;
 mod input;
 mod library;
+mod pool;
+mod similar;
+mod text;
 mod window;
 mod util;
+mod watch;
+mod xmp;
 
 #[derive(Allocative)]
 struct State {
@@ -29,6 +49,18 @@ struct State {
     view: View,
     #[allocative(skip)]
     pub thread_pool: Pool,
+    #[allocative(skip)]
+    watcher: Option<Watcher>,
+    #[allocative(skip)]
+    modifiers: Modifiers,
+    /// The last batch of file operations, for Ctrl+Z.
+    #[allocative(skip)]
+    undo: Vec<Operation>,
+    /// Where the config was loaded from, it is reloaded when that file changes.
+    config_path: PathBuf,
+    /// What the command line changed about the config, which reloading keeps.
+    #[allocative(skip)]
+    args: Args,
 }
 
 impl State {
@@ -39,7 +71,13 @@ impl State {
             library: Library::new(),
             buffer: Buffer::new(Vec2::zero()),
             view: View::gallery(),
-            thread_pool: Pool::new(6),
+            // Replaced once the config says how many threads to use.
+            thread_pool: Pool::new(1),
+            watcher: None,
+            modifiers: Modifiers::default(),
+            undo: Vec::new(),
+            config_path: PathBuf::new(),
+            args: Args::default(),
         }
     }
 
@@ -50,14 +88,91 @@ impl State {
 
 static GLOBAL_STATE: LazyLock<RwLock<State>> = LazyLock::new(|| RwLock::new(State::new()));
 
+/// Locks the state. When a job panicked while holding it, the pool keeps going and so does everything else
+/// with the state as the job left it, rather than every later lock panicking too.
+fn lock_state() -> RwLockWriteGuard<'static, State> {
+    GLOBAL_STATE.write().unwrap_or_else(PoisonError::into_inner)
+}
+
 fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => return,
+        Err(err) => {
+            eprintln!("{err}\nTry 'nanogallery --help' for more information.");
+            std::process::exit(2);
+        }
+    };
+
     let exe = current_exe().unwrap();
     let dir: PathBuf = exe.parent().unwrap().to_path_buf();
-    let mut state = GLOBAL_STATE.write().unwrap();
-    state.config.load(dir.join("nanogallery.cfg"));
-    for lib in state.config.libraries.clone().iter() {
-        state.library.load(PathBuf::from(lib));
+    let mut state = lock_state();
+    let config_path = args.config.clone().unwrap_or_else(|| dir.join("nanogallery.cfg"));
+    state.config_path = std::path::absolute(&config_path).unwrap_or(config_path);
+    let config_path = state.config_path.clone();
+    state.config.load(&config_path);
+    for (key, value) in args.overrides.iter() {
+        if let Err(err) = state.config.set(key, &Value::from_arg(value)) {
+            eprintln!("--set {key}={value}: {err}");
+        }
+    }
+    if let Some(image) = &args.bench {
+        bench::scale(image, args.bench_width, state.config.threads as usize);
+        return;
     }
+
+    // Paths on the command line are shown instead of the libraries, which are left as they are in the config.
+    let (roots, watched): (Vec<Root>, Vec<PathBuf>) = if args.paths.is_empty() {
+        let libraries = &state.config.libraries;
+        let path = |lib: &Source| Library::resolve(PathBuf::from(&lib.path));
+        let roots = libraries.iter().map(|lib| Root::new(path(lib), &lib.exclude));
+        (roots.collect(), libraries.iter().filter(|lib| lib.watch).map(path).collect())
+    } else {
+        (args.paths.iter().map(|path| Root::new(path.clone(), &[])).collect(), args.paths.clone())
+    };
+    state.thread_pool = Pool::new(state.config.threads as usize);
+    state.library.thumbnail_size = state.config.thumbnail_size;
+    state.library.kernel = state.config.thumbnail_filter;
+    state.library.sort = state.config.sort;
+    state.library.reverse = state.config.sort_reverse;
+    state.library.partial = !args.paths.is_empty();
+    state.library.load_all(roots, false);
+    state.watcher = Watcher::new();
+    if let Some(watcher) = &state.watcher {
+        for root in watched.iter().filter(|root| root.is_dir()) {
+            watcher.add(root);
+        }
+        watcher.config(&config_path);
+    }
+    // Thumbnails of the libraries that weren't loaded would look stale.
+    if !state.library.partial {
+        let keys = state.library.cache_keys();
+        state.thread_pool.submit(Priority::Low, None, move || cache::clean(keys));
+    }
+
+    if args.mode != Mode::Gallery && !state.library.images.is_empty() {
+        let images = &state.library.images;
+        let start = args.start.as_ref().and_then(|start| images.iter().position(|image| image.path == *start));
+        if let Some(path) = &args.start
+            && start.is_none()
+        {
+            eprintln!("{} is not an image in the library", path.display());
+        }
+        let index = start.unwrap_or(0);
+        let view = match args.mode {
+            Mode::Slideshow => View::slideshow(index, Filter::default()),
+            _ => View::viewer(index, Filter::default()),
+        };
+        state.view.open(view);
+        View::navigate(&mut state);
+    }
+    state.args = args;
     drop(state);
     window::create();
+
+    // The jobs that are still running need the state to finish, so the workers are joined without holding it.
+    let workers = lock_state().thread_pool.shutdown();
+    for worker in workers {
+        let _ = worker.join();
+    }
 }
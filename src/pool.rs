@@ -0,0 +1,235 @@
+use std::collections::BinaryHeap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering::Relaxed};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+}
+
+/// Lets a queued job be dropped before it runs. Jobs that already started aren't interrupted.
+#[derive(Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn cancel(&self) {
+        self.0.store(true, Relaxed);
+    }
+
+    pub fn cancelled(&self) -> bool {
+        self.0.load(Relaxed)
+    }
+}
+
+struct Queued {
+    priority: Priority,
+    /// Keeps jobs with the same priority in the order they were queued.
+    order: u64,
+    token: Option<Token>,
+    job: Job,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.order == other.order
+    }
+}
+
+impl Eq for Queued {}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority.cmp(&other.priority).then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+#[derive(Default)]
+struct Jobs {
+    queue: BinaryHeap<Queued>,
+    order: u64,
+    shutdown: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    jobs: Mutex<Jobs>,
+    available: Condvar,
+    running: AtomicUsize,
+    completed: AtomicUsize,
+    panicked: AtomicUsize,
+}
+
+impl Shared {
+    fn push(&self, priority: Priority, token: Option<Token>, job: Job) {
+        let mut jobs = self.jobs.lock().unwrap();
+        if jobs.shutdown {
+            return;
+        }
+        jobs.order += 1;
+        let order = jobs.order;
+        jobs.queue.push(Queued { priority, order, token, job });
+        self.available.notify_one();
+    }
+}
+
+/// A fixed number of worker threads that run the most important queued job first.
+pub struct Pool {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+/// Lets jobs, which can't reach the pool through the state, spread work over the workers.
+#[derive(Clone)]
+pub struct Handle {
+    shared: Arc<Shared>,
+    workers: usize,
+}
+
+impl Pool {
+    /// Starts `size` workers, or one per CPU if `size` is zero.
+    pub fn new(size: usize) -> Pool {
+        let size = match size {
+            0 => thread::available_parallelism().map_or(4, |n| n.get()),
+            size => size,
+        };
+        let shared = Arc::new(Shared::default());
+        let workers = (0..size)
+            .filter_map(|i| {
+                let shared = shared.clone();
+                thread::Builder::new()
+                    .name(format!("worker {i}"))
+                    .spawn(move || Pool::work(&shared))
+                    .map_err(|err| eprintln!("Failed to start worker: {}", err))
+                    .ok()
+            })
+            .collect();
+        Pool { shared, workers }
+    }
+
+    fn work(shared: &Shared) {
+        loop {
+            let job = {
+                let mut jobs = shared.jobs.lock().unwrap();
+                loop {
+                    if jobs.shutdown {
+                        return;
+                    }
+                    match jobs.queue.pop() {
+                        Some(job) if job.token.as_ref().is_some_and(Token::cancelled) => continue,
+                        Some(job) => break job,
+                        None => jobs = shared.available.wait(jobs).unwrap(),
+                    }
+                }
+            };
+
+            shared.running.fetch_add(1, Relaxed);
+            // A corrupt file can make a decoder panic, that shouldn't take the worker with it.
+            if panic::catch_unwind(AssertUnwindSafe(job.job)).is_err() {
+                shared.panicked.fetch_add(1, Relaxed);
+            }
+            shared.running.fetch_sub(1, Relaxed);
+            shared.completed.fetch_add(1, Relaxed);
+        }
+    }
+
+    pub fn execute<F: FnOnce() + Send + 'static>(&mut self, f: F) {
+        self.submit(Priority::Normal, None, f);
+    }
+
+    /// Queues `f` to run before every job with a lower priority, unless `token` is cancelled first.
+    pub fn submit<F: FnOnce() + Send + 'static>(&mut self, priority: Priority, token: Option<Token>, f: F) {
+        self.shared.push(priority, token, Box::new(f));
+    }
+
+    pub fn handle(&self) -> Handle {
+        Handle { shared: self.shared.clone(), workers: self.workers.len() }
+    }
+
+    /// Drops the jobs that haven't started yet and lets the workers exit once the running ones are done.
+    /// Joining the workers it returns waits for that, so it mustn't happen while holding what those jobs need.
+    pub fn shutdown(&mut self) -> Vec<JoinHandle<()>> {
+        {
+            let mut jobs = self.shared.jobs.lock().unwrap();
+            jobs.shutdown = true;
+            jobs.queue.clear();
+        }
+        self.shared.available.notify_all();
+        std::mem::take(&mut self.workers)
+    }
+
+    pub fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    pub fn queued(&self) -> usize {
+        self.shared.jobs.lock().unwrap().queue.len()
+    }
+
+    pub fn running(&self) -> usize {
+        self.shared.running.load(Relaxed)
+    }
+
+    pub fn completed(&self) -> usize {
+        self.shared.completed.load(Relaxed)
+    }
+
+    pub fn panicked(&self) -> usize {
+        self.shared.panicked.load(Relaxed)
+    }
+}
+
+/// A pool that is replaced doesn't leave its workers waiting for jobs forever.
+impl Drop for Pool {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl Handle {
+    /// Calls `f` with every index below `count`, spread over the workers with the calling thread helping out.
+    /// Indices are only handed out when someone is ready to work on them, so this never waits for a job that
+    /// hasn't started. Busy workers just leave more for the caller, which makes it safe to call from a job.
+    pub fn parallel<F: Fn(usize) + Send + Sync + 'static>(&self, count: usize, f: F) {
+        let next = Arc::new(AtomicUsize::new(0));
+        // How many indices are done, and whether any of them panicked.
+        let finished = Arc::new((Mutex::new((0, false)), Condvar::new()));
+        let run = {
+            let (f, finished) = (Arc::new(f), finished.clone());
+            move || loop {
+                let i = next.fetch_add(1, Relaxed);
+                if i >= count {
+                    return;
+                }
+                let panicked = panic::catch_unwind(AssertUnwindSafe(|| f(i))).is_err();
+                let mut state = finished.0.lock().unwrap();
+                state.0 += 1;
+                state.1 |= panicked;
+                finished.1.notify_all();
+            }
+        };
+        for _ in 1..count.min(self.workers + 1) {
+            self.shared.push(Priority::High, None, Box::new(run.clone()));
+        }
+        run();
+
+        let mut state = finished.0.lock().unwrap();
+        while state.0 < count {
+            state = finished.1.wait(state).unwrap();
+        }
+        if state.1 {
+            panic!("A parallel job panicked");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::buffer::{BufferView, Kernel};
+use crate::Vec2;
+
+/// A difference hash: the image shrunk to 9x8 pixels, one bit per pair of neighbours in a row that is set where
+/// the left one is brighter. Resizing, recompressing and small edits change only a few bits.
+pub fn dhash(image: &impl BufferView) -> u64 {
+    let small = image.scale(Vec2::from(9, 8), Kernel::Box);
+    let luma = |x: u32, y: u32| {
+        let color = small.get(Vec2::from(x, y));
+        ((color >> 16) & 0xFF) * 299 + ((color >> 8) & 0xFF) * 587 + (color & 0xFF) * 114
+    };
+    let mut hash = 0;
+    for y in 0..8 {
+        for x in 0..8 {
+            hash = (hash << 1) | (luma(x, y) > luma(x + 1, y)) as u64;
+        }
+    }
+    hash
+}
+
+/// How many bits two hashes differ in.
+pub fn distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Groups the indices whose hashes are at most `threshold` bits apart, also through other members of the group.
+/// Only groups of at least two are returned, ordered by their first index.
+pub fn groups(hashes: &[(usize, u64)], threshold: u32) -> Vec<Vec<usize>> {
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    // Union-find over every pair. Comparing hashes is cheap enough that this stays fast for tens of thousands.
+    let mut parent: Vec<usize> = (0..hashes.len()).collect();
+    for a in 0..hashes.len() {
+        for b in a + 1..hashes.len() {
+            if distance(hashes[a].1, hashes[b].1) <= threshold {
+                let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+                parent[ra.max(rb)] = ra.min(rb);
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); hashes.len()];
+    for (i, &(index, _)) in hashes.iter().enumerate() {
+        let r = root(&mut parent, i);
+        groups[r].push(index);
+    }
+    groups.retain(|group| group.len() > 1);
+    for group in groups.iter_mut() {
+        group.sort_unstable();
+    }
+    groups.sort_by_key(|group| group[0]);
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    /// The same gray pattern at any resolution, getting brighter to the right and down with a few hard edges.
+    fn pattern(size: u32) -> Buffer {
+        let data = (0..size * size)
+            .map(|i| {
+                let (x, y) = ((i % size) as f32 / size as f32, (i / size) as f32 / size as f32);
+                let v = ((x * 0.7 + y * 0.3) * 200.0) as u32 ^ ((x * 4.0) as u32 * 40);
+                0xFF000000 | (v << 16) | (v << 8) | v
+            })
+            .collect();
+        Buffer { size: Vec2::from(size, size), data }
+    }
+
+    #[test]
+    fn dhash_survives_resizing() {
+        let large = dhash(&pattern(256));
+        assert!(distance(large, dhash(&pattern(64))) <= 4);
+        let mut flipped = pattern(256);
+        flipped.data.reverse();
+        assert!(distance(large, dhash(&flipped)) > 16);
+    }
+
+    #[test]
+    fn groups_within_the_threshold() {
+        let hashes = [(10, 0b0000), (11, 0b1111_0000), (12, 0b0001), (13, u64::MAX)];
+        assert_eq!(groups(&hashes, 1), [vec![10, 12]]);
+        assert_eq!(groups(&hashes, 0), Vec::<Vec<usize>>::new());
+        assert_eq!(groups(&hashes, 5), [vec![10, 11, 12]]);
+        assert_eq!(groups(&hashes, 64), [vec![10, 11, 12, 13]]);
+        assert_eq!(groups(&[], 10), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn groups_chain_through_members() {
+        // 0 and 2 are 4 bits apart, but both are within 2 of 1.
+        let hashes = [(0, 0b0000), (1, 0b0011), (2, 0b1111)];
+        assert_eq!(groups(&hashes, 2), [vec![0, 1, 2]]);
+        assert_eq!(groups(&hashes, 1), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn groups_are_sorted() {
+        let hashes = [(9, 0xFF), (7, 0), (8, 0xFF), (3, 0), (5, 0xF0F0)];
+        assert_eq!(groups(&hashes, 0), [vec![3, 7], vec![8, 9]]);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::buffer::Buffer;
+use crate::font::{Glyph, COVERAGE, GLYPHS};
+use crate::Vec2;
+
+pub const LINE_HEIGHT: u32 = crate::font::HEIGHT;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+fn glyph(c: char) -> &'static Glyph {
+    let c = if c.is_control() { ' ' } else { c };
+    match GLYPHS.binary_search_by_key(&c, |g| g.char) {
+        Ok(i) => &GLYPHS[i],
+        Err(_) => glyph('\u{FFFD}'),
+    }
+}
+
+/// Returns how wide `text` is in pixels on a single line.
+pub fn measure(text: &str) -> u32 {
+    text.chars().map(|c| glyph(c).advance as u32).sum()
+}
+
+/// Cuts `text` off with an ellipsis so it fits in `width` pixels.
+pub fn truncate(text: &str, width: u32) -> String {
+    if measure(text) <= width {
+        return text.to_string();
+    }
+    let mut used = glyph('…').advance as u32;
+    let mut result = String::new();
+    for c in text.chars() {
+        used += glyph(c).advance as u32;
+        if used > width {
+            break;
+        }
+        result.push(c);
+    }
+    result.push('…');
+    result
+}
+
+impl Buffer {
+    /// Draws a single line of text into the box at `x`, `y` that is `width` pixels wide and
+    /// [`LINE_HEIGHT`] tall. Text that doesn't fit is cut off with an ellipsis and nothing is
+    /// drawn outside of the box.
+    pub fn text(&mut self, text: &str, x: i32, y: i32, width: u32, align: Align, color: u32) {
+        let text = truncate(text, width);
+        let left = match align {
+            Align::Left => x,
+            Align::Center => x + (width as i32 - measure(&text) as i32) / 2,
+            Align::Right => x + width as i32 - measure(&text) as i32,
+        };
+
+        let min_x = x.max(0);
+        let max_x = (x + width as i32).min(self.size.x as i32);
+        let min_y = y.max(0);
+        let max_y = (y + LINE_HEIGHT as i32).min(self.size.y as i32);
+
+        let mut pen = left;
+        for c in text.chars() {
+            let glyph = glyph(c);
+            for row in 0..glyph.height as i32 {
+                let py = y + glyph.y as i32 + row;
+                if py < min_y || py >= max_y {
+                    continue;
+                }
+                for col in 0..glyph.width as i32 {
+                    let px = pen + glyph.x as i32 + col;
+                    if px < min_x || px >= max_x {
+                        continue;
+                    }
+                    let nibble = glyph.offset as usize + (row * glyph.width as i32 + col) as usize;
+                    let byte = COVERAGE[nibble / 2];
+                    let coverage = if nibble.is_multiple_of(2) { byte >> 4 } else { byte & 0xF };
+                    if coverage > 0 {
+                        self.set_transparent(Vec2::from(px as u32, py as u32), color, coverage as f32 / 15.0);
+                    }
+                }
+            }
+            pen += glyph.advance as i32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyphs_are_sorted() {
+        // `glyph` finds them with a binary search.
+        assert!(GLYPHS.is_sorted_by_key(|g| g.char));
+        for g in GLYPHS.iter() {
+            assert_eq!(glyph(g.char).char, g.char);
+        }
+        assert_eq!(glyph('\u{E000}').char, '\u{FFFD}');
+    }
+}
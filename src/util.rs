@@ -1,7 +1,5 @@
 use allocative::Allocative;
 use std::cmp::Ordering;
-use std::sync::{mpsc, Arc, Mutex};
-use std::thread;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Allocative)]
 pub struct Vec2 {
@@ -18,17 +16,17 @@ impl Vec2 {
         Vec2 { x, y }
     }
 
-    pub fn add(self, other: u32) -> Self {
+    pub fn sub(self, other: u32) -> Self {
         Vec2 {
-            x: self.x + other,
-            y: self.y + other,
+            x: self.x - other,
+            y: self.y - other,
         }
     }
 
-    pub fn sub(self, other: u32) -> Self {
+    pub fn add2(self, x: u32, y: u32) -> Self {
         Vec2 {
-            x: self.x - other,
-            y: self.y - other,
+            x: self.x + x,
+            y: self.y + y,
         }
     }
 
@@ -58,31 +56,64 @@ impl PartialOrd for Vec2 {
     }
 }
 
-pub struct Pool {
-    size: u8,
-    max_size: u8,
-    sender: mpsc::Sender<Box<dyn FnOnce() + Send + 'static>>,
-    receiver: Arc<Mutex<mpsc::Receiver<Box<dyn FnOnce() + Send + 'static>>>>,
+pub fn hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xCBF29CE484222325;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001B3);
+    }
+    hash
 }
 
-impl Pool {
-    pub fn new(size: u8) -> Pool {
-        let (sender, receiver) = mpsc::channel();
-        let receiver = Arc::new(Mutex::new(receiver));
-
-        Pool { size: 0, max_size: size, sender, receiver }
-    }
+/// Seconds since the Unix epoch as the digits of `YYYYMMDDhhmmss` in UTC, like [`crate::exif::Exif::taken`].
+pub fn date(secs: i64) -> u64 {
+    let (days, time) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+    // Howard Hinnant's days_from_civil, backwards.
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    let date = (year * 10000 + month * 100 + day) as u64;
+    date * 1000000 + (time / 3600 * 10000 + time / 60 % 60 * 100 + time % 60) as u64
+}
 
-    pub fn execute<F: FnOnce() + Send + 'static>(&mut self, f: F) {
-        if self.size == 0 || self.size <= self.max_size && self.receiver.try_lock().is_ok() {
-            let receiver = self.receiver.clone();
-            self.size += 1;
-            thread::spawn(move || {
-                loop {
-                    receiver.lock().unwrap().recv().unwrap()();
+/// Compares strings the way people expect, ignoring case and treating runs of digits as numbers.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(c);
+                    }
+                    digits
+                };
+                let (x, y) = (take(&mut a), take(&mut b));
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
                 }
-            });
+                a.next();
+                b.next();
+            }
         }
-        self.sender.send(Box::new(f)).unwrap();
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,184 @@
+use std::path::Path;
+
+#[cfg(target_os = "linux")]
+pub use linux::Watcher;
+
+/// Watches directories for files being added, removed, renamed or modified.
+#[cfg(not(target_os = "linux"))]
+pub struct Watcher;
+
+#[cfg(not(target_os = "linux"))]
+impl Watcher {
+    pub fn new() -> Option<Watcher> {
+        None
+    }
+
+    pub fn add(&self, _dir: &Path) {}
+
+    pub fn remove(&self, _dir: &Path) {}
+
+    pub fn config(&self, _path: &Path) {}
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::Path;
+    use crate::config;
+    use crate::gui::View;
+    use crate::library::{Library, Scanned};
+    use crate::lock_state;
+    use inotify::{EventMask, Inotify, WatchDescriptor, WatchMask, Watches};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Watches directories for files being added, removed, renamed or modified.
+    pub struct Watcher {
+        watches: Watches,
+        dirs: Arc<Mutex<HashMap<WatchDescriptor, PathBuf>>>,
+        /// The config file and the watch on its directory, which may be a library directory as well.
+        config: Arc<Mutex<Option<(WatchDescriptor, PathBuf)>>>,
+    }
+
+    /// What a batch of events found on disk, to apply to the library in order.
+    enum Found {
+        Removed(PathBuf),
+        Scanned(Vec<Scanned>),
+    }
+
+    /// The same for every directory, because watching a directory again replaces its mask.
+    const MASK: WatchMask = WatchMask::CREATE
+        .union(WatchMask::CLOSE_WRITE)
+        .union(WatchMask::MOVED_TO)
+        .union(WatchMask::MOVED_FROM)
+        .union(WatchMask::DELETE)
+        .union(WatchMask::ONLYDIR);
+
+    impl Watcher {
+        pub fn new() -> Option<Watcher> {
+            let mut inotify = match Inotify::init() {
+                Ok(inotify) => inotify,
+                Err(err) => {
+                    eprintln!("Failed to watch library: {}", err);
+                    return None;
+                }
+            };
+            let watcher = Watcher {
+                watches: inotify.watches(),
+                dirs: Arc::new(Mutex::new(HashMap::new())),
+                config: Arc::new(Mutex::new(None)),
+            };
+
+            let handle = Watcher {
+                watches: watcher.watches.clone(),
+                dirs: watcher.dirs.clone(),
+                config: watcher.config.clone(),
+            };
+            thread::spawn(move || {
+                let mut buffer = [0; 4096];
+                loop {
+                    let events = match inotify.read_events_blocking(&mut buffer) {
+                        Ok(events) => events,
+                        Err(err) => {
+                            eprintln!("Stopped watching library: {}", err);
+                            return;
+                        }
+                    };
+
+                    // Files are read before the state is locked, so the window doesn't wait on the disk.
+                    let mut roots = None;
+                    let mut found = Vec::new();
+                    let mut reload = false;
+                    for event in events {
+                        if event.mask.contains(EventMask::IGNORED) {
+                            handle.dirs.lock().unwrap().remove(&event.wd);
+                            continue;
+                        }
+                        // Editors either write the file or move a new one over it.
+                        if let Some((wd, path)) = &*handle.config.lock().unwrap()
+                            && *wd == event.wd
+                            && event.name.is_some_and(|name| path.file_name() == Some(name))
+                            && event.mask.intersects(EventMask::CLOSE_WRITE | EventMask::MOVED_TO)
+                        {
+                            reload = true;
+                        }
+                        let Some(dir) = handle.dirs.lock().unwrap().get(&event.wd).cloned() else {
+                            continue;
+                        };
+                        let Some(name) = event.name else {
+                            continue;
+                        };
+
+                        let path = dir.join(name);
+                        let roots = roots.get_or_insert_with(|| lock_state().library.roots.clone());
+                        if event.mask.intersects(EventMask::DELETE | EventMask::MOVED_FROM) {
+                            found.push(Found::Removed(path));
+                        } else if event.mask.contains(EventMask::ISDIR) {
+                            handle.add(&path);
+                            found.push(Found::Scanned(Library::scan(roots, &path)));
+                        } else if event.mask.intersects(EventMask::CLOSE_WRITE | EventMask::MOVED_TO) {
+                            found.push(Found::Scanned(Library::scan(roots, &path)));
+                        }
+                    }
+
+                    let state = &mut *lock_state();
+                    let mut changes = Vec::new();
+                    for found in found {
+                        changes.extend(match found {
+                            Found::Removed(path) => state.library.remove(&path),
+                            Found::Scanned(scanned) => state.library.merge(scanned),
+                        });
+                    }
+                    View::changed(state, &changes);
+                    if reload {
+                        config::reload(state);
+                    }
+                }
+            });
+            Some(watcher)
+        }
+
+        /// Watches `dir` and all of its subdirectories.
+        pub fn add(&self, dir: &Path) {
+            let wd = match self.watches.clone().add(dir, MASK) {
+                Ok(wd) => wd,
+                Err(_) => return,
+            };
+            self.dirs.lock().unwrap().insert(wd, dir.to_path_buf());
+
+            for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+                if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                    self.add(&entry.path());
+                }
+            }
+        }
+
+        /// Stops watching `dir` and all of its subdirectories.
+        pub fn remove(&self, dir: &Path) {
+            let config = self.config.lock().unwrap().as_ref().map(|(wd, _)| wd.clone());
+            let mut watches = self.watches.clone();
+            self.dirs.lock().unwrap().retain(|wd, path| {
+                if !path.starts_with(dir) {
+                    return true;
+                }
+                if config.as_ref() != Some(wd) {
+                    let _ = watches.remove(wd.clone());
+                }
+                false
+            });
+        }
+
+        /// Reloads the config whenever the file at `path` is saved.
+        pub fn config(&self, path: &Path) {
+            let Some(dir) = path.parent() else {
+                return;
+            };
+            match self.watches.clone().add(dir, MASK) {
+                Ok(wd) => *self.config.lock().unwrap() = Some((wd, path.to_path_buf())),
+                Err(err) => eprintln!("Failed to watch {}: {}", path.display(), err),
+            }
+        }
+    }
+}
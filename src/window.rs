@@ -1,8 +1,9 @@
 use crate::buffer::Buffer;
 use crate::gui::View;
-use crate::input::Input;
-use crate::{Vec2, GLOBAL_STATE};
+use crate::input::{Input, Mouse, Pointer};
+use crate::{lock_state, Vec2};
 use minifb::{Window, WindowOptions};
+use std::time::Instant;
 
 pub fn create() {
     let mut window = Window::new(
@@ -18,10 +19,12 @@ pub fn create() {
 
     window.set_input_callback(Box::new(Input {}));
     window.set_target_fps(60);
+    let mut pointer = Pointer::default();
+    let mut last_frame = Instant::now();
 
     while window.is_open() {
         let buffer_opt = {
-            let state = &mut *GLOBAL_STATE.write().unwrap();
+            let state = &mut *lock_state();
             let (width, height) = window.get_size();
             let width = width.clamp(100, 1920) as u32;
             let height = height.clamp(100, 1080) as u32;
@@ -33,14 +36,28 @@ pub fn create() {
                 state.update();
             }
 
-            window.get_scroll_wheel().map(|(_, vertical)| {
-                state.view.scroll = if state.view.height <= state.buffer.size.x
-                    || vertical * 9.0 >= state.view.scroll as f32 { 0 } else {
-                    (state.view.scroll as f32 - vertical * 9.0)
-                        .min((state.view.height as f32) - state.buffer.size.y as f32) as u32
-                };
+            for event in pointer.poll(&window, size) {
+                View::mouse(state, event);
+            }
+
+            if let Some((_, vertical)) = window.get_scroll_wheel() {
+                if !View::scroll(state, vertical) {
+                    state.view.scroll = if state.view.height <= state.buffer.size.x
+                        || vertical * 9.0 >= state.view.scroll as f32 { 0 } else {
+                        (state.view.scroll as f32 - vertical * 9.0)
+                            .min((state.view.height as f32) - state.buffer.size.y as f32) as u32
+                    };
+                }
+                if let Some(pos) = pointer.pos {
+                    // The content moved underneath the mouse, so hover has to be recomputed.
+                    View::mouse(state, Mouse::Move(pos));
+                }
                 state.update();
-            });
+            }
+
+            let now = Instant::now();
+            View::tick(state, now - last_frame);
+            last_frame = now;
 
             if state.dirty {
                 state.dirty = false;
@@ -55,7 +72,7 @@ pub fn create() {
             window
                 .update_with_buffer(&buffer.data, buffer.size.x as usize, buffer.size.y as usize)
                 .unwrap();
-            GLOBAL_STATE.write().unwrap().buffer = buffer;
+            lock_state().buffer = buffer;
         } else {
             window.update();
         }
@@ -0,0 +1,206 @@
+use allocative::Allocative;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Copy, Clone, PartialEq, Debug, Allocative)]
+pub enum Label {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl Label {
+    pub const ALL: [Label; 5] = [Label::Red, Label::Yellow, Label::Green, Label::Blue, Label::Purple];
+
+    /// The name Lightroom and darktable use in `xmp:Label`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Label::Red => "Red",
+            Label::Yellow => "Yellow",
+            Label::Green => "Green",
+            Label::Blue => "Blue",
+            Label::Purple => "Purple",
+        }
+    }
+
+    pub fn color(self) -> u32 {
+        match self {
+            Label::Red => 0xFFE53935,
+            Label::Yellow => 0xFFFDD835,
+            Label::Green => 0xFF43A047,
+            Label::Blue => 0xFF1E88E5,
+            Label::Purple => 0xFF8E24AA,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Debug, Allocative)]
+pub enum Flag {
+    #[default]
+    None,
+    Pick,
+    Reject,
+}
+
+/// The culling metadata that is kept in `.xmp` sidecar files.
+#[derive(Clone, Default, PartialEq, Debug, Allocative)]
+pub struct Xmp {
+    /// From 0 to 5 stars.
+    pub rating: u8,
+    pub label: Option<Label>,
+    pub flag: Flag,
+}
+
+const XMP: &str = "http://ns.adobe.com/xap/1.0/";
+const XMP_DM: &str = "http://ns.adobe.com/xmp/1.0/DynamicMedia/";
+/// Where the rating of a rejected image is kept, since `xmp:Rating` holds -1 then.
+const NANOGALLERY: &str = "urn:nanogallery:xmp:1.0:";
+
+/// darktable names sidecars `photo.jpg.xmp`, Lightroom `photo.xmp`. Returns the one that exists.
+pub fn sidecar(image: &Path) -> Option<PathBuf> {
+    let mut appended = image.as_os_str().to_os_string();
+    appended.push(".xmp");
+    [PathBuf::from(appended), image.with_extension("xmp")].into_iter().find(|path| path.is_file())
+}
+
+/// Where `sidecar` has to go when its image goes to `image`, keeping the naming style.
+pub fn sidecar_for(sidecar: &Path, image: &Path) -> PathBuf {
+    let stem = sidecar.file_stem().map(PathBuf::from).unwrap_or_default();
+    if stem.extension().is_some() {
+        let mut appended = image.as_os_str().to_os_string();
+        appended.push(".xmp");
+        PathBuf::from(appended)
+    } else {
+        image.with_extension("xmp")
+    }
+}
+
+/// Finds a property, written either as an attribute or as an element.
+fn property<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    for quote in ['"', '\''] {
+        if let Some(start) = xml.find(&format!("{name}={quote}")) {
+            let value = &xml[start + name.len() + 2..];
+            return value.split(quote).next();
+        }
+    }
+    let start = xml.find(&format!("<{name}>"))? + name.len() + 2;
+    let end = xml[start..].find(&format!("</{name}>"))?;
+    Some(xml[start..start + end].trim())
+}
+
+/// Removes a property in either form.
+fn remove(xml: &mut String, name: &str) {
+    for quote in ['"', '\''] {
+        let pattern = format!("{name}={quote}");
+        while let Some(start) = xml.find(&pattern) {
+            let Some(len) = xml[start + pattern.len()..].find(quote) else {
+                break;
+            };
+            let end = start + pattern.len() + len + 1;
+            let start = xml[..start].trim_end().len();
+            xml.replace_range(start..end, "");
+        }
+    }
+    let (open, close) = (format!("<{name}>"), format!("</{name}>"));
+    while let Some(start) = xml.find(&open)
+        && let Some(len) = xml[start..].find(&close)
+    {
+        xml.replace_range(start..start + len + close.len(), "");
+    }
+}
+
+impl Xmp {
+    /// Reads the sidecar of `image`, if it has one.
+    pub fn read(image: &Path) -> Xmp {
+        let Some(xml) = sidecar(image).and_then(|path| fs::read_to_string(path).ok()) else {
+            return Xmp::default();
+        };
+        let number = |name| property(&xml, name).and_then(|r| r.parse::<i32>().ok()).unwrap_or(0);
+        let mut rating = number("xmp:Rating");
+        let flag = if rating < 0 {
+            rating = number("nanogallery:Rating");
+            Flag::Reject
+        } else if property(&xml, "xmpDM:pick") == Some("1") {
+            Flag::Pick
+        } else {
+            Flag::None
+        };
+        Xmp {
+            rating: rating.clamp(0, 5) as u8,
+            label: property(&xml, "xmp:Label").and_then(|name| Label::ALL.into_iter().find(|l| l.name() == name)),
+            flag,
+        }
+    }
+
+    /// Writes the sidecar of `image`, keeping whatever else other programs put in an existing one.
+    pub fn write(&self, image: &Path) -> io::Result<()> {
+        let path = sidecar(image).unwrap_or_else(|| image.with_extension("xmp"));
+        let mut xml = fs::read_to_string(&path).unwrap_or_default();
+        if !xml.contains("<rdf:Description") {
+            xml = "<?xpacket begin=\"\u{FEFF}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n\
+                   <x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n \
+                   <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n  \
+                   <rdf:Description rdf:about=\"\"/>\n \
+                   </rdf:RDF>\n\
+                   </x:xmpmeta>\n\
+                   <?xpacket end=\"w\"?>\n"
+                .to_string();
+        }
+
+        for name in ["xmp:Rating", "xmp:Label", "xmpDM:pick", "nanogallery:Rating"] {
+            remove(&mut xml, name);
+        }
+        let mut attributes = Vec::new();
+        for (prefix, namespace) in [("xmp", XMP), ("xmpDM", XMP_DM), ("nanogallery", NANOGALLERY)] {
+            if !xml.contains(&format!("xmlns:{prefix}=")) {
+                attributes.push(format!("xmlns:{prefix}=\"{namespace}\""));
+            }
+        }
+        // Lightroom and darktable both read a rating of -1 as rejected, the stars stay in our own property.
+        let rating = if self.flag == Flag::Reject { -1 } else { self.rating as i32 };
+        attributes.push(format!("xmp:Rating=\"{rating}\""));
+        if self.flag == Flag::Reject && self.rating > 0 {
+            attributes.push(format!("nanogallery:Rating=\"{}\"", self.rating));
+        }
+        if let Some(label) = self.label {
+            attributes.push(format!("xmp:Label=\"{}\"", label.name()));
+        }
+        // Only this program reads picks back: Lightroom keeps them in its catalog rather than in sidecars,
+        // and darktable has no picks at all.
+        if self.flag == Flag::Pick {
+            attributes.push("xmpDM:pick=\"1\"".to_string());
+        }
+
+        let at = xml.find("<rdf:Description").unwrap_or(0) + "<rdf:Description".len();
+        let inserted: String = attributes.iter().map(|a| format!("\n    {a}")).collect();
+        xml.insert_str(at, &inserted);
+        fs::write(path, xml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejecting_keeps_the_rating() {
+        let dir = std::env::temp_dir().join(format!("nanogallery-xmp-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let image = dir.join("photo.jpg");
+        for xmp in [
+            Xmp { rating: 4, label: Some(Label::Green), flag: Flag::Reject },
+            Xmp { rating: 0, label: None, flag: Flag::Reject },
+            Xmp { rating: 4, label: None, flag: Flag::Pick },
+            Xmp { rating: 2, label: None, flag: Flag::None },
+        ] {
+            xmp.write(&image).unwrap();
+            assert_eq!(Xmp::read(&image), xmp);
+        }
+        let xml = fs::read_to_string(image.with_extension("xmp")).unwrap();
+        assert!(!xml.contains("nanogallery:Rating=") && xml.contains("xmp:Rating=\"2\""));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}